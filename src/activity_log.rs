@@ -0,0 +1,344 @@
+use std::collections::VecDeque;
+use std::fmt;
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use iced::Task;
+
+use libsftpman::SftpManError;
+
+use crate::application::ApplicationMessage;
+use crate::messages::Message;
+use crate::strings;
+
+// We only keep this many entries around, so that a long-running application doesn't grow its log forever.
+const ACTIVITY_LOG_MAX_ENTRIES: usize = 500;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActivityKind {
+    Mount,
+    Unmount,
+    Save,
+    Remove,
+    PreflightCheck,
+    Reconnect,
+}
+
+impl fmt::Display for ActivityKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            Self::Mount => strings::activity_log_kind_mount_label(),
+            Self::Unmount => strings::activity_log_kind_unmount_label(),
+            Self::Save => strings::activity_log_kind_save_label(),
+            Self::Remove => strings::activity_log_kind_remove_label(),
+            Self::PreflightCheck => strings::activity_log_kind_preflight_check_label(),
+            Self::Reconnect => strings::activity_log_kind_reconnect_label(),
+        };
+
+        write!(f, "{}", label)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ActivityEntry {
+    pub timestamp: SystemTime,
+    pub kind: ActivityKind,
+
+    // fs_id is the id of the filesystem definition this entry is about.
+    // It's `None` for operations (like preflight checks) which don't concern a single filesystem.
+    pub fs_id: Option<String>,
+
+    pub command: Option<String>,
+    pub duration: Duration,
+
+    // error is `None` for successful operations
+    pub error: Option<String>,
+}
+
+impl ActivityEntry {
+    pub fn new(
+        kind: ActivityKind,
+        fs_id: Option<String>,
+        duration: Duration,
+        error: Option<String>,
+    ) -> Self {
+        Self {
+            timestamp: SystemTime::now(),
+            kind,
+            fs_id,
+            command: None,
+            duration,
+            error,
+        }
+    }
+
+    pub fn with_command(mut self, command: Option<String>) -> Self {
+        self.command = command;
+        self
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.error.is_none()
+    }
+
+    pub fn summary(&self) -> String {
+        let outcome = if self.is_success() {
+            strings::activity_log_outcome_success_label()
+        } else {
+            strings::activity_log_outcome_failure_label()
+        };
+
+        let subject = match &self.fs_id {
+            Some(fs_id) => format!("{} {}", self.kind, fs_id),
+            None => self.kind.to_string(),
+        };
+
+        format!(
+            "[{}] {}: {} ({:.2}s)",
+            format_timestamp(self.timestamp),
+            subject,
+            outcome,
+            self.duration.as_secs_f64()
+        )
+    }
+
+    fn to_text(&self) -> String {
+        let mut text = self.summary();
+
+        if let Some(command) = &self.command {
+            text.push_str(&format!(
+                "\n    {} {}",
+                strings::activity_log_command_label(),
+                command
+            ));
+        }
+
+        if let Some(error) = &self.error {
+            text.push_str(&format!(
+                "\n    {} {}",
+                strings::activity_log_error_label(),
+                error
+            ));
+        }
+
+        text
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ActivityLogFilter {
+    All,
+    Filesystem(String),
+}
+
+impl ActivityLogFilter {
+    fn matches(&self, entry: &ActivityEntry) -> bool {
+        match self {
+            Self::All => true,
+            Self::Filesystem(fs_id) => entry.fs_id.as_ref() == Some(fs_id),
+        }
+    }
+}
+
+impl fmt::Display for ActivityLogFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::All => write!(f, "{}", strings::activity_log_filter_all_label()),
+            Self::Filesystem(fs_id) => write!(f, "{}", fs_id),
+        }
+    }
+}
+
+/// ActivityLog records the operations (mounting, unmounting, saving, etc.) performed by the application.
+#[derive(Debug, Default)]
+pub struct ActivityLog {
+    entries: VecDeque<ActivityEntry>,
+}
+
+impl ActivityLog {
+    pub fn push(&mut self, entry: ActivityEntry) {
+        if self.entries.len() >= ACTIVITY_LOG_MAX_ENTRIES {
+            self.entries.pop_front();
+        }
+
+        self.entries.push_back(entry);
+    }
+
+    /// Returns the entries matching the given filter, newest first.
+    pub fn entries(&self, filter: &ActivityLogFilter) -> Vec<&ActivityEntry> {
+        self.entries
+            .iter()
+            .rev()
+            .filter(|entry| filter.matches(entry))
+            .collect()
+    }
+
+    /// Returns all filters which are useful for the current entries.
+    pub fn filters(&self) -> Vec<ActivityLogFilter> {
+        let mut fs_ids: Vec<String> = self
+            .entries
+            .iter()
+            .filter_map(|entry| entry.fs_id.clone())
+            .collect();
+
+        fs_ids.sort();
+        fs_ids.dedup();
+
+        let mut filters = vec![ActivityLogFilter::All];
+        filters.extend(fs_ids.into_iter().map(ActivityLogFilter::Filesystem));
+        filters
+    }
+
+    /// Returns a plain-text version of the entries matching the given filter (oldest first),
+    /// suitable for attaching to bug reports.
+    pub fn export_text(&self, filter: &ActivityLogFilter) -> String {
+        let mut lines: Vec<String> = vec![format!(
+            "{} {} (libsftpman {})",
+            env!("CARGO_PKG_NAME"),
+            env!("CARGO_PKG_VERSION"),
+            libsftpman::VERSION
+        )];
+
+        for entry in self.entries.iter().filter(|entry| filter.matches(entry)) {
+            lines.push(entry.to_text());
+        }
+
+        lines.join("\n") + "\n"
+    }
+}
+
+/// Returns a task which records an entry in the application's activity log.
+pub fn log_activity(entry: ActivityEntry) -> Task<Message> {
+    Task::perform(
+        async move { Message::Application(ApplicationMessage::LogActivity(Box::new(entry))) },
+        |m| m,
+    )
+}
+
+/// Turns a list of commands (like the ones returned by `FilesystemMountDefinition::mount_commands()`)
+/// into a single string which can be shown to (and copied by) the user.
+pub fn commands_to_string(commands: Result<Vec<Command>, SftpManError>) -> Option<String> {
+    match commands {
+        Ok(commands) => {
+            let command_strings: Vec<String> =
+                commands.iter().map(|cmd| format!("{:?}", cmd)).collect();
+            Some(command_strings.join(" && "))
+        }
+        Err(_) => None,
+    }
+}
+
+/// Formats the given time as a UTC timestamp (e.g. `2025-01-07 15:04:05 UTC`).
+pub fn format_timestamp(time: SystemTime) -> String {
+    let seconds_since_epoch = time
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    let days = (seconds_since_epoch / 86400) as i64;
+    let seconds_of_day = seconds_since_epoch % 86400;
+
+    let (year, month, day) = civil_from_days(days);
+
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02} UTC",
+        year,
+        month,
+        day,
+        seconds_of_day / 3600,
+        (seconds_of_day % 3600) / 60,
+        seconds_of_day % 60
+    )
+}
+
+// Converts a number of days since 1970-01-01 to a (year, month, day) date.
+// See: https://howardhinnant.github.io/date_algorithms.html#civil_from_days
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let day_of_era = z.rem_euclid(146097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use super::{ActivityEntry, ActivityKind, ActivityLog, ActivityLogFilter, format_timestamp};
+
+    #[test]
+    fn test_format_timestamp() {
+        assert_eq!(format_timestamp(UNIX_EPOCH), "1970-01-01 00:00:00 UTC");
+
+        assert_eq!(
+            format_timestamp(UNIX_EPOCH + Duration::from_secs(1_700_000_000)),
+            "2023-11-14 22:13:20 UTC"
+        );
+
+        // Leap day
+        assert_eq!(
+            format_timestamp(UNIX_EPOCH + Duration::from_secs(1_709_210_096)),
+            "2024-02-29 12:34:56 UTC"
+        );
+    }
+
+    #[test]
+    fn test_filtering() {
+        let mut log = ActivityLog::default();
+
+        log.push(ActivityEntry::new(
+            ActivityKind::Mount,
+            Some("first".to_owned()),
+            Duration::from_millis(100),
+            None,
+        ));
+        log.push(ActivityEntry::new(
+            ActivityKind::PreflightCheck,
+            None,
+            Duration::from_millis(10),
+            None,
+        ));
+        log.push(ActivityEntry::new(
+            ActivityKind::Unmount,
+            Some("second".to_owned()),
+            Duration::from_millis(50),
+            Some("busy".to_owned()),
+        ));
+        log.push(ActivityEntry::new(
+            ActivityKind::Unmount,
+            Some("first".to_owned()),
+            Duration::from_millis(50),
+            None,
+        ));
+
+        assert_eq!(log.entries(&ActivityLogFilter::All).len(), 4);
+
+        let first_only = log.entries(&ActivityLogFilter::Filesystem("first".to_owned()));
+        assert_eq!(first_only.len(), 2);
+        // Newest first
+        assert_eq!(first_only[0].kind, ActivityKind::Unmount);
+        assert_eq!(first_only[1].kind, ActivityKind::Mount);
+
+        assert_eq!(
+            log.filters(),
+            vec![
+                ActivityLogFilter::All,
+                ActivityLogFilter::Filesystem("first".to_owned()),
+                ActivityLogFilter::Filesystem("second".to_owned()),
+            ]
+        );
+
+        let exported = log.export_text(&ActivityLogFilter::Filesystem("second".to_owned()));
+        assert!(exported.contains("second"));
+        assert!(exported.contains("busy"));
+        assert!(!exported.contains("first"));
+    }
+}
@@ -1,15 +1,35 @@
-use std::process::Command;
+#[cfg(feature = "desktop-notifications")]
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
 
-use iced::clipboard;
+use iced::widget::stack;
 use iced::window::settings::PlatformSpecific;
-use iced::{Element, Font, Subscription, Task, Theme};
-
-use libsftpman::Manager;
+use iced::{Element, Font, Subscription, Task, Theme, clipboard, time, window};
 
+use crate::activity_log::{ActivityEntry, ActivityLog, ActivityLogFilter};
+use crate::cli;
 use crate::messages::Message;
-use crate::pages::Home;
-use crate::pages::HomeMessage;
+use crate::notifications::{DesktopNotification, NotificationQueue, NotificationSeverity};
+use crate::opener;
+use crate::pages::{HomeMessage, RecordMessage};
+use crate::pages::{AlertConfig, Home, StartupError, activity, alert};
+use crate::settings::Settings;
+use crate::single_instance::forwarded_invocations;
+use crate::strings;
 use crate::ui_config::{APP_HEIGHT, APP_WIDTH};
+use crate::utils::sftpman_config_dir;
+use crate::watcher::mount_state_changes;
+use crate::widgets::notification_stack;
+
+#[cfg(feature = "file-picker")]
+use crate::notifications::notify;
+
+#[cfg(feature = "dbus-service")]
+use crate::dbus_service::{DbusServiceEvent, DbusServiceHandle, dbus_service_events};
+#[cfg(feature = "desktop-notifications")]
+use crate::desktop_notifications::{self, details_requests};
+#[cfg(feature = "tray")]
+use crate::tray::{TrayEvent, TrayHandle, tray_events};
 
 const APPLICATION_ID: &str = env!("CARGO_PKG_NAME");
 
@@ -19,28 +39,131 @@ const DEFAULT_THEME: Theme = Theme::Light;
 pub enum ApplicationMessage {
     PutContentInClipboard(String),
     OpenLink(String),
+
+    Notify(NotificationSeverity, Box<AlertConfig>),
+    NotifyDesktop(Box<DesktopNotification>),
+    NotifyDesktopAndExit(Box<DesktopNotification>),
+    ShowNotification(Option<u64>),
+    DismissNotification(u64),
+    DismissAllNotifications,
+    NotificationsTicked(Instant),
+
+    MountStateChanged,
+
+    // InstanceInvoked carries the command-line arguments of another instance, which was started while we're running.
+    InstanceInvoked(Vec<String>),
+
+    LogActivity(Box<ActivityEntry>),
+    ActivityLog(bool),
+    ActivityLogFilterChanged(ActivityLogFilter),
+    #[cfg(feature = "file-picker")]
+    ExportActivityLog,
+
+    #[cfg(feature = "tray")]
+    Tray(TrayEvent),
+    #[cfg(feature = "tray")]
+    WindowCloseRequested(window::Id),
+
+    // DesktopNotificationShown carries the id a desktop notification got from the notification server
+    // and the id of the alert it offers to show.
+    #[cfg(feature = "desktop-notifications")]
+    DesktopNotificationShown(Result<u32, String>, Option<u64>),
+    #[cfg(feature = "desktop-notifications")]
+    DesktopNotificationDetailsRequested(u32),
+
+    #[cfg(feature = "dbus-service")]
+    DbusService(DbusServiceEvent),
 }
 
 struct Application {
     pages: Vec<Box<dyn Page>>,
     theme: Theme,
+
+    // settings holds our own settings (as used by the Home page), reloaded whenever they change (see `HomeMessage::SettingsChanged`).
+    settings: Settings,
+
+    notifications: NotificationQueue,
+
+    // shown_notification_id is the id of the notification whose full details are currently shown (in a modal).
+    shown_notification_id: Option<u64>,
+
+    activity_log: ActivityLog,
+    activity_log_shown: bool,
+    activity_log_filter: ActivityLogFilter,
+
+    // tray is set once the tray icon gets registered (see `crate::tray`).
+    #[cfg(feature = "tray")]
+    tray: Option<TrayHandle>,
+
+    // tray_available tells whether the tray icon is currently shown, so the window can be hidden to it.
+    #[cfg(feature = "tray")]
+    tray_available: bool,
+
+    #[cfg(feature = "tray")]
+    window_hidden: bool,
+
+    // desktop_notification_alerts maps desktop notification ids (as assigned by the notification server)
+    // to the ids of the alerts (in `notifications`) they offer to show.
+    #[cfg(feature = "desktop-notifications")]
+    desktop_notification_alerts: HashMap<u32, u64>,
+
+    // dbus_service is set once our D-Bus service gets exported (see `crate::dbus_service`).
+    #[cfg(feature = "dbus-service")]
+    dbus_service: Option<DbusServiceHandle>,
 }
 
 impl Application {
-    fn new() -> (Self, Task<Message>) {
-        let manager = Manager::new().unwrap();
+    fn new(options: &cli::Options) -> (Self, Task<Message>) {
+        let theme = Self::resolve_theme();
 
-        let tasks = Task::batch([
-            iced::widget::operation::focus_next(),
-            Task::perform(async {}, |_| Message::Home(HomeMessage::RunPreflightCheck)),
-        ]);
+        // If the configuration can't be loaded, we show an error page (offering a retry) instead of crashing.
+        let (page, tasks): (Box<dyn Page>, Task<Message>) = match Home::load() {
+            Ok(mut home) => {
+                let task_cli_options = home.apply_cli_options(options);
 
-        let theme = Self::resolve_theme();
+                let task_autostart = if options.autostart {
+                    Task::batch([home.autostart(), Self::minimize_window()])
+                } else {
+                    Task::none()
+                };
+
+                (
+                    Box::new(home),
+                    Task::batch([
+                        iced::widget::operation::focus_next(),
+                        Task::perform(async {}, |_| Message::Home(HomeMessage::RunPreflightCheck)),
+                        task_cli_options,
+                        task_autostart,
+                    ]),
+                )
+            }
+            Err(error) => {
+                log::error!("Failed to load the sftpman configuration: {}", error);
+
+                (Box::new(StartupError::new(error)), Task::none())
+            }
+        };
 
         (
             Self {
-                pages: vec![Box::new(Home::new(manager.clone()))],
+                pages: vec![page],
                 theme,
+                settings: Settings::load(),
+                notifications: NotificationQueue::default(),
+                shown_notification_id: None,
+                activity_log: ActivityLog::default(),
+                activity_log_shown: false,
+                activity_log_filter: ActivityLogFilter::All,
+                #[cfg(feature = "tray")]
+                tray: None,
+                #[cfg(feature = "tray")]
+                tray_available: false,
+                #[cfg(feature = "tray")]
+                window_hidden: false,
+                #[cfg(feature = "desktop-notifications")]
+                desktop_notification_alerts: HashMap::new(),
+                #[cfg(feature = "dbus-service")]
+                dbus_service: None,
             },
             tasks,
         )
@@ -88,23 +211,232 @@ impl Application {
                 ApplicationMessage::OpenLink(link) => {
                     log::info!("Opening link: {}", link);
 
-                    let mut cmd = Command::new("xdg-open");
-                    cmd.arg(link);
+                    if let Err(e) = opener::open(&self.settings.open_command, &link) {
+                        log::error!("Failed to open link: {}", e);
+
+                        self.notifications.push(
+                            NotificationSeverity::Error,
+                            AlertConfig::new(strings::open_link_failed_alert_title(), e),
+                        );
+                    }
 
-                    let result = cmd.spawn();
+                    Task::none()
+                }
+                ApplicationMessage::Notify(severity, alert_config) => {
+                    self.notifications.push(severity, *alert_config);
+                    Task::none()
+                }
+                ApplicationMessage::NotifyDesktop(notification) => {
+                    let notification = *notification;
 
-                    match result {
-                        Ok(_) => Task::none(),
+                    let alert_id = notification
+                        .alert
+                        .map(|alert| self.notifications.push(notification.severity, alert));
+
+                    #[cfg(feature = "desktop-notifications")]
+                    if notification.force || self.settings.desktop_notifications {
+                        return Task::perform(
+                            desktop_notifications::show(
+                                notification.severity,
+                                notification.summary,
+                                notification.body,
+                                alert_id.is_some(),
+                            ),
+                            move |result| {
+                                Message::Application(ApplicationMessage::DesktopNotificationShown(
+                                    result, alert_id,
+                                ))
+                            },
+                        );
+                    }
+
+                    #[cfg(not(feature = "desktop-notifications"))]
+                    let _ = alert_id;
+
+                    Task::none()
+                }
+                ApplicationMessage::NotifyDesktopAndExit(notification) => {
+                    log::info!("{}: {}", notification.summary, notification.body);
+
+                    #[cfg(feature = "desktop-notifications")]
+                    return Task::future(desktop_notifications::show(
+                        notification.severity,
+                        notification.summary,
+                        notification.body,
+                        false,
+                    ))
+                    .then(|result| {
+                        if let Err(e) = result {
+                            log::warn!("Failed to show a desktop notification: {}", e);
+                        }
+
+                        iced::exit()
+                    });
+
+                    #[cfg(not(feature = "desktop-notifications"))]
+                    iced::exit()
+                }
+                ApplicationMessage::ShowNotification(id) => {
+                    self.shown_notification_id = id;
+                    Task::none()
+                }
+                ApplicationMessage::DismissNotification(id) => {
+                    self.notifications.dismiss(id);
+
+                    if self.shown_notification_id == Some(id) {
+                        self.shown_notification_id = None;
+                    }
+
+                    Task::none()
+                }
+                ApplicationMessage::DismissAllNotifications => {
+                    self.notifications.dismiss_all();
+                    self.shown_notification_id = None;
+                    Task::none()
+                }
+                ApplicationMessage::NotificationsTicked(instant) => {
+                    self.notifications.expire(instant);
+
+                    if let Some(id) = self.shown_notification_id
+                        && self.notifications.get(id).is_none()
+                    {
+                        self.shown_notification_id = None;
+                    }
+
+                    Task::none()
+                }
+                ApplicationMessage::MountStateChanged => {
+                    // The Home page keeps its list up-to-date and the Record page (if open)
+                    // checks whether the definition being edited changed underneath it.
+                    let tasks = Task::batch([
+                        Task::perform(async {}, |_| Message::Home(HomeMessage::MountStateChanged)),
+                        Task::perform(async {}, |_| {
+                            Message::Record(RecordMessage::ConfigurationChanged)
+                        }),
+                    ]);
+
+                    #[cfg(feature = "tray")]
+                    let tasks = Task::batch([tasks, self.refresh_tray()]);
+
+                    #[cfg(feature = "dbus-service")]
+                    let tasks = Task::batch([tasks, self.emit_dbus_state_changed()]);
+
+                    tasks
+                }
+                ApplicationMessage::InstanceInvoked(args) => {
+                    let options = cli::parse_from(args);
+
+                    // Autostarting only makes sense when starting up (e.g. it may quit after mounting).
+                    if let Ok(options) = &options
+                        && options.autostart
+                    {
+                        log::info!("Ignoring an --autostart invocation, as we're already running");
+                        return Task::none();
+                    }
+
+                    // Instances for other configuration directories don't get here (see `crate::single_instance`),
+                    // but the configuration directory can't be changed while running anyway.
+                    if let Ok(options) = &options
+                        && let Some(config_dir) = &options.config_dir
+                    {
+                        log::warn!(
+                            "Ignoring --config-dir {} from another instance, as we keep using {}",
+                            config_dir.display(),
+                            sftpman_config_dir()
+                                .map(|dir| dir.display().to_string())
+                                .unwrap_or_default()
+                        );
+                    }
+
+                    // Bring our window to the front, as that's what the user expects when launching the application.
+                    let task_raise = self.show_window();
+
+                    match options {
+                        Ok(options) => Task::batch([
+                            task_raise,
+                            Task::perform(async {}, move |_| {
+                                Message::Home(HomeMessage::ApplyCliOptions(Box::new(options)))
+                            }),
+                        ]),
                         Err(e) => {
-                            log::error!("Failed to open link: {}", e);
-                            Task::none()
+                            log::warn!("Ignoring invalid arguments from another instance: {}", e);
+                            task_raise
                         }
                     }
                 }
+                ApplicationMessage::LogActivity(entry) => {
+                    self.activity_log.push(*entry);
+                    Task::none()
+                }
+                ApplicationMessage::ActivityLog(show) => {
+                    self.activity_log_shown = show;
+                    Task::none()
+                }
+                ApplicationMessage::ActivityLogFilterChanged(filter) => {
+                    self.activity_log_filter = filter;
+                    Task::none()
+                }
+                #[cfg(feature = "file-picker")]
+                ApplicationMessage::ExportActivityLog => self.export_activity_log(),
+                #[cfg(feature = "desktop-notifications")]
+                ApplicationMessage::DesktopNotificationShown(result, alert_id) => {
+                    match result {
+                        Ok(id) => {
+                            // Alerts which were dismissed in the meantime can't be shown anymore.
+                            self.desktop_notification_alerts
+                                .retain(|_, alert_id| self.notifications.get(*alert_id).is_some());
+
+                            if let Some(alert_id) = alert_id {
+                                self.desktop_notification_alerts.insert(id, alert_id);
+                            }
+                        }
+                        Err(e) => log::warn!("Failed to show a desktop notification: {}", e),
+                    }
+
+                    Task::none()
+                }
+                #[cfg(feature = "desktop-notifications")]
+                ApplicationMessage::DesktopNotificationDetailsRequested(id) => {
+                    let task_show_window = self.show_window();
+
+                    let Some(alert_id) = self.desktop_notification_alerts.remove(&id) else {
+                        return task_show_window;
+                    };
+
+                    self.shown_notification_id = Some(alert_id);
+
+                    task_show_window
+                }
+                #[cfg(feature = "dbus-service")]
+                ApplicationMessage::DbusService(event) => self.handle_dbus_service_event(event),
+                #[cfg(feature = "tray")]
+                ApplicationMessage::Tray(event) => self.handle_tray_event(event),
+                #[cfg(feature = "tray")]
+                ApplicationMessage::WindowCloseRequested(id) => {
+                    if self.tray_available && self.settings.close_to_tray {
+                        self.window_hidden = true;
+                        window::set_mode(id, window::Mode::Hidden)
+                    } else {
+                        iced::exit()
+                    }
+                }
             };
         };
 
-        let navigation = self.pages.last_mut().unwrap().update(message);
+        // Pages saving settings let the Home page know, which is when we pick them up too.
+        if let Message::Home(HomeMessage::SettingsChanged) = &message {
+            self.settings = Settings::load();
+        }
+
+        // Home messages always go to the Home page (the first one), even if another page is shown on top of it.
+        // Mounting and unmounting happen in the background and their results may arrive
+        // while the user is on another page (e.g. editing a record).
+        let page = match message {
+            Message::Home(_) => self.pages.first_mut().unwrap(),
+            _ => self.pages.last_mut().unwrap(),
+        };
+
+        let navigation = page.update(message);
         match navigation {
             Navigation::GoTo(page, tasks) => {
                 self.pages.push(page);
@@ -116,12 +448,179 @@ impl Application {
                 }
                 tasks
             }
+            Navigation::Replace(page, tasks) => {
+                self.pages = vec![page];
+                tasks
+            }
             Navigation::None(tasks) => tasks,
         }
     }
 
+    fn minimize_window() -> Task<Message> {
+        window::latest().then(|id| match id {
+            Some(id) => window::minimize(id, true),
+            None => Task::none(),
+        })
+    }
+
+    fn show_window(&mut self) -> Task<Message> {
+        #[cfg(feature = "tray")]
+        {
+            self.window_hidden = false;
+        }
+
+        window::latest().then(|id| match id {
+            Some(id) => Task::batch([
+                window::set_mode(id, window::Mode::Windowed),
+                window::gain_focus(id),
+            ]),
+            None => Task::none(),
+        })
+    }
+
+    #[cfg(feature = "tray")]
+    fn refresh_tray(&self) -> Task<Message> {
+        match &self.tray {
+            Some(tray) => Task::future(tray.clone().refresh()).discard(),
+            None => Task::none(),
+        }
+    }
+
+    #[cfg(feature = "tray")]
+    fn handle_tray_event(&mut self, event: TrayEvent) -> Task<Message> {
+        match event {
+            TrayEvent::Ready(tray) => {
+                self.tray = Some(tray);
+                self.refresh_tray()
+            }
+            TrayEvent::Available(available) => {
+                self.tray_available = available;
+
+                // The window can't be brought back without the tray icon, so we don't leave it hidden.
+                if !available && self.window_hidden {
+                    return self.show_window();
+                }
+
+                Task::none()
+            }
+            TrayEvent::Mount(definition) => Task::perform(async {}, move |_| {
+                Message::Home(HomeMessage::Mount(definition))
+            }),
+            TrayEvent::Unmount(definition) => Task::perform(async {}, move |_| {
+                Message::Home(HomeMessage::Unmount(definition))
+            }),
+            TrayEvent::MountAll => {
+                Task::perform(async {}, |_| Message::Home(HomeMessage::MountAllUnfiltered))
+            }
+            TrayEvent::UnmountAll => Task::perform(async {}, |_| {
+                Message::Home(HomeMessage::UnmountAllUnfiltered)
+            }),
+            TrayEvent::ShowWindow => self.show_window(),
+            TrayEvent::Quit => iced::exit(),
+        }
+    }
+
+    #[cfg(feature = "dbus-service")]
+    fn emit_dbus_state_changed(&self) -> Task<Message> {
+        match &self.dbus_service {
+            Some(dbus_service) => Task::future(dbus_service.clone().state_changed()).discard(),
+            None => Task::none(),
+        }
+    }
+
+    #[cfg(feature = "dbus-service")]
+    fn handle_dbus_service_event(&mut self, event: DbusServiceEvent) -> Task<Message> {
+        match event {
+            DbusServiceEvent::Ready(dbus_service) => {
+                self.dbus_service = Some(dbus_service);
+                Task::none()
+            }
+            DbusServiceEvent::Mount(definition) => Task::perform(async {}, move |_| {
+                Message::Home(HomeMessage::Mount(definition))
+            }),
+            DbusServiceEvent::Unmount(definition) => Task::perform(async {}, move |_| {
+                Message::Home(HomeMessage::Unmount(definition))
+            }),
+            DbusServiceEvent::MountAll => {
+                Task::perform(async {}, |_| Message::Home(HomeMessage::MountAllUnfiltered))
+            }
+            DbusServiceEvent::UnmountAll => Task::perform(async {}, |_| {
+                Message::Home(HomeMessage::UnmountAllUnfiltered)
+            }),
+        }
+    }
+
+    #[cfg(feature = "file-picker")]
+    fn export_activity_log(&self) -> Task<Message> {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+
+        let mut file = None;
+
+        rt.block_on(async {
+            file = tokio::task::block_in_place(|| {
+                rfd::FileDialog::new()
+                    .set_directory("~")
+                    .set_file_name(format!("{}-activity.log", APPLICATION_ID))
+                    .save_file()
+            });
+        });
+
+        let Some(file) = file else {
+            return Task::none();
+        };
+
+        let path = file.display().to_string();
+
+        match std::fs::write(
+            &file,
+            self.activity_log.export_text(&self.activity_log_filter),
+        ) {
+            Ok(_) => notify(
+                NotificationSeverity::Info,
+                AlertConfig::new(
+                    strings::activity_log_export_succeeded_title(&path),
+                    "".to_owned(),
+                ),
+            ),
+            Err(e) => notify(
+                NotificationSeverity::Error,
+                AlertConfig::new(
+                    strings::activity_log_export_failed_title(),
+                    format!("{}: {}", path, e),
+                ),
+            ),
+        }
+    }
+
     fn view(&self) -> Element<'_, Message> {
-        self.pages.last().unwrap().view(&self.theme())
+        let theme = self.theme();
+
+        let mut page = self.pages.last().unwrap().view(&theme);
+
+        if self.activity_log_shown {
+            page = activity(page, &self.activity_log, &self.activity_log_filter, &theme);
+        }
+
+        if self.notifications.all().is_empty() {
+            return page;
+        }
+
+        let content: Element<'_, Message> =
+            stack![page, notification_stack(self.notifications.all())].into();
+
+        let shown_notification = self
+            .shown_notification_id
+            .and_then(|id| self.notifications.get(id));
+
+        match shown_notification {
+            // Closing the details of a notification acknowledges (dismisses) it.
+            Some(notification) => alert(
+                content,
+                &notification.alert,
+                Message::Application(ApplicationMessage::DismissNotification(notification.id)),
+            ),
+            None => content,
+        }
     }
 
     fn theme(&self) -> Theme {
@@ -129,11 +628,57 @@ impl Application {
     }
 
     fn subscription(&self) -> Subscription<Message> {
-        self.pages.last().unwrap().subscription()
+        let page_subscription = self.pages.last().unwrap().subscription();
+        let background_subscription = self.pages.first().unwrap().background_subscription();
+
+        // This lives at the application level (instead of on the Home page),
+        // so that the Home page's state stays up-to-date even while another page is shown on top of it.
+        let mount_state_changes = mount_state_changes()
+            .map(|_| Message::Application(ApplicationMessage::MountStateChanged));
+
+        let forwarded_invocations = forwarded_invocations()
+            .map(|args| Message::Application(ApplicationMessage::InstanceInvoked(args)));
+
+        let mut subscriptions = vec![
+            page_subscription,
+            background_subscription,
+            mount_state_changes,
+            forwarded_invocations,
+        ];
+
+        #[cfg(feature = "desktop-notifications")]
+        subscriptions.push(details_requests().map(|id| {
+            Message::Application(ApplicationMessage::DesktopNotificationDetailsRequested(id))
+        }));
+
+        #[cfg(feature = "dbus-service")]
+        subscriptions.push(
+            dbus_service_events()
+                .map(|event| Message::Application(ApplicationMessage::DbusService(event))),
+        );
+
+        #[cfg(feature = "tray")]
+        {
+            subscriptions.push(
+                tray_events().map(|event| Message::Application(ApplicationMessage::Tray(event))),
+            );
+            subscriptions.push(
+                window::close_requests()
+                    .map(|id| Message::Application(ApplicationMessage::WindowCloseRequested(id))),
+            );
+        }
+
+        if self.notifications.has_auto_dismissable() {
+            subscriptions.push(time::every(Duration::from_secs(1)).map(|instant| {
+                Message::Application(ApplicationMessage::NotificationsTicked(instant))
+            }));
+        }
+
+        Subscription::batch(subscriptions)
     }
 }
 
-pub fn run_application() -> iced::Result {
+pub fn run_application(options: cli::Options) -> iced::Result {
     let window_settings_platform_specific = PlatformSpecific {
         application_id: APPLICATION_ID.to_string(),
         ..PlatformSpecific::default()
@@ -142,12 +687,16 @@ pub fn run_application() -> iced::Result {
     #[cfg(feature = "x11-icon")]
     let mut window_settings = iced::window::Settings {
         platform_specific: window_settings_platform_specific,
+        // With a tray icon, closing may only hide the window (see `ApplicationMessage::WindowCloseRequested`).
+        exit_on_close_request: !cfg!(feature = "tray"),
         ..iced::window::Settings::default()
     };
 
     #[cfg(not(feature = "x11-icon"))]
     let window_settings = iced::window::Settings {
         platform_specific: window_settings_platform_specific,
+        // With a tray icon, closing may only hide the window (see `ApplicationMessage::WindowCloseRequested`).
+        exit_on_close_request: !cfg!(feature = "tray"),
         ..iced::window::Settings::default()
     };
 
@@ -168,13 +717,17 @@ pub fn run_application() -> iced::Result {
         );
     }
 
-    let app = iced::application(Application::new, Application::update, Application::view)
-        .title(Application::title)
-        .subscription(Application::subscription)
-        .window_size((APP_WIDTH, APP_HEIGHT))
-        .window(window_settings)
-        .theme(Application::theme)
-        .default_font(Font::DEFAULT);
+    let app = iced::application(
+        move || Application::new(&options),
+        Application::update,
+        Application::view,
+    )
+    .title(Application::title)
+    .subscription(Application::subscription)
+    .window_size((APP_WIDTH, APP_HEIGHT))
+    .window(window_settings)
+    .theme(Application::theme)
+    .default_font(Font::DEFAULT);
 
     app.run()
 }
@@ -188,6 +741,8 @@ impl Application {
 pub enum Navigation {
     GoTo(Box<dyn Page>, iced::Task<Message>),
     Back(iced::Task<Message>),
+    // Replace replaces all pages with the given one
+    Replace(Box<dyn Page>, iced::Task<Message>),
     None(iced::Task<Message>),
 }
 
@@ -195,4 +750,11 @@ pub trait Page {
     fn update(&mut self, message: Message) -> Navigation;
     fn view(&self, theme: &iced::theme::Theme) -> Element<'_, Message>;
     fn subscription(&self) -> Subscription<Message>;
+
+    /// Returns a subscription which stays active even while other pages are shown on top of this one.
+    ///
+    /// Only the first page's (usually Home's) background subscription is used.
+    fn background_subscription(&self) -> Subscription<Message> {
+        Subscription::none()
+    }
 }
@@ -18,13 +18,17 @@ pub mod bootstrap_icons {
     pub const CLIPBOARD_CHECK: &[u8] = include_bytes!("../assets/bootstrap/clipboard-check.svg");
     pub const CLIPBOARD_MINUS: &[u8] = include_bytes!("../assets/bootstrap/clipboard-minus.svg");
     pub const COPY: &[u8] = include_bytes!("../assets/bootstrap/copy.svg");
+    pub const DOWNLOAD: &[u8] = include_bytes!("../assets/bootstrap/download.svg");
     pub const EXCLAMATION_TRIANGLE: &[u8] =
         include_bytes!("../assets/bootstrap/exclamation-triangle.svg");
     pub const FOLDER2_OPEN: &[u8] = include_bytes!("../assets/bootstrap/folder2-open.svg");
+    pub const GEAR: &[u8] = include_bytes!("../assets/bootstrap/gear.svg");
     pub const INFO_CIRCLE: &[u8] = include_bytes!("../assets/bootstrap/info-circle.svg");
     pub const PENCIL: &[u8] = include_bytes!("../assets/bootstrap/pencil.svg");
     pub const PLUS_CIRCLE: &[u8] = include_bytes!("../assets/bootstrap/plus-circle.svg");
     pub const QUESTION_CIRCLE: &[u8] = include_bytes!("../assets/bootstrap/question-circle.svg");
     pub const SEARCH: &[u8] = include_bytes!("../assets/bootstrap/search.svg");
+    pub const TERMINAL: &[u8] = include_bytes!("../assets/bootstrap/terminal.svg");
     pub const TRASH: &[u8] = include_bytes!("../assets/bootstrap/trash.svg");
+    pub const X_CIRCLE: &[u8] = include_bytes!("../assets/bootstrap/x-circle.svg");
 }
@@ -0,0 +1,54 @@
+use std::fs;
+use std::path::PathBuf;
+
+// The entry is named after our `.desktop` file, so that desktop environments associate the two.
+const ENTRY_FILE_NAME: &str = concat!(env!("CARGO_PKG_NAME"), ".desktop");
+
+/// Tells whether an XDG autostart entry (starting us with `--autostart` on login) is installed.
+pub fn is_installed() -> bool {
+    entry_path().is_some_and(|path| path.exists())
+}
+
+/// Installs an XDG autostart entry (in `~/.config/autostart`), which starts us with `--autostart` on login.
+pub fn install() -> Result<(), String> {
+    let path = entry_path().ok_or("Could not determine the autostart directory. Is $HOME set?")?;
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+    }
+
+    fs::write(&path, entry()).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+/// Removes the XDG autostart entry installed by `install()` (if any).
+pub fn uninstall() -> Result<(), String> {
+    let Some(path) = entry_path() else {
+        return Ok(());
+    };
+
+    match fs::remove_file(&path) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(format!("{}: {}", path.display(), e)),
+    }
+}
+
+fn entry_path() -> Option<PathBuf> {
+    directories::BaseDirs::new()
+        .map(|dirs| dirs.config_dir().join("autostart").join(ENTRY_FILE_NAME))
+}
+
+fn entry() -> String {
+    format!(
+        "[Desktop Entry]\n\
+         Type=Application\n\
+         Name={name}\n\
+         Comment={description}\n\
+         Exec={name} --autostart\n\
+         Icon={name}\n\
+         Terminal=false\n\
+         X-GNOME-Autostart-enabled=true\n",
+        name = env!("CARGO_PKG_NAME"),
+        description = env!("CARGO_PKG_DESCRIPTION"),
+    )
+}
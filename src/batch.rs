@@ -0,0 +1,175 @@
+use std::collections::{HashSet, VecDeque};
+
+use libsftpman::FilesystemMountDefinition;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BatchKind {
+    Mount,
+    Unmount,
+}
+
+#[derive(Debug, Clone)]
+pub struct BatchOutcome {
+    pub definition: FilesystemMountDefinition,
+
+    // error is `None` if the operation succeeded.
+    pub error: Option<String>,
+}
+
+/// Batch tracks a "Mount all" or "Unmount all" operation.
+///
+/// Each filesystem moves from pending, to in-flight, to having an outcome.
+/// The batch runs to completion regardless of failures, so that all outcomes can be reported at the end.
+#[derive(Debug, Clone)]
+pub struct Batch {
+    kind: BatchKind,
+
+    // parallel tells whether all pending filesystems are started at once (instead of one by one).
+    parallel: bool,
+
+    pending: VecDeque<FilesystemMountDefinition>,
+    in_flight: HashSet<String>,
+    outcomes: Vec<BatchOutcome>,
+}
+
+impl Batch {
+    pub fn new(kind: BatchKind, parallel: bool) -> Self {
+        Self {
+            kind,
+            parallel,
+            pending: VecDeque::new(),
+            in_flight: HashSet::new(),
+            outcomes: Vec::new(),
+        }
+    }
+
+    pub fn kind(&self) -> BatchKind {
+        self.kind
+    }
+
+    /// Adds a filesystem to the batch, unless it's already part of it.
+    pub fn enqueue(&mut self, definition: FilesystemMountDefinition) {
+        if self.contains(&definition.id) {
+            return;
+        }
+
+        self.pending.push_back(definition);
+    }
+
+    fn contains(&self, id: &str) -> bool {
+        self.in_flight.contains(id)
+            || self.pending.iter().any(|d| d.id == id)
+            || self.outcomes.iter().any(|o| o.definition.id == id)
+    }
+
+    /// Returns the filesystems which should be started now, marking them as in-flight.
+    pub fn start_next(&mut self) -> Vec<FilesystemMountDefinition> {
+        let mut started = Vec::new();
+
+        while self.parallel || (self.in_flight.is_empty() && started.is_empty()) {
+            let Some(definition) = self.pending.pop_front() else {
+                break;
+            };
+
+            self.in_flight.insert(definition.id.clone());
+            started.push(definition);
+        }
+
+        started
+    }
+
+    /// Records the outcome for an in-flight filesystem.
+    ///
+    /// Returns `false` if the filesystem is not in-flight as part of this batch
+    /// (e.g. it was mounted individually), in which case nothing is recorded.
+    pub fn record(
+        &mut self,
+        definition: &FilesystemMountDefinition,
+        error: Option<String>,
+    ) -> bool {
+        if !self.in_flight.remove(&definition.id) {
+            return false;
+        }
+
+        self.outcomes.push(BatchOutcome {
+            definition: definition.clone(),
+            error,
+        });
+
+        true
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.pending.is_empty() && self.in_flight.is_empty()
+    }
+
+    pub fn succeeded(&self) -> Vec<&BatchOutcome> {
+        self.outcomes.iter().filter(|o| o.error.is_none()).collect()
+    }
+
+    pub fn failed(&self) -> Vec<&BatchOutcome> {
+        self.outcomes.iter().filter(|o| o.error.is_some()).collect()
+    }
+
+    pub fn total(&self) -> usize {
+        self.pending.len() + self.in_flight.len() + self.outcomes.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use libsftpman::FilesystemMountDefinition;
+
+    use super::{Batch, BatchKind};
+
+    fn definition(id: &str) -> FilesystemMountDefinition {
+        FilesystemMountDefinition {
+            id: id.to_owned(),
+            ..FilesystemMountDefinition::default()
+        }
+    }
+
+    #[test]
+    fn test_sequential_batch_continues_past_failures() {
+        let mut batch = Batch::new(BatchKind::Mount, false);
+        batch.enqueue(definition("a"));
+        batch.enqueue(definition("b"));
+        batch.enqueue(definition("a"));
+
+        assert_eq!(batch.total(), 2);
+
+        let started = batch.start_next();
+        assert_eq!(started.len(), 1);
+        assert_eq!(started[0].id, "a");
+
+        // Nothing else starts while one is in flight.
+        assert!(batch.start_next().is_empty());
+
+        // Outcomes for filesystems which are not in flight are not ours.
+        assert!(!batch.record(&definition("other"), None));
+
+        assert!(batch.record(&definition("a"), Some("failed".to_owned())));
+        assert!(!batch.is_finished());
+
+        let started = batch.start_next();
+        assert_eq!(started.len(), 1);
+        assert_eq!(started[0].id, "b");
+
+        assert!(batch.record(&definition("b"), None));
+        assert!(batch.is_finished());
+
+        assert_eq!(batch.succeeded().len(), 1);
+        assert_eq!(batch.failed().len(), 1);
+        assert_eq!(batch.failed()[0].definition.id, "a");
+    }
+
+    #[test]
+    fn test_parallel_batch_starts_everything() {
+        let mut batch = Batch::new(BatchKind::Unmount, true);
+        batch.enqueue(definition("a"));
+        batch.enqueue(definition("b"));
+
+        assert_eq!(batch.start_next().len(), 2);
+        assert!(batch.start_next().is_empty());
+    }
+}
@@ -0,0 +1,136 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// BusyProcess is a process using (and thus keeping busy) a path under a mount point (see `processes_using()`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BusyProcess {
+    pub pid: u32,
+    pub name: String,
+
+    // path is the first path under the mount point that we found the process to be using
+    // (its working directory, an open file or a memory-mapped file).
+    pub path: String,
+}
+
+/// Returns the processes using paths under the given mount point, like `fuser -m` or `lsof` would.
+///
+/// We scan `/proc/*/cwd`, `/proc/*/fd/*` and `/proc/*/maps`. Processes of other users can't be inspected
+/// (without privileges) and are silently skipped, but they can't be using our (FUSE) mounts anyway.
+pub fn processes_using(mount_path: &str) -> Vec<BusyProcess> {
+    let mount_path = Path::new(mount_path);
+
+    let Ok(entries) = fs::read_dir("/proc") else {
+        return Vec::new();
+    };
+
+    let mut processes = entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let pid = entry.file_name().to_str()?.parse::<u32>().ok()?;
+
+            let path = used_paths(&entry.path())
+                .into_iter()
+                .find(|path| is_under(path, mount_path))?;
+
+            let name = fs::read_to_string(entry.path().join("comm"))
+                .map(|name| name.trim().to_owned())
+                .unwrap_or_default();
+
+            Some(BusyProcess {
+                pid,
+                name,
+                path: path.display().to_string(),
+            })
+        })
+        .collect::<Vec<_>>();
+
+    processes.sort_by_key(|process| process.pid);
+
+    processes
+}
+
+// Returns the paths used by the process with the given `/proc/<pid>` directory.
+// Reading the links doesn't access the paths themselves, so this doesn't hang on dead mounts.
+fn used_paths(proc_dir: &Path) -> Vec<PathBuf> {
+    let mut paths = vec![];
+
+    if let Ok(path) = fs::read_link(proc_dir.join("cwd")) {
+        paths.push(path);
+    }
+
+    if let Ok(fds) = fs::read_dir(proc_dir.join("fd")) {
+        paths.extend(
+            fds.filter_map(|fd| fd.ok())
+                .filter_map(|fd| fs::read_link(fd.path()).ok()),
+        );
+    }
+
+    if let Ok(maps) = fs::read_to_string(proc_dir.join("maps")) {
+        paths.extend(maps.lines().filter_map(mapped_path));
+    }
+
+    paths
+}
+
+// Parses the path out of a `/proc/<pid>/maps` line (e.g. `7f..-7f.. r--p 00000000 00:2f 1234  /path/to/file`).
+// Anonymous mappings and pseudo-paths (e.g. `[heap]`) yield `None`.
+fn mapped_path(line: &str) -> Option<PathBuf> {
+    let path = line.splitn(6, char::is_whitespace).nth(5)?.trim_start();
+
+    path.starts_with('/').then(|| PathBuf::from(path))
+}
+
+fn is_under(path: &Path, mount_path: &Path) -> bool {
+    // `Path::starts_with()` compares whole components, so `/mnt/a-b` is not considered to be under `/mnt/a`.
+    path.starts_with(mount_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use super::{is_under, mapped_path, processes_using};
+
+    #[test]
+    fn test_mapped_path() {
+        assert_eq!(
+            mapped_path(
+                "7f3c1e000000-7f3c1e021000 r--p 00000000 00:2f 1234    /mnt/sshfs/a/lib.so"
+            ),
+            Some(PathBuf::from("/mnt/sshfs/a/lib.so"))
+        );
+        assert_eq!(
+            mapped_path("7f3c1e000000-7f3c1e021000 r--p 00000000 00:2f 1234    /mnt/my files/x"),
+            Some(PathBuf::from("/mnt/my files/x"))
+        );
+        assert_eq!(
+            mapped_path("7ffd5e1f0000-7ffd5e211000 rw-p 00000000 00:00 0      [stack]"),
+            None
+        );
+        assert_eq!(
+            mapped_path("7f3c1e000000-7f3c1e021000 rw-p 00000000 00:00 0"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_is_under() {
+        assert!(is_under(Path::new("/mnt/a"), Path::new("/mnt/a")));
+        assert!(is_under(Path::new("/mnt/a/b"), Path::new("/mnt/a")));
+        assert!(!is_under(Path::new("/mnt/a-b"), Path::new("/mnt/a")));
+    }
+
+    #[test]
+    fn test_processes_using() {
+        // The test runs from within the crate's directory.
+        let cwd = std::env::current_dir().unwrap();
+
+        let processes = processes_using(&cwd.display().to_string());
+
+        assert!(
+            processes
+                .iter()
+                .any(|process| process.pid == std::process::id())
+        );
+    }
+}
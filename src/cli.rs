@@ -0,0 +1,160 @@
+use std::path::PathBuf;
+
+use clap::{Arg, ArgAction, ArgMatches, Command, value_parser};
+
+/// Options holds what was requested via the command line.
+///
+/// Actions (mounting, searching, etc.) are performed once the Home page loads.
+#[derive(Debug, Clone, Default)]
+pub struct Options {
+    pub mount: Vec<String>,
+    pub unmount: Vec<String>,
+    pub mount_all: bool,
+    pub unmount_all: bool,
+    pub search: Option<String>,
+    pub edit: Option<String>,
+    pub new: bool,
+    pub autostart: bool,
+    pub config_dir: Option<PathBuf>,
+}
+
+pub fn build() -> Command {
+    Command::new(env!("CARGO_PKG_NAME"))
+        .about(env!("CARGO_PKG_DESCRIPTION"))
+        .version(env!("CARGO_PKG_VERSION"))
+        .arg(
+            Arg::new("mount")
+                .long("mount")
+                .value_name("ID")
+                .action(ArgAction::Append)
+                .help("Mount the filesystem with the given id (can be repeated)"),
+        )
+        .arg(
+            Arg::new("unmount")
+                .long("unmount")
+                .value_name("ID")
+                .action(ArgAction::Append)
+                .help("Unmount the filesystem with the given id (can be repeated)"),
+        )
+        .arg(
+            Arg::new("mount-all")
+                .long("mount-all")
+                .action(ArgAction::SetTrue)
+                .conflicts_with("unmount-all")
+                .help("Mount all filesystems"),
+        )
+        .arg(
+            Arg::new("unmount-all")
+                .long("unmount-all")
+                .action(ArgAction::SetTrue)
+                .help("Unmount all filesystems"),
+        )
+        .arg(
+            Arg::new("search")
+                .long("search")
+                .value_name("TEXT")
+                .help("Start with the search bar open and filled in"),
+        )
+        .arg(
+            Arg::new("edit")
+                .long("edit")
+                .value_name("ID")
+                .conflicts_with("new")
+                .help("Open the editor for the filesystem with the given id"),
+        )
+        .arg(
+            Arg::new("new")
+                .long("new")
+                .action(ArgAction::SetTrue)
+                .help("Open the editor for a new filesystem"),
+        )
+        .arg(
+            Arg::new("autostart")
+                .long("autostart")
+                .action(ArgAction::SetTrue)
+                .help("Start minimized and mount the filesystems marked for mounting at startup (meant for starting on login)"),
+        )
+        .arg(
+            Arg::new("config-dir")
+                .long("config-dir")
+                .value_name("DIR")
+                .value_parser(value_parser!(PathBuf))
+                .help("Use DIR as the base configuration directory instead of $XDG_CONFIG_HOME (~/.config). Filesystem definitions are read from DIR/sftpman/mounts and settings from DIR/sftpman-iced"),
+        )
+}
+
+pub fn parse() -> Options {
+    from_matches(&build().get_matches())
+}
+
+/// Parses arguments received from another instance (see `crate::single_instance`).
+///
+/// Unlike `parse()`, this doesn't exit the process on invalid arguments or `--help`.
+pub fn parse_from(args: Vec<String>) -> Result<Options, clap::Error> {
+    let args = std::iter::once(env!("CARGO_PKG_NAME").to_owned()).chain(args);
+
+    build()
+        .try_get_matches_from(args)
+        .map(|matches| from_matches(&matches))
+}
+
+fn from_matches(matches: &ArgMatches) -> Options {
+    let values = |id: &str| -> Vec<String> {
+        matches
+            .get_many::<String>(id)
+            .map(|values| values.cloned().collect())
+            .unwrap_or_default()
+    };
+
+    Options {
+        mount: values("mount"),
+        unmount: values("unmount"),
+        mount_all: matches.get_flag("mount-all"),
+        unmount_all: matches.get_flag("unmount-all"),
+        search: matches.get_one::<String>("search").cloned(),
+        edit: matches.get_one::<String>("edit").cloned(),
+        new: matches.get_flag("new"),
+        autostart: matches.get_flag("autostart"),
+        config_dir: matches.get_one::<PathBuf>("config-dir").cloned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{build, from_matches};
+
+    #[test]
+    fn test_parse() {
+        let matches = build()
+            .try_get_matches_from([
+                "sftpman-iced",
+                "--mount",
+                "one",
+                "--mount",
+                "two",
+                "--search",
+                "text",
+                "--config-dir",
+                "/tmp/config",
+            ])
+            .unwrap();
+
+        let options = from_matches(&matches);
+
+        assert_eq!(options.mount, vec!["one", "two"]);
+        assert!(options.unmount.is_empty());
+        assert!(!options.mount_all);
+        assert_eq!(options.search.as_deref(), Some("text"));
+        assert_eq!(options.edit, None);
+        assert_eq!(
+            options.config_dir.as_deref(),
+            Some(std::path::Path::new("/tmp/config"))
+        );
+
+        assert!(
+            build()
+                .try_get_matches_from(["sftpman-iced", "--edit", "one", "--new"])
+                .is_err()
+        );
+    }
+}
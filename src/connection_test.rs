@@ -0,0 +1,421 @@
+use std::process::Command;
+use std::time::{Duration, Instant};
+
+use libsftpman::FilesystemMountDefinition;
+
+use crate::network;
+use crate::sftp::{self, BatchOutput};
+use crate::strings;
+
+/// How long testing a connection may take at most.
+pub const TIMEOUT: Duration = Duration::from_secs(20);
+
+/// CheckResult is the outcome of one of the checks a connection test performs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CheckResult {
+    Passed,
+    Failed(String),
+
+    // Skipped means that the check could not be performed (e.g. because an earlier one failed).
+    Skipped,
+}
+
+/// Report holds the outcome of each check a connection test performs (in order).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    // reachability tells whether a TCP connection could be made to the SSH server.
+    pub reachability: CheckResult,
+
+    // authentication tells whether an SFTP session could be started (which requires authenticating).
+    pub authentication: CheckResult,
+
+    // remote_path tells whether the remote path exists and is a directory.
+    pub remote_path: CheckResult,
+}
+
+/// Tests whether the given filesystem definition could be mounted, without mounting it (or persisting anything).
+///
+/// This is a blocking call, taking up to `TIMEOUT`.
+pub fn run(definition: &FilesystemMountDefinition) -> Report {
+    run_with_ssh_options(definition, &[], TIMEOUT)
+}
+
+fn run_with_ssh_options(
+    definition: &FilesystemMountDefinition,
+    ssh_options: &[String],
+    timeout: Duration,
+) -> Report {
+    let started_at = Instant::now();
+
+    let reachability = check_reachability(definition, ssh_options);
+
+    if let CheckResult::Failed(_) = reachability {
+        return Report {
+            reachability,
+            authentication: CheckResult::Skipped,
+            remote_path: CheckResult::Skipped,
+        };
+    }
+
+    let mut commands = vec!["pwd".to_owned()];
+    if !definition.remote_path.is_empty() {
+        match sftp::quote(&definition.remote_path) {
+            Ok(quoted) => commands.push(format!("cd {}", quoted)),
+            Err(e) => {
+                return Report {
+                    reachability,
+                    authentication: CheckResult::Skipped,
+                    remote_path: CheckResult::Failed(e),
+                };
+            }
+        }
+    }
+
+    let (authentication, remote_path) = match sftp::run_batch(
+        definition,
+        &commands,
+        ssh_options,
+        timeout.saturating_sub(started_at.elapsed()),
+    ) {
+        Ok(output) => interpret(&output, &definition.remote_path, timeout),
+        Err(e) => (CheckResult::Failed(e), CheckResult::Skipped),
+    };
+
+    Report {
+        reachability,
+        authentication,
+        remote_path,
+    }
+}
+
+// Checks whether the SSH server accepts TCP connections.
+//
+// The host and port are resolved via `ssh -G`, because `~/.ssh/config` may override them (e.g. for aliases).
+// Connections going through a proxy (`ProxyJump` or `ProxyCommand`) can't be checked directly, so the check is skipped for them.
+fn check_reachability(
+    definition: &FilesystemMountDefinition,
+    ssh_options: &[String],
+) -> CheckResult {
+    let output = Command::new("ssh")
+        .arg("-G")
+        .args(ssh_options)
+        .arg("-p")
+        .arg(definition.port.to_string())
+        .arg("-l")
+        .arg(&definition.user)
+        .arg("--")
+        .arg(&definition.host)
+        .output();
+
+    let resolved = match output {
+        Ok(output) if output.status.success() => {
+            parse_resolved_destination(&String::from_utf8_lossy(&output.stdout))
+        }
+        Ok(output) => {
+            log::debug!(
+                "Failed to resolve {} via ssh -G: {}",
+                definition.host,
+                String::from_utf8_lossy(&output.stderr)
+            );
+            None
+        }
+        Err(e) => {
+            log::debug!("Failed to run ssh -G: {}", e);
+            None
+        }
+    };
+
+    let (host, port) = match resolved {
+        Some(ResolvedDestination::Proxied) => return CheckResult::Skipped,
+        Some(ResolvedDestination::Direct(host, port)) => (host, port),
+        None => (definition.host.clone(), definition.port),
+    };
+
+    if network::is_reachable(&host, port) {
+        CheckResult::Passed
+    } else {
+        CheckResult::Failed(strings::connection_test_unreachable_error(&host, port))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum ResolvedDestination {
+    Direct(String, u16),
+    Proxied,
+}
+
+// Extracts where ssh would connect to, from the (`keyword value`) configuration printed by `ssh -G`.
+fn parse_resolved_destination(config: &str) -> Option<ResolvedDestination> {
+    let mut host = None;
+    let mut port = None;
+
+    for line in config.lines() {
+        let Some((keyword, value)) = line.split_once(' ') else {
+            continue;
+        };
+
+        match keyword {
+            "hostname" => host = Some(value.to_owned()),
+            "port" => port = value.parse::<u16>().ok(),
+            "proxyjump" | "proxycommand" if value != "none" => {
+                return Some(ResolvedDestination::Proxied);
+            }
+            _ => {}
+        }
+    }
+
+    Some(ResolvedDestination::Direct(host?, port?))
+}
+
+// Tells the authentication and remote path results apart, based on how far sftp got through the commands.
+fn interpret(
+    output: &BatchOutput,
+    remote_path: &str,
+    timeout: Duration,
+) -> (CheckResult, CheckResult) {
+    let failure = || CheckResult::Failed(sftp::failure_message(output, timeout));
+
+    // sftp can only respond to `pwd` after authenticating.
+    let authenticated = output
+        .stdout
+        .lines()
+        .any(|line| line.starts_with(sftp::PWD_RESPONSE_PREFIX));
+
+    if !authenticated {
+        return (failure(), CheckResult::Skipped);
+    }
+
+    if remote_path.is_empty() {
+        return (
+            CheckResult::Passed,
+            CheckResult::Failed(strings::connection_test_empty_remote_path_error()),
+        );
+    }
+
+    if output.success {
+        (CheckResult::Passed, CheckResult::Passed)
+    } else {
+        (CheckResult::Passed, failure())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::net::TcpListener;
+    use std::path::{Path, PathBuf};
+    use std::process::{Child, Command, Stdio};
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    use libsftpman::{AuthType, FilesystemMountDefinition};
+
+    use super::{
+        CheckResult, ResolvedDestination, interpret, parse_resolved_destination,
+        run_with_ssh_options,
+    };
+    use crate::network;
+    use crate::sftp::BatchOutput;
+
+    #[test]
+    fn test_parse_resolved_destination() {
+        assert_eq!(
+            parse_resolved_destination("user admin\nhostname 192.0.2.10\nport 2222\n"),
+            Some(ResolvedDestination::Direct("192.0.2.10".to_owned(), 2222))
+        );
+        assert_eq!(
+            parse_resolved_destination("hostname web\nport 22\nproxyjump bastion\n"),
+            Some(ResolvedDestination::Proxied)
+        );
+        assert_eq!(parse_resolved_destination("port 22\n"), None);
+    }
+
+    #[test]
+    fn test_interpret() {
+        let timeout = Duration::from_secs(20);
+
+        let output = BatchOutput {
+            success: false,
+            stderr: "user@example.com: Permission denied (publickey).\r\nConnection closed\n"
+                .to_owned(),
+            ..BatchOutput::default()
+        };
+        assert_eq!(
+            interpret(&output, "/srv", timeout),
+            (
+                CheckResult::Failed(
+                    "user@example.com: Permission denied (publickey). Connection closed".to_owned()
+                ),
+                CheckResult::Skipped
+            )
+        );
+
+        let output = BatchOutput {
+            success: false,
+            stdout: "sftp> pwd\nRemote working directory: /home/user\nsftp> cd \"/srv\"\n"
+                .to_owned(),
+            stderr:
+                "Connected to example.com.\nCan't change directory: \"/srv\" is not a directory\n"
+                    .to_owned(),
+            ..BatchOutput::default()
+        };
+        assert_eq!(
+            interpret(&output, "/srv", timeout),
+            (
+                CheckResult::Passed,
+                CheckResult::Failed(
+                    "Can't change directory: \"/srv\" is not a directory".to_owned()
+                )
+            )
+        );
+
+        let output = BatchOutput {
+            success: true,
+            ..output
+        };
+        assert_eq!(
+            interpret(&output, "/srv", timeout),
+            (CheckResult::Passed, CheckResult::Passed)
+        );
+    }
+
+    // Sshd stops the sshd started by a test and removes its directory, even if the test fails.
+    struct Sshd {
+        dir: PathBuf,
+        child: Option<Child>,
+    }
+
+    impl Drop for Sshd {
+        fn drop(&mut self) {
+            if let Some(child) = &mut self.child {
+                let _ = child.kill();
+                let _ = child.wait();
+            }
+
+            let _ = fs::remove_dir_all(&self.dir);
+        }
+    }
+
+    // Tests against a local sshd, which lets the current user in with a throwaway key.
+    // This needs sshd (and ssh-keygen) to be installed, so it only runs when asked for (`cargo test -- --ignored`).
+    #[test]
+    #[ignore]
+    fn test_run_against_local_sshd() {
+        let sshd_path = ["/usr/sbin/sshd", "/usr/bin/sshd"]
+            .into_iter()
+            .map(PathBuf::from)
+            .find(|path| path.is_file())
+            .expect("sshd is not installed");
+
+        let mut sshd = Sshd {
+            dir: env::temp_dir().join(format!("sftpman-iced-sshd-{}", std::process::id())),
+            child: None,
+        };
+        let dir = sshd.dir.clone();
+
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        generate_key(&dir.join("host_key"));
+        generate_key(&dir.join("user_key"));
+        fs::copy(dir.join("user_key.pub"), dir.join("authorized_keys")).unwrap();
+
+        let port = TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+
+        let sftp_server = if Path::new("/usr/lib/openssh/sftp-server").is_file() {
+            "/usr/lib/openssh/sftp-server"
+        } else {
+            "internal-sftp"
+        };
+
+        fs::write(
+            dir.join("sshd_config"),
+            format!(
+                "ListenAddress 127.0.0.1\nPort {}\nHostKey {}\nAuthorizedKeysFile {}\nPidFile {}\nStrictModes no\nUsePAM no\nSubsystem sftp {}\n",
+                port,
+                dir.join("host_key").display(),
+                dir.join("authorized_keys").display(),
+                dir.join("sshd.pid").display(),
+                sftp_server,
+            ),
+        )
+        .unwrap();
+
+        sshd.child = Some(
+            Command::new(sshd_path)
+                .arg("-D")
+                .arg("-e")
+                .arg("-f")
+                .arg(dir.join("sshd_config"))
+                .stderr(Stdio::null())
+                .spawn()
+                .unwrap(),
+        );
+
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while !network::is_reachable("127.0.0.1", port) && Instant::now() < deadline {
+            thread::sleep(Duration::from_millis(100));
+        }
+
+        let ssh_options = [
+            "-o".to_owned(),
+            "StrictHostKeyChecking=accept-new".to_owned(),
+            "-o".to_owned(),
+            format!("UserKnownHostsFile={}", dir.join("known_hosts").display()),
+        ];
+
+        let definition = FilesystemMountDefinition {
+            host: "127.0.0.1".to_owned(),
+            port,
+            user: env::var("USER").unwrap_or_else(|_| "root".to_owned()),
+            auth_type: AuthType::PublicKey,
+            ssh_key: dir.join("user_key").display().to_string(),
+            remote_path: dir.display().to_string(),
+            ..FilesystemMountDefinition::default()
+        };
+
+        let timeout = Duration::from_secs(20);
+
+        let report = run_with_ssh_options(&definition, &ssh_options, timeout);
+        assert_eq!(report.reachability, CheckResult::Passed);
+        assert_eq!(report.authentication, CheckResult::Passed);
+        assert_eq!(report.remote_path, CheckResult::Passed);
+
+        let report = run_with_ssh_options(
+            &FilesystemMountDefinition {
+                remote_path: dir.join("authorized_keys").display().to_string(),
+                ..definition.clone()
+            },
+            &ssh_options,
+            timeout,
+        );
+        assert_eq!(report.authentication, CheckResult::Passed);
+        assert!(matches!(report.remote_path, CheckResult::Failed(_)));
+
+        let report = run_with_ssh_options(
+            &FilesystemMountDefinition {
+                ssh_key: dir.join("host_key").display().to_string(),
+                ..definition
+            },
+            &ssh_options,
+            timeout,
+        );
+        assert!(matches!(report.authentication, CheckResult::Failed(_)));
+        assert_eq!(report.remote_path, CheckResult::Skipped);
+    }
+
+    fn generate_key(path: &Path) {
+        let status = Command::new("ssh-keygen")
+            .args(["-q", "-t", "ed25519", "-N", "", "-f"])
+            .arg(path)
+            .status()
+            .unwrap();
+
+        assert!(status.success());
+    }
+}
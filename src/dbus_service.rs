@@ -0,0 +1,205 @@
+use std::fmt;
+
+use iced::Subscription;
+use iced::futures::Stream;
+use iced::futures::channel::mpsc;
+use iced::stream;
+use libsftpman::{FilesystemMountDefinition, Manager};
+use zbus::object_server::SignalEmitter;
+use zbus::{Connection, fdo};
+
+use crate::state::load_state;
+use crate::utils::{run_blocking, sftpman_manager};
+
+const BUS_NAME: &str = "io.github.spantaleev.SftpmanIced";
+const OBJECT_PATH: &str = "/io/github/spantaleev/SftpmanIced";
+
+// FilesystemInfo describes a filesystem to D-Bus clients: its id, whether it's mounted and its local mount path.
+type FilesystemInfo = (String, bool, String);
+
+#[derive(Debug, Clone)]
+pub enum DbusServiceEvent {
+    // Ready is produced once the service is exported. The handle can be used for emitting signals.
+    Ready(DbusServiceHandle),
+
+    Mount(FilesystemMountDefinition),
+    Unmount(FilesystemMountDefinition),
+    MountAll,
+    UnmountAll,
+}
+
+struct Service {
+    manager: Manager,
+    output: mpsc::Sender<DbusServiceEvent>,
+}
+
+impl Service {
+    fn send(&mut self, event: DbusServiceEvent) -> fdo::Result<()> {
+        self.output.try_send(event).map_err(|e| {
+            fdo::Error::Failed(format!("The application is not accepting requests: {}", e))
+        })
+    }
+
+    async fn definition(&self, id: String) -> fdo::Result<FilesystemMountDefinition> {
+        let manager = self.manager.clone();
+
+        let state = run_blocking(move || load_state(&manager))
+            .await
+            .map_err(|e| fdo::Error::Failed(e.to_string()))?;
+
+        state
+            .filesystems
+            .into_iter()
+            .map(|fs| fs.definition)
+            .find(|definition| definition.id == id)
+            .ok_or_else(|| fdo::Error::InvalidArgs(format!("No filesystem with id {} exists", id)))
+    }
+}
+
+// Mount and unmount requests are handed over to the application, which performs them like it does for the GUI
+// (asking for passphrases via askpass, showing alerts on failure, etc.).
+// The methods return once the request is accepted. The outcome can be observed via `StateChanged`.
+#[zbus::interface(name = "io.github.spantaleev.SftpmanIced")]
+impl Service {
+    /// Lists all filesystems as (id, mounted, local mount path) tuples.
+    async fn list(&self) -> fdo::Result<Vec<FilesystemInfo>> {
+        filesystems(self.manager.clone()).await
+    }
+
+    /// Requests that the filesystem with the given id be mounted.
+    async fn mount(&mut self, id: String) -> fdo::Result<()> {
+        let definition = self.definition(id).await?;
+        self.send(DbusServiceEvent::Mount(definition))
+    }
+
+    /// Requests that the filesystem with the given id be unmounted.
+    async fn unmount(&mut self, id: String) -> fdo::Result<()> {
+        let definition = self.definition(id).await?;
+        self.send(DbusServiceEvent::Unmount(definition))
+    }
+
+    /// Requests that all filesystems be mounted.
+    async fn mount_all(&mut self) -> fdo::Result<()> {
+        self.send(DbusServiceEvent::MountAll)
+    }
+
+    /// Requests that all filesystems be unmounted.
+    async fn unmount_all(&mut self) -> fdo::Result<()> {
+        self.send(DbusServiceEvent::UnmountAll)
+    }
+
+    /// Emitted whenever filesystems get mounted, unmounted, added or removed. Carries the same list as `List`.
+    #[zbus(signal)]
+    async fn state_changed(
+        emitter: &SignalEmitter<'_>,
+        filesystems: Vec<FilesystemInfo>,
+    ) -> zbus::Result<()>;
+}
+
+async fn filesystems(manager: Manager) -> fdo::Result<Vec<FilesystemInfo>> {
+    // Like the Home page, we skip over invalid definitions instead of failing like `Manager::full_state()`.
+    let state = run_blocking(move || load_state(&manager))
+        .await
+        .map_err(|e| fdo::Error::Failed(e.to_string()))?;
+
+    Ok(state
+        .filesystems
+        .into_iter()
+        .map(|fs| {
+            let path = fs.definition.local_mount_path();
+            (fs.definition.id, fs.mounted, path)
+        })
+        .collect())
+}
+
+/// DbusServiceHandle is used for emitting signals after the service has been exported (see `DbusServiceEvent::Ready`).
+#[derive(Clone)]
+pub struct DbusServiceHandle {
+    connection: Connection,
+    manager: Manager,
+}
+
+impl fmt::Debug for DbusServiceHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DbusServiceHandle").finish_non_exhaustive()
+    }
+}
+
+impl DbusServiceHandle {
+    /// Emits the `StateChanged` signal with the current list of filesystems.
+    pub async fn state_changed(self) {
+        let filesystems = match filesystems(self.manager).await {
+            Ok(filesystems) => filesystems,
+            Err(e) => {
+                log::warn!(
+                    "Failed to load the filesystems list for the D-Bus service: {}",
+                    e
+                );
+                return;
+            }
+        };
+
+        let result = match SignalEmitter::new(&self.connection, OBJECT_PATH) {
+            Ok(emitter) => Service::state_changed(&emitter, filesystems).await,
+            Err(e) => Err(e),
+        };
+
+        if let Err(e) = result {
+            log::warn!("Failed to emit the StateChanged D-Bus signal: {}", e);
+        }
+    }
+}
+
+/// Returns a subscription which exports our D-Bus service (on the session bus) and produces events for requests made through it.
+///
+/// Nothing is produced if the service can't be exported (e.g. D-Bus is not available
+/// or another instance using a different `--config-dir` already exports it).
+pub fn dbus_service_events() -> Subscription<DbusServiceEvent> {
+    Subscription::run(run)
+}
+
+fn run() -> impl Stream<Item = DbusServiceEvent> {
+    stream::channel(16, async |mut output: mpsc::Sender<DbusServiceEvent>| {
+        let manager = match sftpman_manager() {
+            Ok(manager) => manager,
+            Err(e) => {
+                log::warn!("Failed to export the D-Bus service: {}", e);
+                return;
+            }
+        };
+
+        let service = Service {
+            manager: manager.clone(),
+            output: output.clone(),
+        };
+
+        let connection = async {
+            zbus::connection::Builder::session()?
+                .name(BUS_NAME)?
+                .serve_at(OBJECT_PATH, service)?
+                .build()
+                .await
+        }
+        .await;
+
+        // The service runs in the background for as long as we (or the application) hold on to the connection.
+        let _connection = match connection {
+            Ok(connection) => {
+                log::debug!("Exported the D-Bus service as {}", BUS_NAME);
+
+                let _ = output.try_send(DbusServiceEvent::Ready(DbusServiceHandle {
+                    connection: connection.clone(),
+                    manager,
+                }));
+
+                connection
+            }
+            Err(e) => {
+                log::warn!("Failed to export the D-Bus service: {}", e);
+                return;
+            }
+        };
+
+        std::future::pending::<()>().await
+    })
+}
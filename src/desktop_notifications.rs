@@ -0,0 +1,146 @@
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use iced::Subscription;
+use iced::futures::channel::mpsc;
+use iced::futures::{SinkExt, Stream, StreamExt};
+use iced::stream;
+use zbus::Connection;
+use zbus::zvariant::Value;
+
+use crate::notifications::NotificationSeverity;
+use crate::strings;
+
+const APP_NAME: &str = env!("CARGO_PKG_NAME");
+
+// "default" is the action invoked when the notification itself is clicked.
+// Some notification servers also render it as a button.
+const ACTION_SHOW_DETAILS: &str = "default";
+
+// Letting the notification server decide how long notifications stay.
+const EXPIRE_TIMEOUT_DEFAULT: i32 = -1;
+
+const URGENCY_NORMAL: u8 = 1;
+const URGENCY_CRITICAL: u8 = 2;
+
+#[zbus::proxy(
+    interface = "org.freedesktop.Notifications",
+    default_service = "org.freedesktop.Notifications",
+    default_path = "/org/freedesktop/Notifications"
+)]
+trait Notifications {
+    #[allow(clippy::too_many_arguments)]
+    fn notify(
+        &self,
+        app_name: &str,
+        replaces_id: u32,
+        app_icon: &str,
+        summary: &str,
+        body: &str,
+        actions: &[&str],
+        hints: HashMap<&str, Value<'_>>,
+        expire_timeout: i32,
+    ) -> zbus::Result<u32>;
+
+    #[zbus(signal)]
+    fn action_invoked(&self, id: u32, action_key: String) -> zbus::Result<()>;
+}
+
+/// Shows a desktop notification (via the `org.freedesktop.Notifications` D-Bus service).
+///
+/// When `with_details_action` is set, the notification offers an action for showing more details in the application.
+/// Invocations of it are reported via `details_requests()`.
+///
+/// Returns the id assigned to the notification by the notification server.
+pub async fn show(
+    severity: NotificationSeverity,
+    summary: String,
+    body: String,
+    with_details_action: bool,
+) -> Result<u32, String> {
+    let connection = connection().await.map_err(|e| e.to_string())?;
+    let proxy = NotificationsProxy::new(&connection)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let label = strings::desktop_notification_show_details_action_label();
+    let actions = if with_details_action {
+        vec![ACTION_SHOW_DETAILS, label.as_str()]
+    } else {
+        vec![]
+    };
+
+    let urgency = match severity {
+        NotificationSeverity::Error => URGENCY_CRITICAL,
+        _ => URGENCY_NORMAL,
+    };
+
+    let mut hints = HashMap::new();
+    hints.insert("urgency", Value::from(urgency));
+    // This lets notification servers associate the notification with our `.desktop` file (and icon).
+    hints.insert("desktop-entry", Value::from(APP_NAME));
+
+    proxy
+        .notify(
+            APP_NAME,
+            0,
+            APP_NAME,
+            &summary,
+            &body,
+            &actions,
+            hints,
+            EXPIRE_TIMEOUT_DEFAULT,
+        )
+        .await
+        .map_err(|e| e.to_string())
+}
+
+// The session bus connection is shared, so that notifications are sent over the same connection we listen on for actions.
+// Some notification servers only deliver action signals to the connection which sent the notification.
+async fn connection() -> zbus::Result<Connection> {
+    static CONNECTION: OnceLock<Connection> = OnceLock::new();
+
+    if let Some(connection) = CONNECTION.get() {
+        return Ok(connection.clone());
+    }
+
+    let connection = Connection::session().await?;
+
+    Ok(CONNECTION.get_or_init(|| connection).clone())
+}
+
+/// Returns a subscription which produces the (notification server assigned) id of each desktop notification,
+/// whose "show details" action got invoked.
+pub fn details_requests() -> Subscription<u32> {
+    Subscription::run(listen)
+}
+
+fn listen() -> impl Stream<Item = u32> {
+    stream::channel(16, async |mut output: mpsc::Sender<u32>| {
+        let result = async {
+            let connection = connection().await?;
+            let proxy = NotificationsProxy::new(&connection).await?;
+
+            let mut signals = proxy.receive_action_invoked().await?;
+
+            while let Some(signal) = signals.next().await {
+                let args = signal.args()?;
+
+                if args.action_key != ACTION_SHOW_DETAILS {
+                    continue;
+                }
+
+                if output.send(args.id).await.is_err() {
+                    break;
+                }
+            }
+
+            Ok::<(), zbus::Error>(())
+        }
+        .await;
+
+        if let Err(e) = result {
+            log::warn!("Failed to listen for desktop notification actions: {}", e);
+        }
+    })
+}
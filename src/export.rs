@@ -0,0 +1,408 @@
+use libsftpman::{AuthType, FilesystemMountDefinition};
+
+// Like libsftpman, we don't bother running these no-op before-mount commands (sftpman-gtk used to hardcode them).
+const NO_OP_COMMANDS: [&str; 2] = ["/bin/true", "true"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Systemd,
+    Fstab,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportedFile {
+    pub file_name: String,
+    pub contents: String,
+}
+
+/// Export holds a definition converted to configuration for mounting it without us (see `ExportFormat`).
+#[derive(Debug, Clone)]
+pub struct Export {
+    pub id: String,
+    pub format: ExportFormat,
+    pub files: Vec<ExportedFile>,
+}
+
+impl Export {
+    pub fn new(definition: &FilesystemMountDefinition, format: ExportFormat) -> Self {
+        let owner = Owner::current();
+
+        let files = match format {
+            ExportFormat::Systemd => systemd_units(definition, &owner),
+            ExportFormat::Fstab => vec![fstab_entry(definition, &owner)],
+        };
+
+        Self {
+            id: definition.id.clone(),
+            format,
+            files,
+        }
+    }
+
+    /// Returns the contents of all files, one after another.
+    pub fn text(&self) -> String {
+        self.files
+            .iter()
+            .map(|file| file.contents.as_str())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Owner is the user who system-wide mounts (see `system_mount_options()`) are set up for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Owner {
+    pub uid: u32,
+    pub gid: u32,
+
+    // known_hosts is the path to the user's `known_hosts` file, which root is to trust hosts from.
+    pub known_hosts: Option<String>,
+}
+
+impl Owner {
+    /// Returns the owner corresponding to the current user.
+    pub fn current() -> Self {
+        Self {
+            uid: nix::unistd::getuid().as_raw(),
+            gid: nix::unistd::getgid().as_raw(),
+            known_hosts: directories::BaseDirs::new().map(|dirs| {
+                dirs.home_dir()
+                    .join(".ssh")
+                    .join("known_hosts")
+                    .display()
+                    .to_string()
+            }),
+        }
+    }
+}
+
+/// Returns the sshfs options (passed via `-o`) needed for mounting the definition the way libsftpman does.
+fn sshfs_options(definition: &FilesystemMountDefinition) -> Vec<String> {
+    let mut options = vec![format!("port={}", definition.port)];
+
+    // sshfs passes options it doesn't know about to ssh.
+    match &definition.auth_type {
+        AuthType::PublicKey => {
+            options.push(format!(
+                "PreferredAuthentications={}",
+                AuthType::PublicKey.to_static_str()
+            ));
+            options.push(format!("IdentityFile={}", definition.ssh_key));
+        }
+        AuthType::AuthenticationAgent => {}
+        any_other => {
+            options.push(format!(
+                "PreferredAuthentications={}",
+                any_other.to_static_str()
+            ));
+        }
+    }
+
+    options.extend(definition.mount_options.iter().cloned());
+
+    options
+}
+
+/// Returns the options for mounting the definition system-wide (as root), on behalf of the given owner.
+///
+/// Such mounts are only accessible to root, unless they're opened up to others (`allow_other`),
+/// with permissions checked as usual (`default_permissions`) and files appearing to belong to the owner (`uid`, `gid`).
+/// ssh (running as root) uses the owner's SSH key (see `sshfs_options()`) and trusts the hosts they trust.
+fn system_mount_options(definition: &FilesystemMountDefinition, owner: &Owner) -> Vec<String> {
+    let mut options = vec![
+        "_netdev".to_owned(),
+        "allow_other".to_owned(),
+        "default_permissions".to_owned(),
+        format!("uid={}", owner.uid),
+        format!("gid={}", owner.gid),
+    ];
+
+    if let Some(known_hosts) = &owner.known_hosts {
+        options.push(format!("UserKnownHostsFile={}", known_hosts));
+    }
+
+    options.extend(sshfs_options(definition));
+
+    options
+}
+
+// Returns a comment warning about system-wide mounts (see `system_mount_options()`) not being able to authenticate
+// with anything other than an SSH key, as root has no access to the user's SSH agent and nobody can be asked for a password.
+fn system_mount_auth_warning(definition: &FilesystemMountDefinition) -> &'static str {
+    if definition.auth_type == AuthType::PublicKey {
+        return "";
+    }
+
+    "# Warning: mounting as root can't use your SSH agent or ask for a password,\n\
+     # so this only works with an SSH key (without a passphrase).\n"
+}
+
+fn remote(definition: &FilesystemMountDefinition) -> String {
+    // Like libsftpman, we use `[]` around the host, so that IPv6 addresses (containing `:`) work.
+    format!(
+        "{}@[{}]:{}",
+        definition.user, definition.host, definition.remote_path
+    )
+}
+
+fn before_mount_command(definition: &FilesystemMountDefinition) -> Option<&str> {
+    let command = definition.cmd_before_mount.trim();
+
+    (!command.is_empty() && !NO_OP_COMMANDS.contains(&command)).then_some(command)
+}
+
+/// Returns a pair of (system) systemd units for mounting the definition system-wide, on behalf of the given owner.
+///
+/// The `.mount` does the mounting (see `system_mount_options()`) and pulls in the `.service`,
+/// which prepares for it, like libsftpman does before mounting (creating the mount point, running the before-mount command).
+pub fn systemd_units(definition: &FilesystemMountDefinition, owner: &Owner) -> Vec<ExportedFile> {
+    let path = definition.local_mount_path();
+
+    let service_name = format!("sftpman-{}.service", definition.id);
+
+    // Mount units need to be named after the path they mount.
+    let mount_name = format!("{}.mount", systemd_escape_path(&path));
+
+    // The `+` prefix makes the mount point get created as root (regardless of `User=`), as its parent may belong to root.
+    let mut exec_start = vec![format!("ExecStart=+mkdir -p {}", systemd_quote(&path))];
+    if let Some(command) = before_mount_command(definition) {
+        // The command is not quoted, because (like libsftpman) we treat it as space-separated arguments.
+        exec_start.push(format!("ExecStart={}", command.replace('%', "%%")));
+    }
+
+    let service = format!(
+        "# /etc/systemd/system/{service_name}\n\
+         # Prepares for mounting the filesystem. It's pulled in by {mount_name}, so it doesn't need to be enabled.\n\
+         [Unit]\n\
+         Description=sftpman: {id} (preparation)\n\
+         After=network-online.target\n\
+         Wants=network-online.target\n\
+         \n\
+         [Service]\n\
+         Type=oneshot\n\
+         RemainAfterExit=yes\n\
+         # The before-mount command (if any) runs as your user, like it does when mounting via sftpman.\n\
+         User={uid}\n\
+         Group={gid}\n\
+         {exec_start}\n",
+        id = definition.id,
+        uid = owner.uid,
+        gid = owner.gid,
+        exec_start = exec_start.join("\n"),
+    );
+
+    let mount = format!(
+        "# /etc/systemd/system/{mount_name}\n\
+         # Mounts the filesystem system-wide (as root, on boot), with its files belonging to your user.\n\
+         {auth_warning}\
+         # Install it along with {service_name} (into the same directory) and enable it with:\n\
+         #   systemctl daemon-reload && systemctl enable --now {mount_name}\n\
+         [Unit]\n\
+         Description=sftpman: {id}\n\
+         Requires={service_name}\n\
+         After={service_name} network-online.target\n\
+         Wants=network-online.target\n\
+         \n\
+         [Mount]\n\
+         What={remote}\n\
+         Where={path}\n\
+         Type=fuse.sshfs\n\
+         Options={options}\n\
+         \n\
+         [Install]\n\
+         WantedBy=remote-fs.target\n",
+        id = definition.id,
+        auth_warning = system_mount_auth_warning(definition),
+        remote = remote(definition).replace('%', "%%"),
+        path = path.replace('%', "%%"),
+        options = system_mount_options(definition, owner)
+            .join(",")
+            .replace('%', "%%"),
+    );
+
+    vec![
+        ExportedFile {
+            file_name: service_name,
+            contents: service,
+        },
+        ExportedFile {
+            file_name: mount_name,
+            contents: mount,
+        },
+    ]
+}
+
+/// Returns an `/etc/fstab` entry for mounting the definition system-wide (as root), on behalf of the owner.
+pub fn fstab_entry(definition: &FilesystemMountDefinition, owner: &Owner) -> ExportedFile {
+    let contents = format!(
+        "# sftpman: {id}\n\
+         {auth_warning}\
+         sshfs#{remote} {path} fuse {options} 0 0\n",
+        id = definition.id,
+        auth_warning = system_mount_auth_warning(definition),
+        remote = fstab_escape(&remote(definition)),
+        path = fstab_escape(&definition.local_mount_path()),
+        options = fstab_escape(&system_mount_options(definition, owner).join(",")),
+    );
+
+    ExportedFile {
+        file_name: format!("{}.fstab", definition.id),
+        contents,
+    }
+}
+
+// Fields in fstab are whitespace-separated, so whitespace within them needs to be escaped (as octal).
+fn fstab_escape(value: &str) -> String {
+    value
+        .replace('\\', "\\134")
+        .replace(' ', "\\040")
+        .replace('\t', "\\011")
+}
+
+// Quotes a command-line argument for use in systemd's `Exec*=` directives.
+fn systemd_quote(value: &str) -> String {
+    let value = value.replace('%', "%%");
+
+    if !value.contains(|c: char| c.is_whitespace() || c == '"' || c == '\'' || c == '\\') {
+        return value;
+    }
+
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+// Escapes a path the way `systemd-escape --path` does, which is how mount units need to be named.
+fn systemd_escape_path(path: &str) -> String {
+    let trimmed = path.trim_matches('/');
+
+    if trimmed.is_empty() {
+        return "-".to_owned();
+    }
+
+    let mut escaped = String::new();
+
+    for (i, byte) in trimmed.bytes().enumerate() {
+        match byte {
+            b'/' => escaped.push('-'),
+            b'.' if i == 0 => escaped.push_str("\\x2e"),
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b':' | b'_' | b'.' => {
+                escaped.push(byte as char)
+            }
+            _ => escaped.push_str(&format!("\\x{:02x}", byte)),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use libsftpman::{AuthType, FilesystemMountDefinition};
+
+    use super::{Owner, fstab_entry, systemd_escape_path, systemd_units};
+
+    fn definition() -> FilesystemMountDefinition {
+        FilesystemMountDefinition {
+            id: "example".to_owned(),
+            host: "example.com".to_owned(),
+            port: 2222,
+            user: "user".to_owned(),
+            mount_options: vec!["follow_symlinks".to_owned()],
+            remote_path: "/home/user".to_owned(),
+            mount_dest_path: Some("/mnt/my files".to_owned()),
+            cmd_before_mount: "/bin/true".to_owned(),
+            auth_type: AuthType::PublicKey,
+            ssh_key: "/home/user/.ssh/id_ed25519".to_owned(),
+        }
+    }
+
+    fn owner() -> Owner {
+        Owner {
+            uid: 1000,
+            gid: 100,
+            known_hosts: Some("/home/user/.ssh/known_hosts".to_owned()),
+        }
+    }
+
+    #[test]
+    fn test_systemd_escape_path() {
+        assert_eq!(
+            systemd_escape_path("/mnt/sshfs/example"),
+            "mnt-sshfs-example"
+        );
+        assert_eq!(systemd_escape_path("/mnt/my files/"), "mnt-my\\x20files");
+        assert_eq!(systemd_escape_path("/mnt/a-b"), "mnt-a\\x2db");
+        assert_eq!(systemd_escape_path("/"), "-");
+    }
+
+    #[test]
+    fn test_systemd_units() {
+        let mut definition = definition();
+        definition.cmd_before_mount = "/usr/local/bin/wake-up example.com".to_owned();
+
+        let files = systemd_units(&definition, &owner());
+
+        assert_eq!(files.len(), 2);
+
+        assert_eq!(files[0].file_name, "sftpman-example.service");
+        assert!(files[0].contents.contains("Type=oneshot\n"));
+        assert!(files[0].contents.contains("User=1000\nGroup=100\n"));
+        assert!(files[0].contents.contains(
+            "ExecStart=+mkdir -p \"/mnt/my files\"\nExecStart=/usr/local/bin/wake-up example.com\n"
+        ));
+
+        assert_eq!(files[1].file_name, "mnt-my\\x20files.mount");
+        assert!(
+            files[1]
+                .contents
+                .contains("What=user@[example.com]:/home/user\n")
+        );
+        assert!(files[1].contents.contains("Where=/mnt/my files\n"));
+        assert!(files[1].contents.contains("Type=fuse.sshfs\n"));
+
+        // The mount pulls in (and waits for) the service, which prepares for it.
+        assert!(
+            files[1]
+                .contents
+                .contains("Requires=sftpman-example.service\nAfter=sftpman-example.service ")
+        );
+    }
+
+    #[test]
+    fn test_systemd_units_skip_no_op_before_mount_command() {
+        let files = systemd_units(&definition(), &owner());
+
+        assert!(!files[0].contents.contains("/bin/true"));
+    }
+
+    #[test]
+    fn test_systemd_mount_options() {
+        let files = systemd_units(&definition(), &owner());
+
+        // The mount is made by root, so it needs to be opened up to the owner, who root also takes SSH settings from.
+        assert!(files[1].contents.contains(
+            "Options=_netdev,allow_other,default_permissions,uid=1000,gid=100,UserKnownHostsFile=/home/user/.ssh/known_hosts,port=2222,PreferredAuthentications=publickey,IdentityFile=/home/user/.ssh/id_ed25519,follow_symlinks\n"
+        ));
+        assert!(!files[1].contents.contains("Warning:"));
+
+        let mut definition = definition();
+        definition.auth_type = AuthType::AuthenticationAgent;
+
+        let files = systemd_units(&definition, &owner());
+
+        // Root has no access to the owner's SSH agent.
+        assert!(files[1].contents.contains("Warning:"));
+    }
+
+    #[test]
+    fn test_fstab_entry() {
+        let mut definition = definition();
+        definition.auth_type = AuthType::AuthenticationAgent;
+
+        let file = fstab_entry(&definition, &owner());
+
+        assert_eq!(
+            file.contents.lines().last().unwrap(),
+            "sshfs#user@[example.com]:/home/user /mnt/my\\040files fuse _netdev,allow_other,default_permissions,uid=1000,gid=100,UserKnownHostsFile=/home/user/.ssh/known_hosts,port=2222,follow_symlinks 0 0"
+        );
+    }
+}
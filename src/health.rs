@@ -0,0 +1,208 @@
+use std::collections::HashSet;
+use std::fs;
+use std::process::Command;
+use std::sync::{Arc, Mutex, mpsc};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use libsftpman::{FilesystemMountDefinition, SftpManError};
+
+use crate::strings;
+
+/// How often mounted filesystems are probed (see `probe()`).
+pub const PROBE_INTERVAL: Duration = Duration::from_secs(30);
+
+// How long a mount point may take to respond, before we consider the filesystem dead.
+// A stat on a healthy sshfs mount is answered from its cache (or with a single round-trip), so this is generous.
+const PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Health {
+    Healthy,
+
+    // Broken means the filesystem is still mounted, but accessing it fails or hangs.
+    // This is what happens to sshfs mounts when the connection drops (e.g. "Transport endpoint is not connected").
+    Broken(String),
+}
+
+/// Prober checks whether mounted filesystems respond (see `Prober::probe()`).
+///
+/// It remembers which mount points are still being stat-ed, so that a dead mount doesn't get a new thread stuck on it with each probe.
+#[derive(Debug, Clone, Default)]
+pub struct Prober {
+    // in_flight contains the ids of filesystems whose mount point is being stat-ed (possibly by a thread abandoned by an earlier probe).
+    in_flight: Arc<Mutex<HashSet<String>>>,
+}
+
+impl Prober {
+    /// Checks whether the given (mounted) filesystems respond, by stat-ing their mount points.
+    ///
+    /// Each mount point is stat-ed on its own thread, because a dead mount may block forever.
+    /// Threads that don't finish within `PROBE_TIMEOUT` are abandoned (they exit whenever the stat returns).
+    /// Until they do, their filesystems are reported as not responding, without being stat-ed again.
+    pub fn probe(&self, definitions: Vec<FilesystemMountDefinition>) -> Vec<(String, Health)> {
+        let (sender, receiver) = mpsc::channel();
+
+        let mut probing = Vec::with_capacity(definitions.len());
+
+        for definition in &definitions {
+            if !self.in_flight.lock().unwrap().insert(definition.id.clone()) {
+                log::debug!("{}: still waiting for an earlier probe", definition.id);
+                continue;
+            }
+
+            probing.push(definition.id.clone());
+
+            let sender = sender.clone();
+            let in_flight = self.in_flight.clone();
+            let id = definition.id.clone();
+            let path = definition.local_mount_path();
+
+            thread::spawn(move || {
+                let health = match fs::metadata(&path) {
+                    Ok(_) => Health::Healthy,
+                    Err(e) => Health::Broken(e.to_string()),
+                };
+
+                in_flight.lock().unwrap().remove(&id);
+
+                let _ = sender.send((id, health));
+            });
+        }
+
+        drop(sender);
+
+        let deadline = Instant::now() + PROBE_TIMEOUT;
+
+        let mut results = Vec::with_capacity(definitions.len());
+
+        while results.len() < probing.len() {
+            let timeout = deadline.saturating_duration_since(Instant::now());
+
+            match receiver.recv_timeout(timeout) {
+                Ok(result) => results.push(result),
+                Err(_) => break,
+            }
+        }
+
+        for definition in definitions {
+            if !results.iter().any(|(id, _)| id == &definition.id) {
+                results.push((
+                    definition.id,
+                    Health::Broken(strings::health_not_responding_error(
+                        PROBE_TIMEOUT.as_secs(),
+                    )),
+                ));
+            }
+        }
+
+        results
+    }
+}
+
+/// Returns the commands for lazily unmounting the filesystem (`fusermount -u -z`).
+///
+/// Unlike a regular unmount, a lazy one succeeds even if the filesystem is dead or busy.
+/// We reuse the program from `FilesystemMountDefinition::umount_commands()`, so that the same `fusermount` variant is used.
+// The error type is libsftpman's, so that the result can be used like `umount_commands()`'s (see `commands_to_string()`).
+#[allow(clippy::result_large_err)]
+pub fn lazy_umount_commands(
+    definition: &FilesystemMountDefinition,
+) -> Result<Vec<Command>, SftpManError> {
+    Ok(definition
+        .umount_commands()?
+        .iter()
+        .map(|cmd| {
+            let mut lazy_cmd = Command::new(cmd.get_program());
+            lazy_cmd
+                .arg("-u")
+                .arg("-z")
+                .arg(definition.local_mount_path());
+            lazy_cmd
+        })
+        .collect())
+}
+
+/// Lazily unmounts the filesystem (see `lazy_umount_commands()`).
+pub fn lazy_umount(definition: &FilesystemMountDefinition) -> Result<(), String> {
+    log::info!("{0}: unmounting lazily..", definition.id);
+
+    for mut cmd in lazy_umount_commands(definition).map_err(|e| e.to_string())? {
+        let output = cmd
+            .output()
+            .map_err(|e| format!("{:?}: {}", cmd.get_program(), e))?;
+
+        if !output.status.success() {
+            return Err(format!(
+                "{:?} exited with {}: {}",
+                cmd.get_program(),
+                output.status,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use libsftpman::FilesystemMountDefinition;
+
+    use super::{Health, Prober, lazy_umount_commands};
+
+    #[test]
+    fn test_probe() {
+        let healthy = FilesystemMountDefinition {
+            id: "healthy".to_owned(),
+            mount_dest_path: Some(std::env::temp_dir().display().to_string()),
+            ..FilesystemMountDefinition::default()
+        };
+
+        let missing = FilesystemMountDefinition {
+            id: "missing".to_owned(),
+            mount_dest_path: Some("/nonexistent/sftpman-iced-test".to_owned()),
+            ..FilesystemMountDefinition::default()
+        };
+
+        let prober = Prober::default();
+
+        let results = prober.probe(vec![healthy.clone(), missing]);
+
+        assert_eq!(results.len(), 2);
+        assert!(results.contains(&("healthy".to_owned(), Health::Healthy)));
+        assert!(
+            results
+                .iter()
+                .any(|(id, health)| id == "missing" && matches!(health, Health::Broken(_)))
+        );
+
+        // A mount point which is still being stat-ed (as if it hung during an earlier probe) is not stat-ed again.
+        prober
+            .in_flight
+            .lock()
+            .unwrap()
+            .insert("healthy".to_owned());
+
+        let results = prober.probe(vec![healthy]);
+
+        assert!(matches!(results.as_slice(), [(id, Health::Broken(_))] if id == "healthy"));
+    }
+
+    #[test]
+    fn test_lazy_umount_commands() {
+        let definition = FilesystemMountDefinition {
+            id: "example".to_owned(),
+            mount_dest_path: Some("/mnt/example".to_owned()),
+            ..FilesystemMountDefinition::default()
+        };
+
+        let commands = lazy_umount_commands(&definition).unwrap();
+
+        assert_eq!(commands.len(), 1);
+        assert_eq!(
+            commands[0].get_args().collect::<Vec<_>>(),
+            ["-u", "-z", "/mnt/example"]
+        );
+    }
+}
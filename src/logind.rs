@@ -0,0 +1,211 @@
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use iced::Subscription;
+use iced::futures::channel::mpsc;
+use iced::futures::{SinkExt, Stream, StreamExt};
+use iced::stream;
+use zbus::Connection;
+use zbus::zvariant::OwnedFd;
+
+use crate::strings;
+
+const APP_NAME: &str = env!("CARGO_PKG_NAME");
+
+#[zbus::proxy(
+    interface = "org.freedesktop.login1.Manager",
+    default_service = "org.freedesktop.login1",
+    default_path = "/org/freedesktop/login1"
+)]
+trait LoginManager {
+    fn inhibit(&self, what: &str, who: &str, why: &str, mode: &str) -> zbus::Result<OwnedFd>;
+
+    #[zbus(signal)]
+    fn prepare_for_sleep(&self, start: bool) -> zbus::Result<()>;
+}
+
+#[derive(Debug, Clone)]
+pub enum SleepEvent {
+    // Suspending is produced when the system is about to sleep.
+    // Sleeping is delayed until the inhibitor is released (or logind's `InhibitDelayMaxSec` passes).
+    Suspending(SleepInhibitor),
+
+    Resumed,
+}
+
+/// SleepInhibitor holds a logind delay inhibitor lock (a file descriptor), which delays sleeping until released.
+#[derive(Clone, Default)]
+pub struct SleepInhibitor(Arc<Mutex<Option<OwnedFd>>>);
+
+impl SleepInhibitor {
+    /// Releases the lock (if still held), letting the system go to sleep.
+    pub fn release(&self) {
+        if let Ok(mut fd) = self.0.lock()
+            && fd.take().is_some()
+        {
+            log::debug!("Released the sleep inhibitor lock");
+        }
+    }
+}
+
+impl fmt::Debug for SleepInhibitor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SleepInhibitor").finish_non_exhaustive()
+    }
+}
+
+/// Returns a subscription which produces events for the system going to sleep and resuming (via logind, on the system bus).
+///
+/// Nothing is produced if logind is not available.
+pub fn sleep_events() -> Subscription<SleepEvent> {
+    Subscription::run(run)
+}
+
+fn run() -> impl Stream<Item = SleepEvent> {
+    stream::channel(16, async |output: mpsc::Sender<SleepEvent>| {
+        let result = async {
+            let connection = Connection::system().await?;
+            watch(&connection, output).await
+        }
+        .await;
+
+        if let Err(e) = result {
+            log::warn!("Failed to listen for sleep events via logind: {}", e);
+        }
+    })
+}
+
+/// Takes a delay inhibitor lock from logind (over the given connection) and sends sleep events to `output`,
+/// for as long as the connection works and `output` accepts them.
+///
+/// A new lock is taken after resuming, because the previous one gets released while suspending.
+async fn watch(connection: &Connection, mut output: mpsc::Sender<SleepEvent>) -> zbus::Result<()> {
+    let proxy = LoginManagerProxy::new(connection).await?;
+
+    // Subscribing before taking the lock, so that no signal can be missed in-between.
+    let mut signals = proxy.receive_prepare_for_sleep().await?;
+
+    let mut inhibitor = inhibit(&proxy).await?;
+
+    while let Some(signal) = signals.next().await {
+        let event = if signal.args()?.start {
+            SleepEvent::Suspending(std::mem::take(&mut inhibitor))
+        } else {
+            inhibitor = inhibit(&proxy).await?;
+            SleepEvent::Resumed
+        };
+
+        if output.send(event).await.is_err() {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+async fn inhibit(proxy: &LoginManagerProxy<'_>) -> zbus::Result<SleepInhibitor> {
+    let fd = proxy
+        .inhibit(
+            "sleep",
+            APP_NAME,
+            &strings::logind_inhibitor_reason(),
+            "delay",
+        )
+        .await?;
+
+    Ok(SleepInhibitor(Arc::new(Mutex::new(Some(fd)))))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    use iced::futures::StreamExt;
+    use iced::futures::channel::mpsc;
+    use zbus::connection::Builder;
+    use zbus::object_server::SignalEmitter;
+    use zbus::zvariant::OwnedFd;
+    use zbus::{Connection, Guid};
+
+    use super::{SleepEvent, watch};
+
+    const PATH: &str = "/org/freedesktop/login1";
+
+    // StandIn plays the part of logind, so that we don't need (and don't affect) the real one.
+    struct StandIn {
+        inhibit_calls: Arc<AtomicUsize>,
+    }
+
+    #[zbus::interface(name = "org.freedesktop.login1.Manager")]
+    impl StandIn {
+        fn inhibit(&self, what: &str, _who: &str, _why: &str, mode: &str) -> OwnedFd {
+            assert_eq!((what, mode), ("sleep", "delay"));
+
+            self.inhibit_calls.fetch_add(1, Ordering::SeqCst);
+
+            std::os::fd::OwnedFd::from(File::open("/dev/null").unwrap()).into()
+        }
+
+        #[zbus(signal)]
+        async fn prepare_for_sleep(emitter: &SignalEmitter<'_>, start: bool) -> zbus::Result<()>;
+    }
+
+    async fn connect(inhibit_calls: Arc<AtomicUsize>) -> (Connection, Connection) {
+        let (server_stream, client_stream) = tokio::net::UnixStream::pair().unwrap();
+
+        let server = async {
+            Builder::unix_stream(server_stream)
+                .server(Guid::generate())?
+                .p2p()
+                .serve_at(PATH, StandIn { inhibit_calls })?
+                .build()
+                .await
+        };
+
+        let client = Builder::unix_stream(client_stream).p2p().build();
+
+        let (server, client) = tokio::join!(server, client);
+
+        (server.unwrap(), client.unwrap())
+    }
+
+    async fn wait_for_inhibit_calls(inhibit_calls: &AtomicUsize, expected: usize) {
+        while inhibit_calls.load(Ordering::SeqCst) < expected {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_watch() {
+        let inhibit_calls = Arc::new(AtomicUsize::new(0));
+
+        let (server, client) = connect(inhibit_calls.clone()).await;
+
+        let (sender, mut receiver) = mpsc::channel(16);
+        tokio::spawn(async move { watch(&client, sender).await });
+
+        wait_for_inhibit_calls(&inhibit_calls, 1).await;
+
+        let emitter = SignalEmitter::new(&server, PATH).unwrap();
+
+        StandIn::prepare_for_sleep(&emitter, true).await.unwrap();
+
+        let Some(SleepEvent::Suspending(inhibitor)) = receiver.next().await else {
+            panic!("Expected a Suspending event");
+        };
+        assert!(inhibitor.0.lock().unwrap().is_some());
+
+        inhibitor.release();
+        assert!(inhibitor.0.lock().unwrap().is_none());
+
+        StandIn::prepare_for_sleep(&emitter, false).await.unwrap();
+
+        assert!(matches!(receiver.next().await, Some(SleepEvent::Resumed)));
+
+        // A new lock is taken for the next time the system goes to sleep.
+        wait_for_inhibit_calls(&inhibit_calls, 2).await;
+    }
+}
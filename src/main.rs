@@ -1,11 +1,40 @@
+mod activity_log;
 mod application;
 mod assets;
+mod autostart;
+mod batch;
+mod busy;
+mod cli;
+mod connection_test;
+#[cfg(feature = "dbus-service")]
+mod dbus_service;
+#[cfg(feature = "desktop-notifications")]
+mod desktop_notifications;
+mod export;
+mod health;
 mod locale;
+#[cfg(feature = "logind")]
+mod logind;
 mod messages;
+mod mount;
+mod network;
+mod notifications;
+mod opener;
 mod pages;
+mod reconnect;
+mod settings;
+mod sftp;
+mod single_instance;
+mod ssh_config;
+mod state;
 mod strings;
+mod suggestions;
+mod terminal;
+#[cfg(feature = "tray")]
+mod tray;
 mod ui_config;
 mod utils;
+mod watcher;
 mod widgets;
 
 #[macro_use]
@@ -14,8 +43,16 @@ i18n!("locales", fallback = "en");
 
 use crate::application::run_application;
 use crate::locale::detect_locale;
+use crate::single_instance::Instance;
 
 pub fn main() -> iced::Result {
+    let options = cli::parse();
+
+    // This needs to happen before anything else, while we're still single-threaded.
+    if let Some(config_dir) = &options.config_dir {
+        utils::use_config_dir(config_dir);
+    }
+
     let mut builder = env_logger::Builder::new();
 
     let logging_directive = match std::env::var("RUST_LOG") {
@@ -28,5 +65,19 @@ pub fn main() -> iced::Result {
 
     rust_i18n::set_locale(&detect_locale());
 
-    run_application()
+    // Only one instance runs at a time. Starting another one forwards its arguments to the running one.
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    match single_instance::acquire(&args) {
+        Ok(Instance::Secondary) => {
+            log::info!("Another instance is already running. Forwarded our arguments to it");
+            return Ok(());
+        }
+        Ok(Instance::Primary) => {}
+        Err(e) => log::warn!(
+            "Failed to set up single-instance mode, continuing anyway: {}",
+            e
+        ),
+    }
+
+    run_application(options)
 }
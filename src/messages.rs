@@ -1,10 +1,16 @@
 use crate::application::ApplicationMessage;
 use crate::pages::HomeMessage;
 use crate::pages::RecordMessage;
+use crate::pages::SettingsMessage;
+use crate::pages::SshImportMessage;
+use crate::pages::StartupErrorMessage;
 
 #[derive(Debug, Clone)]
 pub enum Message {
     Application(ApplicationMessage),
     Home(HomeMessage),
     Record(RecordMessage),
+    Settings(SettingsMessage),
+    SshImport(SshImportMessage),
+    StartupError(StartupErrorMessage),
 }
@@ -0,0 +1,375 @@
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
+use std::io::{Read, Seek, SeekFrom};
+use std::os::unix::process::CommandExt;
+use std::process::{Child, Command, Output, Stdio};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use nix::sys::signal::{Signal, killpg};
+use nix::unistd::Pid;
+
+use libsftpman::{FilesystemMountDefinition, Manager, SftpManError};
+
+use crate::busy::{BusyProcess, processes_using};
+
+// How often we check whether a mount command finished, got cancelled or timed out.
+const POLL_INTERVAL_MS: u64 = 100;
+
+// How long we give processes to exit after asking them to (SIGTERM), before killing them forcefully (SIGKILL).
+const TERMINATION_GRACE_PERIOD_MS: u64 = 1000;
+
+/// CancellationToken lets one thread ask an operation running on another thread to stop.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum MountError {
+    Cancelled,
+    TimedOut(Duration),
+    Failed { human: String, debug: String },
+}
+
+impl From<SftpManError> for MountError {
+    fn from(err: SftpManError) -> Self {
+        Self::Failed {
+            human: err.to_string(),
+            debug: format!("{:?}", err),
+        }
+    }
+}
+
+impl fmt::Display for MountError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Cancelled => write!(f, "mounting was cancelled"),
+            Self::TimedOut(timeout) => {
+                write!(f, "mounting timed out after {} seconds", timeout.as_secs())
+            }
+            Self::Failed { human, .. } => write!(f, "{}", human),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct UnmountError {
+    pub human: String,
+    pub debug: String,
+
+    // busy_processes lists the processes using the filesystem, which is the likely reason for the failure.
+    pub busy_processes: Vec<BusyProcess>,
+}
+
+impl From<SftpManError> for UnmountError {
+    fn from(err: SftpManError) -> Self {
+        Self {
+            human: err.to_string(),
+            debug: format!("{:?}", err),
+            busy_processes: vec![],
+        }
+    }
+}
+
+/// Unmounts the given filesystem definition, like `Manager::umount()` does.
+///
+/// When `fusermount -u` fails (most likely, because the filesystem is busy), libsftpman kills the `sshfs` process
+/// and reports success. We'd rather report the failure along with the processes keeping the filesystem busy,
+/// so that the user can decide what to do, so we run the same commands (`FilesystemMountDefinition::umount_commands()`) ourselves.
+pub fn unmount(
+    manager: &Manager,
+    definition: &FilesystemMountDefinition,
+) -> Result<(), UnmountError> {
+    if !manager.is_definition_mounted(definition)? {
+        log::info!("{0}: not mounted, nothing to do..", definition.id);
+        return Ok(());
+    }
+
+    log::info!("{0}: unmounting..", definition.id);
+
+    let local_mount_path = definition.local_mount_path();
+
+    for mut cmd in definition.umount_commands()? {
+        log::debug!("{0}: executing unmount command: {1:?}", definition.id, cmd);
+
+        let result = match cmd.output() {
+            Ok(output) if output.status.success() => Ok(()),
+            Ok(output) => Err(SftpManError::CommandUnsuccessful(cmd, output)),
+            Err(err) => Err(SftpManError::CommandExecution(cmd, err)),
+        };
+
+        if let Err(err) = result {
+            log::warn!("{0}: failed to get unmounted: {1:?}", definition.id, err);
+
+            return Err(UnmountError {
+                busy_processes: processes_using(&local_mount_path),
+                ..err.into()
+            });
+        }
+    }
+
+    // Like libsftpman, we remove the mount point after unmounting.
+    // This only succeeds if the directory is empty, which is what we want.
+    let _ = fs::remove_dir(&local_mount_path);
+
+    Ok(())
+}
+
+/// Mounts the given filesystem definition, like `Manager::mount()` does,
+/// but allows the operation to be cancelled and aborts it if it takes longer than `timeout`.
+///
+/// libsftpman runs the mount commands to completion and offers no way to interrupt them,
+/// so we run the same commands (`FilesystemMountDefinition::mount_commands()`) ourselves.
+pub fn mount(
+    manager: &Manager,
+    definition: &FilesystemMountDefinition,
+    timeout: Option<Duration>,
+    cancellation: &CancellationToken,
+) -> Result<(), MountError> {
+    if manager.is_definition_mounted(definition)? {
+        log::info!("{0}: already mounted, nothing to do..", definition.id);
+        return Ok(());
+    }
+
+    log::info!("{0}: mounting..", definition.id);
+
+    let local_mount_path = definition.local_mount_path();
+
+    fs::create_dir_all(&local_mount_path)
+        .map_err(|err| SftpManError::IO(local_mount_path.clone().into(), err))?;
+
+    let commands = definition.mount_commands()?;
+
+    if let Err(err) = run_commands(commands, timeout, cancellation) {
+        log::error!("{0}: failed to mount: {1}", definition.id, err);
+
+        // Like libsftpman, we try to clean up after a failed mount.
+        // This will most likely do nothing, but a timed out sshfs may have mounted partially.
+        if let Err(err) = manager.umount(definition) {
+            log::debug!(
+                "{0}: failed to perform cleanup-umount: {1:?}",
+                definition.id,
+                err
+            );
+        }
+
+        // This only succeeds if the directory is empty, which is what we want.
+        let _ = fs::remove_dir(&local_mount_path);
+
+        return Err(err);
+    }
+
+    Ok(())
+}
+
+fn run_commands(
+    commands: Vec<Command>,
+    timeout: Option<Duration>,
+    cancellation: &CancellationToken,
+) -> Result<(), MountError> {
+    // The timeout applies to all commands together, as that's what the user perceives as "mounting".
+    let started_at = Instant::now();
+
+    for mut cmd in commands {
+        log::debug!("Executing mount command: {:?}", cmd);
+
+        // The output goes to files (instead of pipes), because sshfs daemonizes after mounting
+        // and its ssh child process may hold on to the output for as long as the filesystem is mounted.
+        // Reading from a pipe until it gets closed would block until unmounting.
+        let (stdout, stderr) = match (output_file(), output_file()) {
+            (Ok(stdout), Ok(stderr)) => (stdout, stderr),
+            (Err(err), _) | (_, Err(err)) => {
+                return Err(SftpManError::CommandExecution(cmd, err).into());
+            }
+        };
+
+        let spawn_result = match (stdout.try_clone(), stderr.try_clone()) {
+            (Ok(stdout_clone), Ok(stderr_clone)) => cmd
+                .stdin(Stdio::null())
+                .stdout(stdout_clone)
+                .stderr(stderr_clone)
+                // A process group of its own lets us terminate the command along with
+                // everything it spawned (ssh, askpass dialogs, etc.)
+                .process_group(0)
+                .spawn(),
+            (Err(err), _) | (_, Err(err)) => Err(err),
+        };
+
+        let mut child = match spawn_result {
+            Ok(child) => child,
+            Err(err) => return Err(SftpManError::CommandExecution(cmd, err).into()),
+        };
+
+        loop {
+            match child.try_wait() {
+                Ok(Some(status)) => {
+                    if status.success() {
+                        break;
+                    }
+
+                    let output = Output {
+                        status,
+                        stdout: read_output_file(stdout),
+                        stderr: read_output_file(stderr),
+                    };
+
+                    return Err(SftpManError::CommandUnsuccessful(cmd, output).into());
+                }
+                Ok(None) => {}
+                Err(err) => {
+                    terminate(&mut child);
+                    return Err(SftpManError::CommandExecution(cmd, err).into());
+                }
+            }
+
+            if cancellation.is_cancelled() {
+                terminate(&mut child);
+                return Err(MountError::Cancelled);
+            }
+
+            if let Some(timeout) = timeout
+                && started_at.elapsed() >= timeout
+            {
+                terminate(&mut child);
+                return Err(MountError::TimedOut(timeout));
+            }
+
+            thread::sleep(Duration::from_millis(POLL_INTERVAL_MS));
+        }
+    }
+
+    Ok(())
+}
+
+/// Terminates the child and its whole process group, gracefully at first.
+///
+/// The child is expected to have been spawned in a process group of its own (see `CommandExt::process_group()`).
+pub fn terminate(child: &mut Child) {
+    let pgid = Pid::from_raw(child.id() as i32);
+
+    if let Err(err) = killpg(pgid, Signal::SIGTERM) {
+        log::debug!("Failed to send SIGTERM to process group {}: {}", pgid, err);
+    }
+
+    let deadline = Instant::now() + Duration::from_millis(TERMINATION_GRACE_PERIOD_MS);
+
+    while Instant::now() < deadline {
+        if let Ok(Some(_)) = child.try_wait() {
+            return;
+        }
+
+        thread::sleep(Duration::from_millis(POLL_INTERVAL_MS));
+    }
+
+    if let Err(err) = killpg(pgid, Signal::SIGKILL) {
+        log::debug!("Failed to send SIGKILL to process group {}: {}", pgid, err);
+    }
+
+    let _ = child.wait();
+}
+
+/// Creates an anonymous (already deleted) file for capturing a command's output.
+pub fn output_file() -> std::io::Result<File> {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    let path = std::env::temp_dir().join(format!(
+        "{}-{}-{}.out",
+        env!("CARGO_PKG_NAME"),
+        std::process::id(),
+        COUNTER.fetch_add(1, Ordering::SeqCst)
+    ));
+
+    let file = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create_new(true)
+        .open(&path)?;
+
+    fs::remove_file(&path)?;
+
+    Ok(file)
+}
+
+/// Reads back everything written to a file created by `output_file()`.
+pub fn read_output_file(mut file: File) -> Vec<u8> {
+    let mut contents = Vec::new();
+
+    if file.seek(SeekFrom::Start(0)).is_ok() {
+        let _ = file.read_to_end(&mut contents);
+    }
+
+    contents
+}
+
+#[cfg(test)]
+mod tests {
+    use std::process::Command;
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    use super::{CancellationToken, MountError, run_commands};
+
+    fn shell(script: &str) -> Command {
+        let mut cmd = Command::new("sh");
+        cmd.arg("-c").arg(script);
+        cmd
+    }
+
+    #[test]
+    fn test_failure_reports_output() {
+        let result = run_commands(
+            vec![shell("true"), shell("echo oops >&2; exit 3")],
+            None,
+            &CancellationToken::default(),
+        );
+
+        let Err(MountError::Failed { debug, .. }) = result else {
+            panic!("Expected a failure, got: {:?}", result);
+        };
+
+        assert!(debug.contains("oops"));
+    }
+
+    #[test]
+    fn test_timeout_kills_the_command() {
+        let started_at = Instant::now();
+
+        let result = run_commands(
+            vec![shell("sleep 30")],
+            Some(Duration::from_millis(200)),
+            &CancellationToken::default(),
+        );
+
+        assert!(matches!(result, Err(MountError::TimedOut(_))));
+        assert!(started_at.elapsed() < Duration::from_secs(10));
+    }
+
+    #[test]
+    fn test_cancellation_kills_the_command() {
+        let cancellation = CancellationToken::default();
+
+        let cancellation_clone = cancellation.clone();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(200));
+            cancellation_clone.cancel();
+        });
+
+        let started_at = Instant::now();
+
+        let result = run_commands(vec![shell("sleep 30")], None, &cancellation);
+
+        assert!(matches!(result, Err(MountError::Cancelled)));
+        assert!(started_at.elapsed() < Duration::from_secs(10));
+    }
+}
@@ -0,0 +1,85 @@
+use std::net::{TcpStream, ToSocketAddrs};
+#[cfg(feature = "logind")]
+use std::thread;
+use std::time::Duration;
+#[cfg(feature = "logind")]
+use std::time::Instant;
+
+#[cfg(feature = "logind")]
+use libsftpman::FilesystemMountDefinition;
+
+/// How long to wait for the network to come back after resuming from sleep (see `wait_until_reachable()`).
+#[cfg(feature = "logind")]
+pub const RESUME_TIMEOUT: Duration = Duration::from_secs(60);
+
+// How long a single connection attempt may take.
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(3);
+
+// How often unreachable hosts are checked again.
+#[cfg(feature = "logind")]
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Tells whether a TCP connection can be made to the given host and port.
+///
+/// This is a blocking call (resolving the host and connecting may take a while).
+pub fn is_reachable(host: &str, port: u16) -> bool {
+    let Ok(addresses) = (host, port).to_socket_addrs() else {
+        return false;
+    };
+
+    addresses
+        .into_iter()
+        .any(|address| TcpStream::connect_timeout(&address, CONNECT_TIMEOUT).is_ok())
+}
+
+/// Waits until the servers of all given filesystems are reachable (see `is_reachable()`), or until `timeout` passes.
+///
+/// Hosts which can't be resolved (e.g. aliases defined in `~/.ssh/config`) never become reachable, so we may wait for the full `timeout`.
+#[cfg(feature = "logind")]
+pub fn wait_until_reachable(definitions: &[FilesystemMountDefinition], timeout: Duration) {
+    let deadline = Instant::now() + timeout;
+
+    let mut pending = definitions.iter().collect::<Vec<_>>();
+
+    loop {
+        pending.retain(|definition| !is_reachable(&definition.host, definition.port));
+
+        if pending.is_empty() {
+            return;
+        }
+
+        if Instant::now() + POLL_INTERVAL >= deadline {
+            log::warn!(
+                "Giving up waiting for the network, with unreachable filesystems: {}",
+                pending
+                    .iter()
+                    .map(|definition| definition.id.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+            return;
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::TcpListener;
+
+    use super::is_reachable;
+
+    #[test]
+    fn test_is_reachable() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        assert!(is_reachable("127.0.0.1", port));
+
+        drop(listener);
+
+        assert!(!is_reachable("127.0.0.1", port));
+        assert!(!is_reachable("host.invalid", 22));
+    }
+}
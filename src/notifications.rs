@@ -0,0 +1,241 @@
+use std::time::{Duration, Instant};
+
+use iced::Task;
+
+use crate::application::ApplicationMessage;
+use crate::messages::Message;
+use crate::pages::AlertConfig;
+
+// Informational notifications disappear on their own after this much time.
+// Warnings and errors stay until they're acknowledged.
+pub const NOTIFICATION_AUTO_DISMISS_MS: u64 = 5000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationSeverity {
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl NotificationSeverity {
+    pub fn is_auto_dismissed(self) -> bool {
+        match self {
+            Self::Info | Self::Success => true,
+            Self::Warning | Self::Error => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Notification {
+    pub id: u64,
+    pub severity: NotificationSeverity,
+    pub alert: AlertConfig,
+    pub created_at: Instant,
+}
+
+/// NotificationQueue holds all notifications which are currently shown (stacked) by the application.
+///
+/// Notifications can be queued from any page (see `notify()`), so that multiple failures
+/// happening around the same time don't overwrite one another.
+#[derive(Debug, Default)]
+pub struct NotificationQueue {
+    notifications: Vec<Notification>,
+    next_id: u64,
+}
+
+impl NotificationQueue {
+    pub fn push(&mut self, severity: NotificationSeverity, alert: AlertConfig) -> u64 {
+        self.push_at(severity, alert, Instant::now())
+    }
+
+    fn push_at(
+        &mut self,
+        severity: NotificationSeverity,
+        alert: AlertConfig,
+        created_at: Instant,
+    ) -> u64 {
+        self.next_id += 1;
+
+        self.notifications.push(Notification {
+            id: self.next_id,
+            severity,
+            alert,
+            created_at,
+        });
+
+        self.next_id
+    }
+
+    pub fn dismiss(&mut self, id: u64) {
+        self.notifications.retain(|n| n.id != id);
+    }
+
+    pub fn dismiss_all(&mut self) {
+        self.notifications.clear();
+    }
+
+    /// Removes all auto-dismissable notifications which have been shown for long enough.
+    pub fn expire(&mut self, now: Instant) {
+        let max_age = Duration::from_millis(NOTIFICATION_AUTO_DISMISS_MS);
+
+        self.notifications.retain(|n| {
+            !n.severity.is_auto_dismissed() || now.duration_since(n.created_at) < max_age
+        });
+    }
+
+    pub fn has_auto_dismissable(&self) -> bool {
+        self.notifications
+            .iter()
+            .any(|n| n.severity.is_auto_dismissed())
+    }
+
+    pub fn get(&self, id: u64) -> Option<&Notification> {
+        self.notifications.iter().find(|n| n.id == id)
+    }
+
+    pub fn all(&self) -> &[Notification] {
+        &self.notifications
+    }
+}
+
+/// Returns a task which queues a notification at the application level.
+pub fn notify(severity: NotificationSeverity, alert: AlertConfig) -> Task<Message> {
+    Task::perform(
+        async move { Message::Application(ApplicationMessage::Notify(severity, Box::new(alert))) },
+        |m| m,
+    )
+}
+
+/// DesktopNotification is a notification which is also shown outside of the application window,
+/// so that it's noticed even if the window is hidden (see `notify_desktop()`).
+#[derive(Debug, Clone)]
+pub struct DesktopNotification {
+    pub severity: NotificationSeverity,
+    pub summary: String,
+    pub body: String,
+
+    // alert is queued at the application level (like `notify()` does).
+    // The desktop notification offers an action for showing it.
+    pub alert: Option<AlertConfig>,
+
+    // force makes the desktop notification be shown even if desktop notifications are disabled in the settings.
+    // It's meant for when the window is not expected to be looked at (e.g. when started via `--autostart`).
+    pub force: bool,
+}
+
+impl DesktopNotification {
+    pub fn new(severity: NotificationSeverity, summary: String, body: String) -> Self {
+        Self {
+            severity,
+            summary,
+            body,
+            alert: None,
+            force: false,
+        }
+    }
+
+    pub fn with_alert(mut self, alert: AlertConfig) -> Self {
+        self.alert = Some(alert);
+        self
+    }
+
+    pub fn with_force(mut self, force: bool) -> Self {
+        self.force = force;
+        self
+    }
+}
+
+/// Returns a task which queues the notification's alert (if any) at the application level,
+/// and shows a desktop notification for it (if enabled in the settings).
+pub fn notify_desktop(notification: DesktopNotification) -> Task<Message> {
+    Task::perform(
+        async move { Message::Application(ApplicationMessage::NotifyDesktop(Box::new(notification))) },
+        |m| m,
+    )
+}
+
+/// Returns a task which shows a desktop notification (regardless of the settings) and then quits the application.
+///
+/// The notification's alert (if any) is ignored, as there'd be nobody to show it to.
+pub fn notify_desktop_and_exit(notification: DesktopNotification) -> Task<Message> {
+    Task::perform(
+        async move {
+            Message::Application(ApplicationMessage::NotifyDesktopAndExit(Box::new(
+                notification,
+            )))
+        },
+        |m| m,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::{NOTIFICATION_AUTO_DISMISS_MS, NotificationQueue, NotificationSeverity};
+    use crate::pages::AlertConfig;
+
+    fn alert_config(title: &str) -> AlertConfig {
+        AlertConfig::new(title.to_owned(), "message".to_owned())
+    }
+
+    #[test]
+    fn test_push_keeps_all_notifications() {
+        let mut queue = NotificationQueue::default();
+
+        let first = queue.push(NotificationSeverity::Error, alert_config("first"));
+        let second = queue.push(NotificationSeverity::Error, alert_config("second"));
+
+        assert_ne!(first, second);
+        assert_eq!(queue.all().len(), 2);
+        assert_eq!(queue.get(first).unwrap().alert.title, "first");
+        assert_eq!(queue.get(second).unwrap().alert.title, "second");
+    }
+
+    #[test]
+    fn test_dismiss() {
+        let mut queue = NotificationQueue::default();
+
+        let first = queue.push(NotificationSeverity::Error, alert_config("first"));
+        let second = queue.push(NotificationSeverity::Warning, alert_config("second"));
+
+        queue.dismiss(first);
+
+        assert!(queue.get(first).is_none());
+        assert!(queue.get(second).is_some());
+
+        queue.dismiss_all();
+
+        assert!(queue.all().is_empty());
+    }
+
+    #[test]
+    fn test_expire_only_removes_old_informational_notifications() {
+        let mut queue = NotificationQueue::default();
+
+        let now = Instant::now();
+        let old = now - Duration::from_millis(NOTIFICATION_AUTO_DISMISS_MS + 1);
+
+        let old_info = queue.push_at(NotificationSeverity::Info, alert_config("a"), old);
+        let old_success = queue.push_at(NotificationSeverity::Success, alert_config("b"), old);
+        let old_error = queue.push_at(NotificationSeverity::Error, alert_config("c"), old);
+        let old_warning = queue.push_at(NotificationSeverity::Warning, alert_config("d"), old);
+        let new_info = queue.push_at(NotificationSeverity::Info, alert_config("e"), now);
+
+        assert!(queue.has_auto_dismissable());
+
+        queue.expire(now);
+
+        assert!(queue.get(old_info).is_none());
+        assert!(queue.get(old_success).is_none());
+        assert!(queue.get(old_error).is_some());
+        assert!(queue.get(old_warning).is_some());
+        assert!(queue.get(new_info).is_some());
+
+        queue.dismiss(new_info);
+
+        assert!(!queue.has_auto_dismissable());
+    }
+}
@@ -0,0 +1,105 @@
+use std::io;
+use std::process::Command;
+
+use crate::strings;
+
+// The placeholder which gets replaced by the path (or link) to open, in open command templates.
+const PATH_PLACEHOLDER: &str = "{path}";
+
+// Commands tried (in order) after the configured one (see `Settings::open_command`).
+// `xdg-open` is what libsftpman's `Manager::open()` uses.
+const FALLBACK_TEMPLATES: [&str; 2] = ["xdg-open {path}", "gio open {path}"];
+
+/// Opens the given path (or link) with the configured open command template (e.g. `thunar {path}`),
+/// falling back to `xdg-open` and `gio open` if the configured program can't be found.
+///
+/// An empty template means only using the fallbacks.
+/// On failure, the error names the commands that were tried.
+pub fn open(template: &str, path: &str) -> Result<(), String> {
+    let templates = std::iter::once(template)
+        .filter(|template| !template.trim().is_empty())
+        .chain(FALLBACK_TEMPLATES)
+        .collect::<Vec<_>>();
+
+    open_with(&templates, path)
+}
+
+fn open_with(templates: &[&str], path: &str) -> Result<(), String> {
+    let mut errors = vec![];
+
+    for template in templates {
+        let args = build_command(template, path);
+
+        log::info!("Opening {} via: {:?}", path, args);
+
+        match Command::new(&args[0]).args(&args[1..]).spawn() {
+            Ok(_) => return Ok(()),
+            Err(e) => {
+                log::warn!("Failed to run {:?}: {}", args, e);
+
+                errors.push(strings::open_command_failed_error(
+                    &args.join(" "),
+                    &e.to_string(),
+                ));
+
+                // Only missing programs make us try the next command.
+                // Other errors (e.g. lacking permissions) are likely to be something the user needs to fix.
+                if e.kind() != io::ErrorKind::NotFound {
+                    break;
+                }
+            }
+        }
+    }
+
+    Err(errors.join("\n"))
+}
+
+// Splits the template on whitespace and replaces the path placeholder in each argument.
+// If there is no placeholder, the path is appended as the last argument.
+fn build_command(template: &str, path: &str) -> Vec<String> {
+    let mut args = template
+        .split_whitespace()
+        .map(|arg| arg.replace(PATH_PLACEHOLDER, path))
+        .collect::<Vec<_>>();
+
+    if !template.contains(PATH_PLACEHOLDER) {
+        args.push(path.to_owned());
+    }
+
+    args
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{build_command, open_with};
+
+    #[test]
+    fn test_build_command() {
+        assert_eq!(
+            build_command("kitty -d {path}", "/mnt/my files"),
+            ["kitty", "-d", "/mnt/my files"]
+        );
+        assert_eq!(
+            build_command("thunar", "/mnt/data"),
+            ["thunar", "/mnt/data"]
+        );
+        assert_eq!(
+            build_command("app --dir={path}", "/mnt/data"),
+            ["app", "--dir=/mnt/data"]
+        );
+    }
+
+    #[test]
+    fn test_open_with_falls_back() {
+        assert!(open_with(&["sftpman-iced-nonexistent {path}", "true {path}"], "/tmp").is_ok());
+
+        let error = open_with(
+            &["sftpman-iced-nonexistent {path}", "sftpman-iced-missing"],
+            "/tmp",
+        )
+        .unwrap_err();
+
+        assert!(error.contains("sftpman-iced-nonexistent /tmp"));
+        assert!(error.contains("sftpman-iced-missing /tmp"));
+    }
+}
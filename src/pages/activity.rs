@@ -0,0 +1,138 @@
+use iced::Alignment::Center;
+use iced::widget::{Column, Space, column, container, pick_list, row, scrollable, text};
+use iced::{Element, Font, Length};
+
+#[cfg(feature = "icons")]
+use iced::widget::svg;
+
+use crate::activity_log::{ActivityEntry, ActivityLog, ActivityLogFilter};
+use crate::application::ApplicationMessage;
+use crate::messages::Message;
+use crate::strings;
+use crate::ui_config::{
+    ACTIVITY_LOG_MODAL_WIDTH, MODAL_MAX_HEIGHT, MODAL_PADDING, MODAL_TITLE_SIZE,
+    MODAL_VERTICAL_SPACING, SCROLLBAR_RESERVED_SPACE, WIDGET_HORIZONTAL_SPACING,
+    WIDGET_VERTICAL_SPACING,
+};
+use crate::widgets::{Button, ButtonStyle, modal};
+
+pub fn activity<'a>(
+    inner_content: Element<'a, Message>,
+    log: &ActivityLog,
+    filter: &ActivityLogFilter,
+    theme: &iced::theme::Theme,
+) -> Element<'a, Message> {
+    let heading_row = row![
+        text(strings::activity_log_title()).size(MODAL_TITLE_SIZE),
+        Space::new().width(Length::Fill),
+        pick_list(log.filters(), Some(filter.clone()), |filter| {
+            Message::Application(ApplicationMessage::ActivityLogFilterChanged(filter))
+        }),
+    ]
+    .spacing(WIDGET_HORIZONTAL_SPACING)
+    .align_y(Center);
+
+    let entries = log.entries(filter);
+
+    let mut entries_column = Column::new().spacing(WIDGET_VERTICAL_SPACING);
+
+    if entries.is_empty() {
+        entries_column = entries_column.push(text(strings::activity_log_empty_message()));
+    }
+
+    for entry in entries {
+        entries_column = entries_column.push(activity_entry(entry, theme));
+    }
+
+    let mut btn_copy = Button::new(strings::activity_log_copy_button_label())
+        .with_style(ButtonStyle::SecondaryOutlined)
+        .with_on_press(Some(Message::Application(
+            ApplicationMessage::PutContentInClipboard(log.export_text(filter)),
+        )));
+
+    #[cfg(feature = "icons")]
+    {
+        btn_copy = btn_copy.with_svg_icon_handle(svg::Handle::from_memory(
+            crate::assets::bootstrap_icons::CLIPBOARD_CHECK,
+        ));
+    }
+
+    let mut buttons_row = row![btn_copy.build()].spacing(WIDGET_HORIZONTAL_SPACING);
+
+    #[cfg(feature = "file-picker")]
+    {
+        let btn_save = Button::new(strings::activity_log_save_button_label())
+            .with_style(ButtonStyle::SecondaryOutlined)
+            .with_on_press(Some(Message::Application(
+                ApplicationMessage::ExportActivityLog,
+            )));
+
+        buttons_row = buttons_row.push(btn_save.build());
+    }
+
+    let mut btn_close = Button::new(strings::about_button_close_label()).with_on_press(Some(
+        Message::Application(ApplicationMessage::ActivityLog(false)),
+    ));
+
+    #[cfg(feature = "icons")]
+    {
+        btn_close = btn_close.with_svg_icon_handle(svg::Handle::from_memory(
+            crate::assets::bootstrap_icons::ARROW_RETURN_LEFT,
+        ));
+    }
+
+    buttons_row = buttons_row.push(Space::new().width(Length::Fill));
+    buttons_row = buttons_row.push(btn_close.build());
+
+    let dialog = container(
+        column![
+            heading_row,
+            container(scrollable(
+                row![entries_column, Space::new().width(SCROLLBAR_RESERVED_SPACE)]
+                    .width(Length::Fill),
+            ))
+            .max_height(MODAL_MAX_HEIGHT),
+            buttons_row,
+        ]
+        .spacing(MODAL_VERTICAL_SPACING),
+    )
+    .width(ACTIVITY_LOG_MODAL_WIDTH)
+    .padding(MODAL_PADDING)
+    .style(container::rounded_box);
+
+    modal(
+        inner_content,
+        dialog,
+        Message::Application(ApplicationMessage::ActivityLog(false)),
+    )
+}
+
+fn activity_entry(entry: &ActivityEntry, theme: &iced::theme::Theme) -> Column<'static, Message> {
+    let palette = theme.palette();
+
+    let summary_color = if entry.is_success() {
+        palette.success
+    } else {
+        palette.danger
+    };
+
+    let mut col = column![text(entry.summary()).color(summary_color)].spacing(2);
+
+    if let Some(command) = &entry.command {
+        col = col.push(
+            text(format!(
+                "{} {}",
+                strings::activity_log_command_label(),
+                command
+            ))
+            .size(12)
+            .font(Font::MONOSPACE),
+        );
+    }
+
+    if let Some(error) = &entry.error {
+        col = col.push(text(format!("{} {}", strings::activity_log_error_label(), error)).size(14));
+    }
+
+    col
+}
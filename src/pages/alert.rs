@@ -1,5 +1,5 @@
 use iced::Alignment::Center;
-use iced::widget::{Container, Space, column, container, row, scrollable, text};
+use iced::widget::{Space, column, container, row, scrollable, text};
 use iced::{Element, Length};
 
 #[cfg(feature = "icons")]
@@ -16,10 +16,11 @@ use crate::widgets::{Button, ButtonStyle, modal};
 #[cfg(feature = "icons")]
 use crate::widgets::{IconColor, icon};
 
-pub fn alert(
-    inner_content_container: Container<'static, Message>,
+pub fn alert<'a>(
+    inner_content: Element<'a, Message>,
     payload: &AlertConfig,
-) -> Element<'static, Message> {
+    on_close: Message,
+) -> Element<'a, Message> {
     // Intentionally not using ICON_TO_TEXT_SPACING here, because the heading is large and needs more spacing.
     let mut heading_row = row![].spacing(WIDGET_HORIZONTAL_SPACING).align_y(Center);
 
@@ -36,7 +37,7 @@ pub fn alert(
     let btn_close = payload
         .close_button
         .clone()
-        .with_on_press(Some(*payload.on_close.clone()));
+        .with_on_press(Some(on_close.clone()));
 
     let mut buttons_row = row![].spacing(WIDGET_HORIZONTAL_SPACING);
     for btn in payload.additional_buttons.iter() {
@@ -65,14 +66,13 @@ pub fn alert(
     .padding(MODAL_PADDING)
     .style(container::rounded_box);
 
-    modal(inner_content_container, dialog, *payload.on_close.clone())
+    modal(inner_content, dialog, on_close)
 }
 
 #[derive(Debug, Clone)]
 pub struct AlertConfig {
     pub title: String,
     pub message: String,
-    pub on_close: Box<Message>,
     pub close_button: Box<Button>,
     pub additional_buttons: Vec<Button>,
     pub width: f32,
@@ -82,7 +82,7 @@ pub struct AlertConfig {
 }
 
 impl AlertConfig {
-    pub fn new(title: String, message: String, on_close: Box<Message>) -> Self {
+    pub fn new(title: String, message: String) -> Self {
         let mut btn_close =
             Button::new(strings::alert_close_button_label()).with_style(ButtonStyle::Primary);
 
@@ -96,7 +96,6 @@ impl AlertConfig {
         Self {
             title,
             message,
-            on_close,
             close_button: Box::new(btn_close),
             additional_buttons: vec![],
             width: MODAL_WIDTH,
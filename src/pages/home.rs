@@ -1,3 +1,4 @@
+use std::collections::{HashMap, HashSet};
 use std::time::{Duration, Instant};
 
 use iced::widget::{Container, column, container, scrollable};
@@ -8,33 +9,79 @@ use iced::widget::svg;
 
 use libsftpman::{FilesystemMountDefinition, Manager, MountState};
 
+use crate::activity_log::{ActivityEntry, ActivityKind, commands_to_string, log_activity};
 use crate::application::{ApplicationMessage, Navigation, Page};
+use crate::batch::{Batch, BatchKind};
+use crate::cli;
+use crate::export::{Export, ExportFormat};
+use crate::health::{self, Health, Prober};
+#[cfg(feature = "logind")]
+use crate::logind::{self, SleepEvent, SleepInhibitor};
 use crate::messages::Message as GlobalMessage;
-use crate::pages::{AlertConfig, ConfirmationConfig, Record, about, alert, confirmation};
+use crate::mount::{CancellationToken, MountError, UnmountError, mount, unmount};
+#[cfg(feature = "logind")]
+use crate::network;
+use crate::notifications::{
+    DesktopNotification, NotificationSeverity, notify, notify_desktop, notify_desktop_and_exit,
+};
+use crate::opener;
+use crate::pages::{
+    AlertConfig, ConfirmationConfig, Record, SettingsPage, SshImport, about, alert, confirmation,
+};
+use crate::reconnect::{Reconnect, WATCHDOG_INTERVAL};
+use crate::settings::Settings;
+#[cfg(feature = "logind")]
+use crate::settings::UnmountBeforeSleep;
+use crate::state::{InvalidDefinition, load_state, no_longer_mounted};
 use crate::strings;
+use crate::terminal;
 use crate::ui_config::{
-    MOUNT_ERROR_MODAL_WIDTH, SCROLLBAR_RESERVED_SPACE, WIDGET_VERTICAL_SPACING,
+    EXPORT_MODAL_WIDTH, FS_LIST_SPACING_BETWEEN_ROWS, MOUNT_ERROR_MODAL_WIDTH,
+    SCROLLBAR_RESERVED_SPACE, WIDGET_VERTICAL_SPACING,
 };
+use crate::utils::{run_blocking, sftpman_manager};
 use crate::widgets::{
-    Button, ButtonStyle, SEARCH_BAR_INPUT_FIELD_ID, control_bar, fs_empty_list, fs_list,
-    preflight_check_errors_bar, search_bar,
+    Button, ButtonStyle, SEARCH_BAR_INPUT_FIELD_ID, control_bar, control_bar_menu, fs_empty_list,
+    fs_invalid_list, fs_list, preflight_check_errors_bar, search_bar,
 };
 
-const REFRESH_INTERVAL_MS: u64 = 5000;
-
 #[derive(Debug, Clone)]
 pub enum Message {
     New,
 
     Mount(FilesystemMountDefinition),
-    DoMount(FilesystemMountDefinition),
-    MountResult(FilesystemMountDefinition, Option<(String, String)>),
+    MountResult(FilesystemMountDefinition, Option<MountError>, Duration),
+    CancelMount(FilesystemMountDefinition),
 
     Unmount(FilesystemMountDefinition),
-    DoUnmount(FilesystemMountDefinition),
-    UnmountResult(FilesystemMountDefinition, Option<(String, String)>),
+    // Remount unmounts a filesystem (as previously defined) and mounts it again (as now defined), after its definition got saved.
+    Remount(FilesystemMountDefinition, FilesystemMountDefinition),
+    UnmountResult(FilesystemMountDefinition, Option<UnmountError>, Duration),
+    RetryBusyUnmount,
+    UnmountBusyLazily,
+    DismissBusyUnmount,
+
+    ProbeHealth,
+    HealthProbed(Vec<(String, Health)>),
+    WatchdogTicked,
+    Repair(FilesystemMountDefinition),
+    // The flag tells whether to mount the filesystem again afterwards (see `Message::Repair`).
+    LazyUnmountResult(
+        FilesystemMountDefinition,
+        bool,
+        Option<(String, String)>,
+        Duration,
+    ),
+
+    #[cfg(feature = "logind")]
+    Sleep(SleepEvent),
+    // ResumeNetworkReady carries the filesystems to mount again after resuming, once the network is back.
+    #[cfg(feature = "logind")]
+    ResumeNetworkReady(Vec<FilesystemMountDefinition>),
 
     Open(FilesystemMountDefinition),
+    OpenTerminal(FilesystemMountDefinition),
+    OpenSsh(FilesystemMountDefinition),
 
     ToggleOptionsForRecord(Option<FilesystemMountDefinition>),
     EditRecord(FilesystemMountDefinition),
@@ -43,23 +90,49 @@ pub enum Message {
     RemoveRecordConfirmed(FilesystemMountDefinition),
     RemoveRecordCancelled,
 
+    Export(FilesystemMountDefinition, ExportFormat),
+    DismissExport,
+    #[cfg(feature = "file-picker")]
+    SaveExport,
+
+    // MountAll and UnmountAll act on the filesystems currently shown (see `filesystems_filtered()`).
     MountAll,
     UnmountAll,
+    // MountAllUnfiltered and UnmountAllUnfiltered act on all filesystems, regardless of the search filter.
+    // They're meant for callers outside of the window (the tray, D-Bus, the command line), which don't see the filter.
+    MountAllUnfiltered,
+    UnmountAllUnfiltered,
+    RetryBatchFailures,
+    DismissBatchSummary,
 
     ToggleSearchEnabled,
     SearchInputChanged(String),
 
+    ToggleMenu,
     About(bool),
+    ShowActivityLog,
+    OpenSettings,
+    SettingsChanged,
+    ImportSshConfig,
 
-    Alert(Option<AlertConfig>),
     Confirmation(Option<ConfirmationConfig>),
 
-    PeriodicRefreshTicked(Instant),
+    MountStateChanged,
 
     RunPreflightCheck,
+
+    ApplyCliOptions(Box<cli::Options>),
 }
 
 struct State {
+    // filesystems holds the last known state of all filesystems (definitions and whether they're mounted).
+    // It's refreshed whenever something changes (see `Message::MountStateChanged`), so that rendering doesn't hit the disk.
+    filesystems: Vec<MountState>,
+
+    // invalid_definitions holds definition files which could not be loaded (e.g. malformed JSON).
+    // They're shown in the list (with their error), so that the user can fix them.
+    invalid_definitions: Vec<InvalidDefinition>,
+
     search_enabled: bool,
     search_input_text: String,
 
@@ -67,30 +140,78 @@ struct State {
     // This is a single id (not a list), because it's possibly not very useful to expand multiple menus at once.
     expand_options_for_fs_id: Option<String>,
 
-    // mounting_in_progress_for_fs_id is used to indicate that the given filesystem is being mounted.
-    // This is a single id (not a list), because mounting is intentionally synchronous
-    // (so we can ask for SSH passphrases in the foreground, etc.).
-    mounting_in_progress_for_fs_id: Option<String>,
+    // mounting_in_progress_for_fs_ids contains the ids of all filesystems which are currently being mounted.
+    // Mounting happens in the background, so multiple filesystems may be connecting at the same time.
+    // Each in-progress mount has a token, which allows it to be cancelled.
+    mounting_in_progress_for_fs_ids: HashMap<String, CancellationToken>,
+
+    // mount_batch tracks the "Mount all" operation which is currently running (if any).
+    mount_batch: Option<Batch>,
+
+    // autostart tells whether the mount batch was started via `--autostart` (see `Home::autostart()`),
+    // in which case its outcome is reported via a desktop notification (and may make us quit).
+    autostart: bool,
+
+    // unmounting_in_progress_for_fs_ids contains the ids of all filesystems which are currently being unmounted.
+    // Like mounting, unmounting happens in the background.
+    unmounting_in_progress_for_fs_ids: HashSet<String>,
+
+    // remount_after_unmount maps the ids of filesystems being unmounted for a remount (see `Message::Remount`)
+    // to the definitions to mount once that's done.
+    remount_after_unmount: HashMap<String, FilesystemMountDefinition>,
 
-    // mounting_scheduled_for_definitions is used to indicate that the given filesystems are scheduled for mounting.
-    // This is used when performing a "Mount all" operation.
-    mounting_scheduled_for_definitions: Vec<FilesystemMountDefinition>,
+    // remove_after_unmount contains the ids of mounted filesystems being unmounted for removal (see `Message::RemoveRecordConfirmed`).
+    remove_after_unmount: HashSet<String>,
 
-    // unmounting_in_progress_for_fs_id is used to indicate that the given filesystem is being unmounted.
-    // This is a single id (not a list), because unmounting is intentionally synchronous.
-    // Unmounting can potentially be asynchronous, but:
-    // - we're being consistent with the way we do mounting
-    // - if it is, multiple unmounts can fail at the same time and we'll need to support simultaneous alerting
-    //   (we only handle single alert right now - see the `alert` field below)
-    unmounting_in_progress_for_fs_id: Option<String>,
+    // unmount_batch tracks the "Unmount all" operation which is currently running (if any).
+    unmount_batch: Option<Batch>,
 
-    // unmounting_scheduled_for_definitions is used to indicate that the given filesystems are scheduled for unmounting.
-    // This is used when performing an "Unmount all" operation.
-    unmounting_scheduled_for_definitions: Vec<FilesystemMountDefinition>,
+    // busy_unmount holds a filesystem which failed to get unmounted, because processes were using it.
+    // It's shown as a dialog (listing the processes and offering a retry or a lazy unmount) until dismissed.
+    busy_unmount: Option<(FilesystemMountDefinition, UnmountError)>,
+
+    // broken_fs_ids maps the ids of mounted filesystems which failed the last health probe to the reason why.
+    // Such (dead) mounts are still reported as mounted, but accessing them fails or hangs (see `crate::health`).
+    broken_fs_ids: HashMap<String, String>,
+
+    // health_probe_in_progress prevents probes from piling up, when dead mounts make them slow.
+    health_probe_in_progress: bool,
+
+    // health_prober keeps track of mount points still being probed, so that dead ones don't pile up threads.
+    health_prober: Prober,
+
+    // reconnecting holds the "keep mounted" filesystems which got lost or broken and are to be mounted again.
+    // The watchdog (see `Message::WatchdogTicked`) makes attempts as they become due.
+    reconnecting: HashMap<String, Reconnect>,
+
+    // reconnect_given_up_fs_ids contains the ids of filesystems for which reconnecting failed too many times.
+    // Health probes don't start reconnecting them again, until they're healthy again or get mounted or unmounted.
+    reconnect_given_up_fs_ids: HashSet<String>,
+
+    // sleep_inhibitor delays the system from going to sleep while we're unmounting filesystems before it (see `crate::logind`).
+    // It's released once all of `unmounting_before_sleep_fs_ids` are done.
+    #[cfg(feature = "logind")]
+    sleep_inhibitor: Option<SleepInhibitor>,
+    #[cfg(feature = "logind")]
+    unmounting_before_sleep_fs_ids: HashSet<String>,
+
+    // remount_after_sleep holds the filesystems which got unmounted before the system went to sleep,
+    // so that they can be mounted again after it resumes.
+    #[cfg(feature = "logind")]
+    remount_after_sleep: Vec<FilesystemMountDefinition>,
+
+    // batch_summary holds a finished batch operation.
+    // It's shown as a dialog (listing all outcomes) until dismissed.
+    batch_summary: Option<Batch>,
+
+    // export holds a definition exported for mounting without us (see `crate::export`).
+    // It's shown as a dialog (offering copying and saving) until dismissed.
+    export: Option<Export>,
 
     confirmation: Option<ConfirmationConfig>,
 
-    alert: Option<AlertConfig>,
+    // menu_expanded tells whether the control bar's menu (Activity, Settings, About) is expanded.
+    menu_expanded: bool,
 
     about_shown: bool,
 
@@ -100,18 +221,38 @@ struct State {
 impl Default for State {
     fn default() -> Self {
         Self {
+            filesystems: Vec::new(),
+            invalid_definitions: Vec::new(),
+
             search_enabled: false,
             search_input_text: "".to_owned(),
             expand_options_for_fs_id: None,
 
-            mounting_in_progress_for_fs_id: None,
-            mounting_scheduled_for_definitions: Vec::new(),
-
-            unmounting_in_progress_for_fs_id: None,
-            unmounting_scheduled_for_definitions: Vec::new(),
+            mounting_in_progress_for_fs_ids: HashMap::new(),
+            mount_batch: None,
+            autostart: false,
+
+            unmounting_in_progress_for_fs_ids: HashSet::new(),
+            remount_after_unmount: HashMap::new(),
+            remove_after_unmount: HashSet::new(),
+            unmount_batch: None,
+            busy_unmount: None,
+            broken_fs_ids: HashMap::new(),
+            health_probe_in_progress: false,
+            health_prober: Prober::default(),
+            reconnecting: HashMap::new(),
+            reconnect_given_up_fs_ids: HashSet::new(),
+            #[cfg(feature = "logind")]
+            sleep_inhibitor: None,
+            #[cfg(feature = "logind")]
+            unmounting_before_sleep_fs_ids: HashSet::new(),
+            #[cfg(feature = "logind")]
+            remount_after_sleep: Vec::new(),
+            batch_summary: None,
+            export: None,
 
             confirmation: None,
-            alert: None,
+            menu_expanded: false,
             about_shown: false,
 
             preflight_check_errors: Vec::new(),
@@ -121,30 +262,265 @@ impl Default for State {
 
 pub struct Home {
     manager: Manager,
+    settings: Settings,
     state: State,
 }
 
 impl Home {
-    pub fn new(manager: Manager) -> Self {
-        Self {
+    /// Creates the Home page, failing if the sftpman configuration can't be loaded at all.
+    ///
+    /// Individual definitions failing to load is not an error (see `InvalidDefinition`).
+    pub fn load() -> Result<Self, String> {
+        let manager = sftpman_manager()?;
+
+        let state = load_state(&manager)?;
+
+        Ok(Self {
             manager,
-            state: State::default(),
+            settings: Settings::load(),
+            state: State {
+                filesystems: state.filesystems,
+                invalid_definitions: state.invalid_definitions,
+                ..State::default()
+            },
+        })
+    }
+
+    /// Performs the actions requested via the command line (see `crate::cli::Options`).
+    pub fn apply_cli_options(&mut self, options: &cli::Options) -> Task<GlobalMessage> {
+        let mut tasks = vec![];
+
+        if let Some(search) = &options.search {
+            self.state.search_enabled = true;
+            self.state.search_input_text = search.clone();
+
+            tasks.push(iced::widget::operation::focus(SEARCH_BAR_INPUT_FIELD_ID));
+        }
+
+        let mut to_mount = vec![];
+        for id in &options.mount {
+            match self.find_definition(id) {
+                Some(definition) => to_mount.push(definition),
+                None => tasks.push(notify_unknown_filesystem(id)),
+            }
+        }
+
+        let mut to_unmount = vec![];
+        for id in &options.unmount {
+            match self.find_definition(id) {
+                Some(definition) => to_unmount.push(definition),
+                None => tasks.push(notify_unknown_filesystem(id)),
+            }
+        }
+
+        tasks.push(self.run_cli_operation(BatchKind::Mount, to_mount));
+        tasks.push(self.run_cli_operation(BatchKind::Unmount, to_unmount));
+
+        if options.mount_all {
+            tasks.push(Task::perform(async {}, |_| {
+                GlobalMessage::Home(Message::MountAllUnfiltered)
+            }));
         }
+
+        if options.unmount_all {
+            tasks.push(Task::perform(async {}, |_| {
+                GlobalMessage::Home(Message::UnmountAllUnfiltered)
+            }));
+        }
+
+        if let Some(id) = &options.edit {
+            match self.find_definition(id) {
+                Some(definition) => tasks.push(Task::perform(async {}, move |_| {
+                    GlobalMessage::Home(Message::EditRecord(definition.clone()))
+                })),
+                None => tasks.push(notify_unknown_filesystem(id)),
+            }
+        }
+
+        if options.new {
+            tasks.push(Task::perform(async {}, |_| {
+                GlobalMessage::Home(Message::New)
+            }));
+        }
+
+        Task::batch(tasks)
     }
 
-    fn filesystems_filtered(&self) -> Vec<MountState> {
-        let state = self.manager.full_state().unwrap();
+    /// Mounts the filesystems marked for mounting at startup (see `--autostart`).
+    pub fn autostart(&mut self) -> Task<GlobalMessage> {
+        let definitions = self
+            .settings
+            .mount_at_startup_ids()
+            .iter()
+            .filter_map(|id| {
+                self.state
+                    .filesystems
+                    .iter()
+                    .find(|item| &item.definition.id == id)
+            })
+            .filter(|item| !item.mounted)
+            .map(|item| item.definition.clone())
+            .collect::<Vec<_>>();
+
+        log::info!(
+            "Autostarting: mounting {} filesystem(s) marked for mounting at startup",
+            definitions.len()
+        );
+
+        if definitions.is_empty() {
+            if self.settings.autostart_exit_after_mounting {
+                return iced::exit();
+            }
+
+            return Task::none();
+        }
+
+        self.state.autostart = true;
+
+        // Even a single filesystem goes through a batch, so that the outcome is reported the same way.
+        self.run_batch(BatchKind::Mount, definitions)
+    }
+
+    fn run_cli_operation(
+        &mut self,
+        kind: BatchKind,
+        definitions: Vec<FilesystemMountDefinition>,
+    ) -> Task<GlobalMessage> {
+        // A single filesystem is handled like toggling it in the list would be (with a detailed error on failure).
+        // Multiple ones are handled like "Mount all" / "Unmount all" would be.
+        match (kind, definitions.len()) {
+            (_, 0) => Task::none(),
+            (BatchKind::Mount, 1) => self.start_mount(definitions[0].clone()),
+            (BatchKind::Unmount, 1) => self.start_unmount(definitions[0].clone()),
+            _ => self.run_batch(kind, definitions),
+        }
+    }
+
+    fn find_definition(&self, id: &str) -> Option<FilesystemMountDefinition> {
+        self.state
+            .filesystems
+            .iter()
+            .find(|item| item.definition.id == id)
+            .map(|item| item.definition.clone())
+    }
+
+    /// Reloads the state of all filesystems.
+    ///
+    /// Returns a task which reports filesystems that got unmounted without us unmounting them.
+    fn refresh(&mut self) -> Task<GlobalMessage> {
+        let state = match load_state(&self.manager) {
+            Ok(state) => state,
+            Err(e) => {
+                log::error!("Failed to load the state of filesystems: {}", e);
+                return Task::none();
+            }
+        };
+
+        // sshfs exits (and the filesystem gets unmounted) when the connection to the server is lost.
+        // The same happens when something else (e.g. the sftpman CLI) unmounts it, which we can't tell apart.
+        let lost = no_longer_mounted(&self.state.filesystems, &state.filesystems)
+            .into_iter()
+            .filter(|definition| {
+                !self
+                    .state
+                    .unmounting_in_progress_for_fs_ids
+                    .contains(&definition.id)
+            })
+            .collect::<Vec<_>>();
+
+        self.state.filesystems = state.filesystems;
+        self.state.invalid_definitions = state.invalid_definitions;
+
+        // Only mounted filesystems can be broken.
+        let filesystems = &self.state.filesystems;
+        self.state.broken_fs_ids.retain(|id, _| {
+            filesystems
+                .iter()
+                .any(|item| &item.definition.id == id && item.mounted)
+        });
+
+        // Filesystems which got removed or mounted (and aren't broken) need no reconnecting.
+        let broken_fs_ids = &self.state.broken_fs_ids;
+        self.state.reconnecting.retain(|id, _| {
+            filesystems.iter().any(|item| {
+                &item.definition.id == id && (!item.mounted || broken_fs_ids.contains_key(id))
+            })
+        });
+
+        Task::batch(lost.into_iter().map(|definition| {
+            log::warn!("{} is no longer mounted", definition.id);
+
+            let title = strings::filesystem_lost_notification_title(&definition.id);
+
+            let message = if self.settings.keep_mounted(&definition.id) {
+                self.state
+                    .reconnecting
+                    .entry(definition.id.clone())
+                    .or_insert_with(|| Reconnect::new(Instant::now()));
 
+                strings::filesystem_lost_reconnecting_notification_message()
+            } else {
+                strings::filesystem_lost_notification_message()
+            };
+
+            notify_desktop(
+                DesktopNotification::new(
+                    NotificationSeverity::Warning,
+                    title.clone(),
+                    message.clone(),
+                )
+                .with_alert(AlertConfig::new(title, message)),
+            )
+        }))
+    }
+
+    fn invalid_definitions_filtered(&self) -> Vec<InvalidDefinition> {
+        self.state
+            .invalid_definitions
+            .iter()
+            .filter(|item| {
+                !self.state.search_enabled || item.id.contains(&self.state.search_input_text)
+            })
+            .cloned()
+            .collect::<Vec<_>>()
+    }
+
+    fn filesystems_filtered(&self) -> Vec<MountState> {
         if self.state.search_enabled {
-            state
-                .into_iter()
+            self.state
+                .filesystems
+                .iter()
                 .filter(|item| item.definition.id.contains(&self.state.search_input_text))
+                .cloned()
                 .collect::<Vec<_>>()
         } else {
-            state
+            self.state.filesystems.clone()
         }
     }
 
+    fn mount_all(&mut self, filesystems: Vec<MountState>) -> Task<GlobalMessage> {
+        // Filesystems are mounted one by one (unless `Settings::parallel_batch_operations` is enabled),
+        // so that askpass dialogs (SSH key passphrases, etc.) for different filesystems don't pile up.
+        // Failures don't stop the batch. All outcomes are reported once it completes.
+        let definitions = filesystems
+            .into_iter()
+            .filter(|item| !item.mounted && !self.is_busy(&item.definition))
+            .map(|item| item.definition)
+            .collect::<Vec<_>>();
+
+        self.run_batch(BatchKind::Mount, definitions)
+    }
+
+    fn unmount_all(&mut self, filesystems: Vec<MountState>) -> Task<GlobalMessage> {
+        let definitions = filesystems
+            .into_iter()
+            .filter(|item| item.mounted && !self.is_busy(&item.definition))
+            .map(|item| item.definition)
+            .collect::<Vec<_>>();
+
+        self.run_batch(BatchKind::Unmount, definitions)
+    }
+
     fn render(&self, theme: &iced::theme::Theme) -> Container<'static, GlobalMessage> {
         let state_filtered = self.filesystems_filtered();
 
@@ -162,6 +538,7 @@ impl Home {
             are_all_mounted,
             are_all_unmounted,
             self.state.search_enabled,
+            self.state.menu_expanded,
         )
         .padding(Padding::new(0.0).right(SCROLLBAR_RESERVED_SPACE));
 
@@ -173,6 +550,11 @@ impl Home {
                     .left(SCROLLBAR_RESERVED_SPACE),
             );
 
+        if self.state.menu_expanded {
+            main_column = main_column
+                .push(control_bar_menu().padding(Padding::new(0.0).right(SCROLLBAR_RESERVED_SPACE)));
+        }
+
         if self.state.search_enabled {
             let search_bar = search_bar(&self.state.search_input_text)
                 .padding(Padding::new(0.0).right(SCROLLBAR_RESERVED_SPACE));
@@ -192,14 +574,24 @@ impl Home {
             main_column = main_column.push(widget_preflight_check_errors);
         }
 
-        if !state_filtered.is_empty() {
-            let widget_fs_list = fs_list(
-                state_filtered,
-                self.state.expand_options_for_fs_id.clone(),
-                self.state.mounting_in_progress_for_fs_id.clone(),
-                self.state.unmounting_in_progress_for_fs_id.clone(),
-            )
-            .padding(
+        let invalid_definitions_filtered = self.invalid_definitions_filtered();
+
+        if !state_filtered.is_empty() || !invalid_definitions_filtered.is_empty() {
+            let widget_fs_list = column![
+                fs_list(
+                    state_filtered,
+                    self.state.expand_options_for_fs_id.clone(),
+                    &self.state.mounting_in_progress_for_fs_ids,
+                    &self.state.unmounting_in_progress_for_fs_ids,
+                    &self.state.broken_fs_ids,
+                    &self.state.reconnecting,
+                    self.settings.reconnect_max_failures,
+                ),
+                fs_invalid_list(invalid_definitions_filtered, theme),
+            ]
+            .spacing(FS_LIST_SPACING_BETWEEN_ROWS);
+
+            let widget_fs_list = container(widget_fs_list).padding(
                 Padding::new(0.0)
                     .right(SCROLLBAR_RESERVED_SPACE)
                     .bottom(WIDGET_VERTICAL_SPACING),
@@ -218,20 +610,449 @@ impl Home {
         container(main_column).height(Length::Fill)
     }
 
-    fn alert(&self, title: String, message: String, additional_buttons: Vec<Button>) -> Navigation {
-        let alert_config = AlertConfig::new(
-            title,
-            message,
-            Box::new(GlobalMessage::Home(Message::Alert(None))),
+    fn is_busy(&self, definition: &FilesystemMountDefinition) -> bool {
+        self.state
+            .mounting_in_progress_for_fs_ids
+            .contains_key(&definition.id)
+            || self
+                .state
+                .unmounting_in_progress_for_fs_ids
+                .contains(&definition.id)
+    }
+
+    fn start_mount(&mut self, definition: FilesystemMountDefinition) -> Task<GlobalMessage> {
+        // Another operation for the same filesystem is already running.
+        // Starting a new one would race with it.
+        if self.is_busy(&definition) {
+            return Task::none();
+        }
+
+        // Mounting is a fresh start, so reconnecting is possible again if it gets lost or broken afterwards.
+        self.state.reconnect_given_up_fs_ids.remove(&definition.id);
+
+        let cancellation = CancellationToken::default();
+
+        self.state
+            .mounting_in_progress_for_fs_ids
+            .insert(definition.id.clone(), cancellation.clone());
+
+        // Mounting runs on a separate thread, so that the UI stays responsive while sshfs connects.
+        // sshfs (and the ssh process it spawns) inherits our environment,
+        // so SSH key passphrases can still be asked for via an askpass program (`SSH_ASKPASS`).
+        let manager = self.manager.clone();
+        let timeout = self.settings.mount_timeout(&definition.id);
+
+        Task::perform(
+            run_blocking(move || {
+                let started_at = Instant::now();
+
+                let error = mount(&manager, &definition, timeout, &cancellation).err();
+
+                (definition, error, started_at.elapsed())
+            }),
+            |(definition, error, duration)| {
+                GlobalMessage::Home(Message::MountResult(definition, error, duration))
+            },
         )
-        .with_additional_buttons(additional_buttons);
+    }
+
+    fn start_unmount(&mut self, definition: FilesystemMountDefinition) -> Task<GlobalMessage> {
+        if self.is_busy(&definition) {
+            return Task::none();
+        }
+
+        // Unmounting is what the user wants, so reconnecting would be fighting them.
+        self.state.reconnecting.remove(&definition.id);
+        self.state.reconnect_given_up_fs_ids.remove(&definition.id);
+
+        self.state
+            .unmounting_in_progress_for_fs_ids
+            .insert(definition.id.clone());
+
+        let manager = self.manager.clone();
+
+        Task::perform(
+            run_blocking(move || {
+                let started_at = Instant::now();
+
+                let error = unmount(&manager, &definition).err();
+
+                (definition, error, started_at.elapsed())
+            }),
+            |(definition, error, duration)| {
+                GlobalMessage::Home(Message::UnmountResult(definition, error, duration))
+            },
+        )
+    }
+
+    /// Unmounts the filesystem lazily (see `crate::health::lazy_umount()`), optionally mounting it again afterwards.
+    fn start_lazy_unmount(
+        &mut self,
+        definition: FilesystemMountDefinition,
+        remount: bool,
+    ) -> Task<GlobalMessage> {
+        if self.is_busy(&definition) {
+            return Task::none();
+        }
+
+        if !remount {
+            self.state.reconnecting.remove(&definition.id);
+        }
 
-        let task = Task::perform(
-            async { GlobalMessage::Home(Message::Alert(Some(alert_config))) },
-            |m| m,
+        self.state
+            .unmounting_in_progress_for_fs_ids
+            .insert(definition.id.clone());
+
+        Task::perform(
+            run_blocking(move || {
+                let started_at = Instant::now();
+
+                let error = health::lazy_umount(&definition)
+                    .err()
+                    .map(|e| (e.clone(), e));
+
+                (definition, error, started_at.elapsed())
+            }),
+            move |(definition, error, duration)| {
+                GlobalMessage::Home(Message::LazyUnmountResult(
+                    definition, remount, error, duration,
+                ))
+            },
+        )
+    }
+
+    /// Removes the (unmounted) filesystem's definition, along with its settings.
+    fn remove_definition(&mut self, definition: FilesystemMountDefinition) -> Task<GlobalMessage> {
+        let started_at = Instant::now();
+
+        // The filesystem is not mounted (see `Message::RemoveRecordConfirmed`),
+        // so this only deletes the definition file and doesn't block.
+        let result = self.manager.remove(&definition);
+
+        let task_refresh = self.refresh();
+
+        if result.is_ok() {
+            self.settings.remove_definition(&definition.id);
+
+            if let Err(e) = self.settings.save() {
+                log::error!("Failed to save settings: {}", e);
+            }
+        }
+
+        let task_log_activity = Task::batch([
+            task_refresh,
+            log_activity(ActivityEntry::new(
+                ActivityKind::Remove,
+                Some(definition.id.clone()),
+                started_at.elapsed(),
+                result.as_ref().err().map(|e| e.to_string()),
+            )),
+        ]);
+
+        let Err(e) = result else {
+            return Task::batch([
+                task_log_activity,
+                notify(
+                    NotificationSeverity::Success,
+                    AlertConfig::new(
+                        strings::remove_succeeded_notification_title(&definition.id),
+                        "".to_owned(),
+                    ),
+                ),
+            ]);
+        };
+
+        let error_message = strings::remove_failed_alert_message(&e.to_string());
+
+        let mut alert_config = AlertConfig::new(
+            strings::remove_failed_alert_title(&definition.id),
+            error_message,
+        );
+
+        #[cfg(feature = "icons")]
+        {
+            alert_config = alert_config.with_svg_icon_handle(svg::Handle::from_memory(
+                crate::assets::bootstrap_icons::EXCLAMATION_TRIANGLE,
+            ));
+        }
+
+        Task::batch([
+            task_log_activity,
+            notify(NotificationSeverity::Error, alert_config),
+        ])
+    }
+
+    /// Mounts the definition which the previous one got unmounted for (see `Message::Remount`).
+    ///
+    /// If the definition got saved under a new id, the previous one goes away now that it's unmounted.
+    fn finish_remount(
+        &mut self,
+        previous: &FilesystemMountDefinition,
+        definition: FilesystemMountDefinition,
+    ) -> Task<GlobalMessage> {
+        let mut task_remove = Task::none();
+        if definition.id != previous.id
+            && let Err(err) = self.manager.remove(previous)
+        {
+            log::warn!("{}: failed to remove: {}", previous.id, err);
+
+            task_remove = notify(
+                NotificationSeverity::Error,
+                AlertConfig::new(
+                    strings::operation_failed_alert_title(),
+                    strings::operation_failed_alert_message(&err.to_string()),
+                ),
+            );
+        }
+
+        Task::batch([task_remove, self.start_mount(definition)])
+    }
+
+    /// Records the outcome of a reconnect attempt, scheduling the next one if it failed (unless we're giving up).
+    fn record_reconnect_outcome(
+        &mut self,
+        definition: &FilesystemMountDefinition,
+        mut reconnect: Reconnect,
+        error: Option<String>,
+    ) -> Task<GlobalMessage> {
+        let Some(error) = error else {
+            log::info!(
+                "{}: reconnected on attempt {}",
+                definition.id,
+                reconnect.attempt()
+            );
+
+            return notify_desktop(DesktopNotification::new(
+                NotificationSeverity::Success,
+                strings::reconnect_succeeded_notification_title(&definition.id),
+                strings::mount_succeeded_notification_message(&definition.local_mount_path()),
+            ));
+        };
+
+        let max_failures = self.settings.reconnect_max_failures;
+
+        if reconnect.record_failure(Instant::now(), max_failures) {
+            log::warn!(
+                "{}: reconnect attempt {} failed: {}",
+                definition.id,
+                reconnect.failures,
+                error
+            );
+
+            self.state
+                .reconnecting
+                .insert(definition.id.clone(), reconnect);
+
+            return Task::none();
+        }
+
+        log::warn!(
+            "{}: giving up reconnecting after {} failed attempts: {}",
+            definition.id,
+            reconnect.failures,
+            error
         );
 
-        Navigation::None(task)
+        self.state
+            .reconnect_given_up_fs_ids
+            .insert(definition.id.clone());
+
+        let title = strings::reconnect_gave_up_notification_title(&definition.id);
+        let message = strings::reconnect_gave_up_notification_message(reconnect.failures, &error);
+
+        notify_desktop(
+            DesktopNotification::new(NotificationSeverity::Error, title.clone(), message.clone())
+                .with_alert(AlertConfig::new(title, message)),
+        )
+    }
+
+    /// Unmounts the filesystems which should not stay mounted while the system sleeps (see `UnmountBeforeSleep`).
+    ///
+    /// The inhibitor is held (delaying sleep) until they're all unmounted (see `record_unmount_before_sleep()`).
+    /// Those which fail to get unmounted (e.g. because they're busy) get unmounted lazily instead (see `Message::UnmountResult`).
+    #[cfg(feature = "logind")]
+    fn prepare_for_sleep(&mut self, inhibitor: SleepInhibitor) -> Task<GlobalMessage> {
+        let definitions = self
+            .state
+            .filesystems
+            .iter()
+            .filter(|item| item.mounted && !self.is_busy(&item.definition))
+            .filter(|item| self.settings.unmount_before_sleep(&item.definition.id))
+            .map(|item| item.definition.clone())
+            .collect::<Vec<_>>();
+
+        log::info!(
+            "Preparing for sleep: unmounting {} filesystem(s)",
+            definitions.len()
+        );
+
+        self.state.remount_after_sleep.clear();
+
+        if definitions.is_empty() {
+            inhibitor.release();
+            return Task::none();
+        }
+
+        self.state.sleep_inhibitor = Some(inhibitor);
+        self.state.unmounting_before_sleep_fs_ids =
+            definitions.iter().map(|def| def.id.clone()).collect();
+
+        Task::batch(
+            definitions
+                .into_iter()
+                .map(|definition| self.start_unmount(definition)),
+        )
+    }
+
+    /// Records the outcome of unmounting a filesystem before sleeping (see `prepare_for_sleep()`),
+    /// letting the system go to sleep once all such filesystems are done.
+    #[cfg(feature = "logind")]
+    fn record_unmount_before_sleep(
+        &mut self,
+        definition: &FilesystemMountDefinition,
+        succeeded: bool,
+    ) {
+        if !self
+            .state
+            .unmounting_before_sleep_fs_ids
+            .remove(&definition.id)
+        {
+            return;
+        }
+
+        if succeeded {
+            self.state.remount_after_sleep.push(definition.clone());
+        }
+
+        if self.state.unmounting_before_sleep_fs_ids.is_empty()
+            && let Some(inhibitor) = self.state.sleep_inhibitor.take()
+        {
+            inhibitor.release();
+        }
+    }
+
+    fn batch_mut(&mut self, kind: BatchKind) -> &mut Option<Batch> {
+        match kind {
+            BatchKind::Mount => &mut self.state.mount_batch,
+            BatchKind::Unmount => &mut self.state.unmount_batch,
+        }
+    }
+
+    /// Adds the given filesystems to the batch operation of the given kind (starting one if necessary).
+    fn run_batch(
+        &mut self,
+        kind: BatchKind,
+        definitions: Vec<FilesystemMountDefinition>,
+    ) -> Task<GlobalMessage> {
+        if definitions.is_empty() {
+            return Task::none();
+        }
+
+        let parallel = self.settings.parallel_batch_operations;
+
+        let batch = self
+            .batch_mut(kind)
+            .get_or_insert_with(|| Batch::new(kind, parallel));
+
+        for definition in definitions {
+            batch.enqueue(definition);
+        }
+
+        self.advance_batch(kind)
+    }
+
+    /// Starts whatever the batch operation of the given kind allows to be started now,
+    /// or reports its outcome if it has finished.
+    fn advance_batch(&mut self, kind: BatchKind) -> Task<GlobalMessage> {
+        let mut tasks = vec![];
+
+        while let Some(batch) = self.batch_mut(kind) {
+            if batch.is_finished() {
+                if let Some(batch) = self.batch_mut(kind).take() {
+                    tasks.push(self.report_batch(batch));
+                }
+                break;
+            }
+
+            let definitions = batch.start_next();
+            if definitions.is_empty() {
+                break;
+            }
+
+            for definition in definitions {
+                // If the opposite operation is running for this filesystem, we'd never get a result for it.
+                // If the same operation is already running (e.g. started individually), its result counts.
+                let is_busy_otherwise = match kind {
+                    BatchKind::Mount => self
+                        .state
+                        .unmounting_in_progress_for_fs_ids
+                        .contains(&definition.id),
+                    BatchKind::Unmount => self
+                        .state
+                        .mounting_in_progress_for_fs_ids
+                        .contains_key(&definition.id),
+                };
+
+                if is_busy_otherwise {
+                    if let Some(batch) = self.batch_mut(kind) {
+                        batch.record(&definition, Some(strings::batch_busy_error()));
+                    }
+                    continue;
+                }
+
+                tasks.push(match kind {
+                    BatchKind::Mount => self.start_mount(definition),
+                    BatchKind::Unmount => self.start_unmount(definition),
+                });
+            }
+        }
+
+        Task::batch(tasks)
+    }
+
+    /// Records the outcome of a mount/unmount operation, if it's part of a batch operation.
+    ///
+    /// Returns `None` if the filesystem is not part of a batch operation.
+    fn record_batch_outcome(
+        &mut self,
+        kind: BatchKind,
+        definition: &FilesystemMountDefinition,
+        error: Option<String>,
+    ) -> Option<Task<GlobalMessage>> {
+        let batch = self.batch_mut(kind).as_mut()?;
+
+        if !batch.record(definition, error) {
+            return None;
+        }
+
+        Some(self.advance_batch(kind))
+    }
+
+    fn report_batch(&mut self, batch: Batch) -> Task<GlobalMessage> {
+        let autostart =
+            batch.kind() == BatchKind::Mount && std::mem::take(&mut self.state.autostart);
+
+        let notification = batch_desktop_notification(&batch);
+
+        if autostart && self.settings.autostart_exit_after_mounting {
+            return notify_desktop_and_exit(notification);
+        }
+
+        // The window is likely minimized after autostarting, so the outcome needs to be noticeable outside of it.
+        let notification = notification.with_force(autostart);
+
+        // The summary dialog is shown by us (instead of being queued as an alert),
+        // because its retry button acts on (and closes) it.
+        self.state.batch_summary = Some(batch);
+
+        notify_desktop(notification)
+    }
+
+    fn alert(&self, title: String, message: String, additional_buttons: Vec<Button>) -> Navigation {
+        let alert_config =
+            AlertConfig::new(title, message).with_additional_buttons(additional_buttons);
+
+        Navigation::None(notify(NotificationSeverity::Error, alert_config))
     }
 }
 
@@ -242,10 +1063,46 @@ impl Page for Home {
         };
 
         match msg {
+            Message::ToggleMenu => {
+                self.state.menu_expanded = !self.state.menu_expanded;
+                Navigation::None(Task::none())
+            }
             Message::About(show) => {
+                self.state.menu_expanded = false;
                 self.state.about_shown = show;
                 Navigation::None(Task::none())
             }
+            Message::ShowActivityLog => {
+                self.state.menu_expanded = false;
+
+                Navigation::None(Task::perform(
+                    async { GlobalMessage::Application(ApplicationMessage::ActivityLog(true)) },
+                    |m| m,
+                ))
+            }
+            Message::OpenSettings => {
+                self.state.menu_expanded = false;
+
+                Navigation::GoTo(
+                    Box::new(SettingsPage::new(self.settings.clone())),
+                    Task::none(),
+                )
+            }
+            Message::ImportSshConfig => {
+                self.state.menu_expanded = false;
+
+                Navigation::GoTo(Box::new(SshImport::new(self.manager.clone())), Task::none())
+            }
+            Message::SettingsChanged => {
+                self.settings = Settings::load();
+
+                let settings = &self.settings;
+                self.state
+                    .reconnecting
+                    .retain(|id, _| settings.keep_mounted(id));
+
+                Navigation::None(Task::none())
+            }
             Message::ToggleSearchEnabled => {
                 self.state.search_enabled = !self.state.search_enabled;
 
@@ -266,64 +1123,109 @@ impl Page for Home {
 
                 Navigation::GoTo(Box::new(page), Task::none())
             }
-            Message::Mount(definition) => {
-                self.state.mounting_in_progress_for_fs_id = Some(definition.id.clone());
-
-                Navigation::None(Task::perform(
-                    async { GlobalMessage::Home(Message::DoMount(definition)) },
-                    |m| m,
-                ))
-            }
-            Message::DoMount(definition) => {
-                // This is intentionally not done asynchronously,
-                // because we want it to happen in the foreground and potentially ask the user
-                // for their password or SSH key passphrase.
-                // If we background it, we can do none of that.
-
-                let result = self.manager.mount(&definition);
+            Message::Mount(definition) => Navigation::None(self.start_mount(definition)),
+            Message::CancelMount(definition) => {
+                if let Some(cancellation) = self
+                    .state
+                    .mounting_in_progress_for_fs_ids
+                    .get(&definition.id)
+                {
+                    log::info!("{}: cancelling mount..", definition.id);
+                    cancellation.cancel();
+                }
 
-                let error = match result {
-                    Ok(_) => None,
-                    Err(e) => Some((e.to_string(), format!("{:?}", e))),
-                };
+                self.state.reconnecting.remove(&definition.id);
 
-                Navigation::None(Task::perform(
-                    async { GlobalMessage::Home(Message::MountResult(definition, error)) },
-                    |m| m,
-                ))
+                Navigation::None(Task::none())
             }
-            Message::MountResult(definition, error) => {
-                self.state.mounting_in_progress_for_fs_id = None;
-
-                let Some((error_human, error_debug)) = error else {
-                    // On success, proceed with mounting the scheduled ones
+            Message::MountResult(definition, error, duration) => {
+                self.state
+                    .mounting_in_progress_for_fs_ids
+                    .remove(&definition.id);
+
+                // Attempts made by the watchdog (see `Message::WatchdogTicked`) are reported on their own.
+                let reconnect = self.state.reconnecting.remove(&definition.id);
+
+                let task_refresh = self.refresh();
+
+                let mount_command = commands_to_string(definition.mount_commands());
+
+                let task_log_activity = Task::batch([
+                    task_refresh,
+                    log_activity(
+                        ActivityEntry::new(
+                            if reconnect.is_some() {
+                                ActivityKind::Reconnect
+                            } else {
+                                ActivityKind::Mount
+                            },
+                            Some(definition.id.clone()),
+                            duration,
+                            error.as_ref().map(|e| e.to_string()),
+                        )
+                        .with_command(mount_command.clone()),
+                    ),
+                ]);
+
+                let error_human = error.as_ref().map(|e| match e {
+                    MountError::Cancelled => strings::mount_cancelled_error(),
+                    MountError::TimedOut(timeout) => {
+                        strings::mount_timed_out_error(timeout.as_secs())
+                    }
+                    MountError::Failed { human, .. } => human.clone(),
+                });
+
+                // Outcomes of batch operations are reported all together, once the whole batch completes.
+                let task_batch =
+                    self.record_batch_outcome(BatchKind::Mount, &definition, error_human.clone());
+
+                if let Some(reconnect) = reconnect {
+                    let task_reconnect =
+                        self.record_reconnect_outcome(&definition, reconnect, error_human);
+
+                    return Navigation::None(Task::batch([
+                        task_log_activity,
+                        task_reconnect,
+                        task_batch.unwrap_or_else(Task::none),
+                    ]));
+                }
 
-                    let Some(next_definition) = self
-                        .state
-                        .mounting_scheduled_for_definitions
-                        .first()
-                        .cloned()
-                    else {
-                        return Navigation::None(Task::none());
-                    };
-
-                    self.state.mounting_scheduled_for_definitions.remove(0);
-
-                    let next_task = Task::perform(
-                        async { Message::Mount(next_definition) },
-                        GlobalMessage::Home,
-                    );
+                if let Some(task_batch) = task_batch {
+                    return Navigation::None(Task::batch([task_log_activity, task_batch]));
+                }
 
-                    return Navigation::None(next_task);
+                let Some(error) = error else {
+                    return Navigation::None(Task::batch([
+                        task_log_activity,
+                        notify_desktop(DesktopNotification::new(
+                            NotificationSeverity::Success,
+                            strings::mount_succeeded_notification_title(&definition.id),
+                            strings::mount_succeeded_notification_message(
+                                &definition.local_mount_path(),
+                            ),
+                        )),
+                    ]));
                 };
 
-                let mount_command = match definition.mount_commands() {
-                    Ok(commands) => {
-                        let command_strings: Vec<String> =
-                            commands.iter().map(|cmd| format!("{:?}", cmd)).collect();
-                        Some(command_strings.join(" && "))
+                let (error_human, error_debug) = match error {
+                    MountError::Cancelled => {
+                        // The user asked for this, so there's no need for an alert.
+                        return Navigation::None(Task::batch([
+                            task_log_activity,
+                            notify(
+                                NotificationSeverity::Info,
+                                AlertConfig::new(
+                                    strings::mount_cancelled_notification_title(&definition.id),
+                                    "".to_owned(),
+                                ),
+                            ),
+                        ]));
                     }
-                    Err(_) => None,
+                    MountError::TimedOut(timeout) => (
+                        strings::mount_timed_out_error(timeout.as_secs()),
+                        format!("{:?}", error),
+                    ),
+                    MountError::Failed { human, debug } => (human, debug),
                 };
 
                 let error_message =
@@ -338,7 +1240,6 @@ impl Page for Home {
                 let mut alert_config = AlertConfig::new(
                     strings::mount_failed_alert_title(&definition.id),
                     error_message,
-                    Box::new(GlobalMessage::Home(Message::Alert(None))),
                 )
                 .with_width(MOUNT_ERROR_MODAL_WIDTH)
                 .with_additional_buttons(additional_buttons);
@@ -350,66 +1251,455 @@ impl Page for Home {
                     ));
                 }
 
-                let task = Task::perform(
-                    async { GlobalMessage::Home(Message::Alert(Some(alert_config))) },
-                    |m| m,
-                );
+                Navigation::None(Task::batch([
+                    task_log_activity,
+                    notify_desktop(
+                        DesktopNotification::new(
+                            NotificationSeverity::Error,
+                            strings::mount_failed_alert_title(&definition.id),
+                            error_human,
+                        )
+                        .with_alert(alert_config),
+                    ),
+                ]))
+            }
+            Message::Unmount(definition) => Navigation::None(self.start_unmount(definition)),
+            Message::Remount(previous, definition) => {
+                if self.is_busy(&previous) {
+                    log::warn!(
+                        "{}: not remounting, as another operation is in progress",
+                        previous.id
+                    );
+
+                    // The definition may have been saved under a new id, in which case the previous one
+                    // was left for us to remove after unmounting. We can't do that, so we report it.
+                    if previous.id != definition.id {
+                        return Navigation::None(notify_previous_definition_kept(
+                            &previous,
+                            &definition,
+                        ));
+                    }
+
+                    return Navigation::None(Task::none());
+                }
+
+                self.state
+                    .remount_after_unmount
+                    .insert(previous.id.clone(), definition);
+
+                Navigation::None(self.start_unmount(previous))
+            }
+            Message::UnmountResult(definition, result, duration) => {
+                // Refreshing while the filesystem is still considered to be unmounting,
+                // so that it doesn't get reported as lost.
+                let task_refresh = self.refresh();
+
+                self.state
+                    .unmounting_in_progress_for_fs_ids
+                    .remove(&definition.id);
+
+                let remount = self.state.remount_after_unmount.remove(&definition.id);
+                let remove = self.state.remove_after_unmount.remove(&definition.id);
+
+                let unmount_command = commands_to_string(definition.umount_commands());
+
+                let is_busy_error = result
+                    .as_ref()
+                    .is_some_and(|e| !e.busy_processes.is_empty());
+
+                // A definition saved under a new id only replaces the previous one once that one gets unmounted (see `finish_remount()`).
+                // Busy filesystems still get unmounted lazily for the remount (see below), so that's reported later (if ever).
+                let task_previous_kept = match &remount {
+                    Some(remount)
+                        if result.is_some() && !is_busy_error && remount.id != definition.id =>
+                    {
+                        notify_previous_definition_kept(&definition, remount)
+                    }
+                    _ => Task::none(),
+                };
+
+                let task_log_activity = Task::batch([
+                    task_refresh,
+                    task_previous_kept,
+                    log_activity(
+                        ActivityEntry::new(
+                            ActivityKind::Unmount,
+                            Some(definition.id.clone()),
+                            duration,
+                            result.as_ref().map(|e| e.human.clone()),
+                        )
+                        .with_command(unmount_command.clone()),
+                    ),
+                ]);
+
+                // Outcomes of batch operations are reported all together, once the whole batch completes.
+                let task_batch = self.record_batch_outcome(
+                    BatchKind::Unmount,
+                    &definition,
+                    result.as_ref().map(|e| e.human.clone()),
+                );
+
+                // Filesystems left mounted may break while the system sleeps,
+                // so ones which fail to get unmounted before it (e.g. busy ones) get unmounted lazily instead.
+                #[cfg(feature = "logind")]
+                {
+                    if result.is_some()
+                        && self
+                            .state
+                            .unmounting_before_sleep_fs_ids
+                            .contains(&definition.id)
+                    {
+                        log::warn!(
+                            "{}: failed to unmount before sleep, unmounting lazily",
+                            definition.id
+                        );
+
+                        return Navigation::None(Task::batch([
+                            task_log_activity,
+                            task_batch.unwrap_or_else(Task::none),
+                            self.start_lazy_unmount(definition, false),
+                        ]));
+                    }
+
+                    self.record_unmount_before_sleep(&definition, result.is_none());
+                }
+
+                // Remounting goes on even if the filesystem was also part of a batch.
+                // Busy filesystems get unmounted lazily (like when repairing), mounting again once that's done.
+                if let Some(remount) = remount {
+                    if result.is_none() {
+                        return Navigation::None(Task::batch([
+                            task_log_activity,
+                            task_batch.unwrap_or_else(Task::none),
+                            self.finish_remount(&definition, remount),
+                        ]));
+                    }
+
+                    if is_busy_error {
+                        log::warn!("{}: busy, unmounting lazily for remounting", definition.id);
+
+                        self.state
+                            .remount_after_unmount
+                            .insert(definition.id.clone(), remount);
+
+                        return Navigation::None(Task::batch([
+                            task_log_activity,
+                            task_batch.unwrap_or_else(Task::none),
+                            self.start_lazy_unmount(definition, false),
+                        ]));
+                    }
+                }
+
+                // Removing goes on even if the filesystem was also part of a batch.
+                // If unmounting failed, that gets reported below and the definition stays.
+                if remove && result.is_none() {
+                    return Navigation::None(Task::batch([
+                        task_log_activity,
+                        task_batch.unwrap_or_else(Task::none),
+                        self.remove_definition(definition),
+                    ]));
+                }
+
+                if let Some(task_batch) = task_batch {
+                    return Navigation::None(Task::batch([task_log_activity, task_batch]));
+                }
+
+                let Some(error) = result else {
+                    return Navigation::None(task_log_activity);
+                };
+
+                // The busy dialog is shown by us (instead of being queued as an alert),
+                // because its buttons act on (and close) it.
+                if !error.busy_processes.is_empty() {
+                    let notification = DesktopNotification::new(
+                        NotificationSeverity::Error,
+                        strings::unmount_failed_alert_title(&definition.id),
+                        strings::unmount_busy_short_message(error.busy_processes.len()),
+                    );
+
+                    self.state.busy_unmount = Some((definition, error));
+
+                    return Navigation::None(Task::batch([
+                        task_log_activity,
+                        notify_desktop(notification),
+                    ]));
+                }
+
+                let UnmountError {
+                    human: error_human,
+                    debug: error_debug,
+                    ..
+                } = error;
+
+                let error_message = strings::unmount_failed_alert_message(
+                    &unmount_command,
+                    &error_human,
+                    &error_debug,
+                );
+
+                let mut additional_buttons = vec![];
+                if let Some(unmount_command) = unmount_command {
+                    additional_buttons.push(create_copy_command_button(unmount_command));
+                }
+                additional_buttons.push(create_copy_error_button(error_debug));
+
+                let mut alert_config = AlertConfig::new(
+                    strings::unmount_failed_alert_title(&definition.id),
+                    error_message,
+                )
+                .with_width(MOUNT_ERROR_MODAL_WIDTH)
+                .with_additional_buttons(additional_buttons);
+
+                #[cfg(feature = "icons")]
+                {
+                    alert_config = alert_config.with_svg_icon_handle(svg::Handle::from_memory(
+                        crate::assets::bootstrap_icons::EXCLAMATION_TRIANGLE,
+                    ));
+                }
+
+                Navigation::None(Task::batch([
+                    task_log_activity,
+                    notify_desktop(
+                        DesktopNotification::new(
+                            NotificationSeverity::Error,
+                            strings::unmount_failed_alert_title(&definition.id),
+                            error_human,
+                        )
+                        .with_alert(alert_config),
+                    ),
+                ]))
+            }
+            Message::RetryBusyUnmount => {
+                let Some((definition, _)) = self.state.busy_unmount.take() else {
+                    return Navigation::None(Task::none());
+                };
+
+                Navigation::None(self.start_unmount(definition))
+            }
+            Message::UnmountBusyLazily => {
+                let Some((definition, _)) = self.state.busy_unmount.take() else {
+                    return Navigation::None(Task::none());
+                };
 
-                Navigation::None(task)
+                Navigation::None(self.start_lazy_unmount(definition, false))
+            }
+            Message::DismissBusyUnmount => {
+                self.state.busy_unmount = None;
+                Navigation::None(Task::none())
             }
-            Message::Unmount(definition) => {
-                self.state.unmounting_in_progress_for_fs_id = Some(definition.id.clone());
+            Message::ProbeHealth => {
+                if self.state.health_probe_in_progress {
+                    return Navigation::None(Task::none());
+                }
+
+                // Filesystems being mounted or unmounted are in flux, so probing them tells us nothing.
+                let definitions = self
+                    .state
+                    .filesystems
+                    .iter()
+                    .filter(|item| item.mounted && !self.is_busy(&item.definition))
+                    .map(|item| item.definition.clone())
+                    .collect::<Vec<_>>();
+
+                if definitions.is_empty() {
+                    return Navigation::None(Task::none());
+                }
+
+                self.state.health_probe_in_progress = true;
+
+                let prober = self.state.health_prober.clone();
 
                 Navigation::None(Task::perform(
-                    async { GlobalMessage::Home(Message::DoUnmount(definition)) },
-                    |m| m,
+                    run_blocking(move || prober.probe(definitions)),
+                    |results| GlobalMessage::Home(Message::HealthProbed(results)),
                 ))
             }
-            Message::DoUnmount(definition) => {
-                let result = self.manager.umount(&definition);
+            Message::HealthProbed(results) => {
+                self.state.health_probe_in_progress = false;
+
+                for (id, health) in results {
+                    match health {
+                        Health::Healthy => {
+                            if self.state.broken_fs_ids.remove(&id).is_some() {
+                                log::info!("{}: responding again", id);
+                            }
+
+                            self.state.reconnect_given_up_fs_ids.remove(&id);
+                        }
+                        Health::Broken(reason) => {
+                            // Filesystems which got unmounted while probing can't be broken.
+                            let is_mounted = self
+                                .state
+                                .filesystems
+                                .iter()
+                                .any(|item| item.definition.id == id && item.mounted);
+
+                            if !is_mounted {
+                                continue;
+                            }
+
+                            if !self.state.broken_fs_ids.contains_key(&id) {
+                                log::warn!("{}: not responding: {}", id, reason);
+                            }
+
+                            if self.settings.keep_mounted(&id)
+                                && !self.state.reconnect_given_up_fs_ids.contains(&id)
+                            {
+                                self.state
+                                    .reconnecting
+                                    .entry(id.clone())
+                                    .or_insert_with(|| Reconnect::new(Instant::now()));
+                            }
+
+                            self.state.broken_fs_ids.insert(id, reason);
+                        }
+                    }
+                }
 
-                let error = match result {
-                    Ok(_) => None,
-                    Err(e) => Some((e.to_string(), format!("{:?}", e))),
-                };
+                Navigation::None(Task::none())
+            }
+            #[cfg(feature = "logind")]
+            Message::Sleep(SleepEvent::Suspending(inhibitor)) => {
+                Navigation::None(self.prepare_for_sleep(inhibitor))
+            }
+            #[cfg(feature = "logind")]
+            Message::Sleep(SleepEvent::Resumed) => {
+                // Unmounting may not have finished before logind stopped waiting for us.
+                if let Some(inhibitor) = self.state.sleep_inhibitor.take() {
+                    inhibitor.release();
+                }
+                self.state.unmounting_before_sleep_fs_ids.clear();
+
+                let definitions = std::mem::take(&mut self.state.remount_after_sleep);
+
+                if definitions.is_empty() {
+                    return Navigation::None(Task::none());
+                }
 
+                log::info!(
+                    "Resumed: waiting for the network, before mounting {} filesystem(s) again",
+                    definitions.len()
+                );
+
+                // The network usually takes a few seconds to come back after resuming,
+                // and mounting before it does would just fail.
                 Navigation::None(Task::perform(
-                    async { GlobalMessage::Home(Message::UnmountResult(definition, error)) },
-                    |m| m,
+                    run_blocking(move || {
+                        network::wait_until_reachable(&definitions, network::RESUME_TIMEOUT);
+                        definitions
+                    }),
+                    |definitions| GlobalMessage::Home(Message::ResumeNetworkReady(definitions)),
                 ))
             }
-            Message::UnmountResult(definition, result) => {
-                self.state.unmounting_in_progress_for_fs_id = None;
+            #[cfg(feature = "logind")]
+            Message::ResumeNetworkReady(definitions) => {
+                // Filesystems may have been removed or mounted (e.g. by the user) in the meantime.
+                let definitions = definitions
+                    .into_iter()
+                    .filter(|definition| {
+                        self.state
+                            .filesystems
+                            .iter()
+                            .any(|item| item.definition.id == definition.id && !item.mounted)
+                    })
+                    .collect::<Vec<_>>();
 
-                let Some((error_human, error_debug)) = result else {
-                    // On success, proceed with unmounting the scheduled ones
+                Navigation::None(self.run_batch(BatchKind::Mount, definitions))
+            }
+            Message::WatchdogTicked => {
+                let now = Instant::now();
+
+                let due = self
+                    .state
+                    .filesystems
+                    .iter()
+                    .filter(|item| {
+                        self.state
+                            .reconnecting
+                            .get(&item.definition.id)
+                            .is_some_and(|reconnect| reconnect.is_due(now))
+                    })
+                    .filter(|item| !self.is_busy(&item.definition))
+                    .cloned()
+                    .collect::<Vec<_>>();
 
-                    let Some(next_definition) = self
-                        .state
-                        .unmounting_scheduled_for_definitions
-                        .first()
-                        .cloned()
-                    else {
-                        return Navigation::None(Task::none());
-                    };
-
-                    self.state.unmounting_scheduled_for_definitions.remove(0);
-
-                    let next_task = Task::perform(
-                        async { Message::Unmount(next_definition) },
-                        GlobalMessage::Home,
-                    );
+                let mut tasks = vec![];
+
+                for item in due {
+                    let attempt = self.state.reconnecting[&item.definition.id].attempt();
+                    log::info!("{}: reconnect attempt {}", item.definition.id, attempt);
+
+                    // Broken mounts need to be unmounted first (see `Message::Repair`).
+                    tasks.push(if item.mounted {
+                        self.start_lazy_unmount(item.definition, true)
+                    } else {
+                        self.start_mount(item.definition)
+                    });
+                }
+
+                Navigation::None(Task::batch(tasks))
+            }
+            Message::Repair(definition) => {
+                // A dead mount can't be unmounted normally (it's "busy" or doesn't respond), so we unmount it lazily.
+                // Mounting it again happens once that's done (see `Message::LazyUnmountResult`).
+                Navigation::None(self.start_lazy_unmount(definition, true))
+            }
+            Message::LazyUnmountResult(definition, remount, result, duration) => {
+                // Refreshing while the filesystem is still considered to be unmounting,
+                // so that it doesn't get reported as lost.
+                let task_refresh = self.refresh();
+
+                self.state
+                    .unmounting_in_progress_for_fs_ids
+                    .remove(&definition.id);
+
+                let remount_definition = self.state.remount_after_unmount.remove(&definition.id);
+
+                #[cfg(feature = "logind")]
+                self.record_unmount_before_sleep(&definition, result.is_none());
 
-                    return Navigation::None(next_task);
+                let unmount_command = commands_to_string(health::lazy_umount_commands(&definition));
+
+                // Remounting busy filesystems ends up here (see `Message::UnmountResult`).
+                let task_previous_kept = match &remount_definition {
+                    Some(remount) if result.is_some() && remount.id != definition.id => {
+                        notify_previous_definition_kept(&definition, remount)
+                    }
+                    _ => Task::none(),
                 };
 
-                let unmount_command = match definition.umount_commands() {
-                    Ok(commands) => {
-                        let command_strings: Vec<String> =
-                            commands.iter().map(|cmd| format!("{:?}", cmd)).collect();
-                        Some(command_strings.join(" && "))
+                let task_log_activity = Task::batch([
+                    task_refresh,
+                    task_previous_kept,
+                    log_activity(
+                        ActivityEntry::new(
+                            ActivityKind::Unmount,
+                            Some(definition.id.clone()),
+                            duration,
+                            result.as_ref().map(|(error_human, _)| error_human.clone()),
+                        )
+                        .with_command(unmount_command.clone()),
+                    ),
+                ]);
+
+                let Some((error_human, error_debug)) = result else {
+                    self.state.broken_fs_ids.remove(&definition.id);
+
+                    if let Some(remount) = remount_definition {
+                        return Navigation::None(Task::batch([
+                            task_log_activity,
+                            self.finish_remount(&definition, remount),
+                        ]));
+                    }
+
+                    if !remount {
+                        return Navigation::None(task_log_activity);
                     }
-                    Err(_) => None,
+
+                    return Navigation::None(Task::batch([
+                        task_log_activity,
+                        self.start_mount(definition),
+                    ]));
                 };
 
                 let error_message = strings::unmount_failed_alert_message(
@@ -424,13 +1714,23 @@ impl Page for Home {
                 }
                 additional_buttons.push(create_copy_error_button(error_debug));
 
-                let mut alert_config = AlertConfig::new(
-                    strings::unmount_failed_alert_title(&definition.id),
-                    error_message,
-                    Box::new(GlobalMessage::Home(Message::Alert(None))),
-                )
-                .with_width(MOUNT_ERROR_MODAL_WIDTH)
-                .with_additional_buttons(additional_buttons);
+                // For reconnect attempts (see `Message::WatchdogTicked`), this is just another failed attempt.
+                if let Some(reconnect) = self.state.reconnecting.remove(&definition.id) {
+                    let task_reconnect =
+                        self.record_reconnect_outcome(&definition, reconnect, Some(error_human));
+
+                    return Navigation::None(Task::batch([task_log_activity, task_reconnect]));
+                }
+
+                let title = if remount {
+                    strings::repair_failed_alert_title(&definition.id)
+                } else {
+                    strings::unmount_failed_alert_title(&definition.id)
+                };
+
+                let mut alert_config = AlertConfig::new(title, error_message)
+                    .with_width(MOUNT_ERROR_MODAL_WIDTH)
+                    .with_additional_buttons(additional_buttons);
 
                 #[cfg(feature = "icons")]
                 {
@@ -439,18 +1739,17 @@ impl Page for Home {
                     ));
                 }
 
-                let task = Task::perform(
-                    async { GlobalMessage::Home(Message::Alert(Some(alert_config))) },
-                    |m| m,
-                );
-
-                Navigation::None(task)
+                Navigation::None(Task::batch([
+                    task_log_activity,
+                    notify(NotificationSeverity::Error, alert_config),
+                ]))
             }
             Message::Open(definition) => {
-                let result = self.manager.open(&definition);
+                let result =
+                    opener::open(&self.settings.open_command, &definition.local_mount_path());
 
                 if let Err(e) = result {
-                    let error_message = strings::open_failed_alert_message(&e.to_string());
+                    let error_message = strings::open_failed_alert_message(&e);
 
                     return self.alert(
                         strings::open_failed_alert_title(&definition.id),
@@ -461,6 +1760,47 @@ impl Page for Home {
 
                 Navigation::None(Task::none())
             }
+            Message::OpenTerminal(definition) => {
+                self.state.expand_options_for_fs_id = None;
+
+                let result = terminal::open(
+                    &self.settings.terminal_command,
+                    terminal::shell_command(),
+                    &definition.local_mount_path(),
+                );
+
+                if let Err(e) = result {
+                    return self.alert(
+                        strings::terminal_failed_alert_title(&definition.id),
+                        e,
+                        vec![],
+                    );
+                }
+
+                Navigation::None(Task::none())
+            }
+            Message::OpenSsh(definition) => {
+                self.state.expand_options_for_fs_id = None;
+
+                // The working directory doesn't matter for SSH sessions, but the mount point may be dead or missing.
+                let cwd = std::env::var("HOME").unwrap_or_else(|_| "/".to_owned());
+
+                let result = terminal::open(
+                    &self.settings.terminal_command,
+                    terminal::ssh_command(&definition),
+                    &cwd,
+                );
+
+                if let Err(e) = result {
+                    return self.alert(
+                        strings::terminal_failed_alert_title(&definition.id),
+                        e,
+                        vec![],
+                    );
+                }
+
+                Navigation::None(Task::none())
+            }
             Message::ToggleOptionsForRecord(definition) => {
                 self.state.expand_options_for_fs_id = if let Some(definition) = definition {
                     Some(definition.id)
@@ -472,6 +1812,24 @@ impl Page for Home {
 
                 Navigation::None(Task::none())
             }
+            Message::Export(definition, format) => {
+                self.state.expand_options_for_fs_id = None;
+                self.state.export = Some(Export::new(&definition, format));
+
+                Navigation::None(Task::none())
+            }
+            Message::DismissExport => {
+                self.state.export = None;
+                Navigation::None(Task::none())
+            }
+            #[cfg(feature = "file-picker")]
+            Message::SaveExport => {
+                let Some(export) = &self.state.export else {
+                    return Navigation::None(Task::none());
+                };
+
+                Navigation::None(save_export(export))
+            }
             Message::EditRecord(definition) => {
                 // Close the options menu
                 self.state.expand_options_for_fs_id = None;
@@ -548,116 +1906,76 @@ impl Page for Home {
                 // Close the options menu
                 self.state.expand_options_for_fs_id = None;
 
-                let result = self.manager.remove(&definition);
-
-                let Err(e) = result else {
-                    return Navigation::None(Task::none());
-                };
-
-                let error_message = strings::remove_failed_alert_message(&e.to_string());
-
-                let mut alert_config = AlertConfig::new(
-                    strings::remove_failed_alert_title(&definition.id),
-                    error_message,
-                    Box::new(GlobalMessage::Home(Message::Alert(None))),
-                );
+                if self.is_busy(&definition) {
+                    log::warn!(
+                        "{}: not removing, as another operation is in progress",
+                        definition.id
+                    );
 
-                #[cfg(feature = "icons")]
-                {
-                    alert_config = alert_config.with_svg_icon_handle(svg::Handle::from_memory(
-                        crate::assets::bootstrap_icons::EXCLAMATION_TRIANGLE,
+                    return Navigation::None(notify(
+                        NotificationSeverity::Warning,
+                        AlertConfig::new(
+                            strings::remove_failed_alert_title(&definition.id),
+                            strings::remove_busy_error(),
+                        ),
                     ));
                 }
 
-                let task = Task::perform(
-                    async { GlobalMessage::Home(Message::Alert(Some(alert_config))) },
-                    |m| m,
-                );
-
-                Navigation::None(task)
-            }
-
-            Message::MountAll => {
-                // This performs synchronous mounting of all unmounted filesystems, by:
-                // - returning a task that immediately mounts the first one
-                // - recording all others in `mounting_scheduled_for_definitions` for later mounting
-                //
-                // `mounting_scheduled_for_definitions` is processed after successful mounting in `Message::MountResult`
-                //
-                // We intentionally mount synchronously (in the foreground) to be able to ask for SSH key passphrases, etc.
+                let is_mounted = self
+                    .state
+                    .filesystems
+                    .iter()
+                    .any(|item| item.definition.id == definition.id && item.mounted);
 
-                let state_filtered_unmounted_only = self
-                    .filesystems_filtered()
-                    .into_iter()
-                    .filter(|item| !item.mounted)
-                    .collect::<Vec<_>>();
-
-                let Some(first_definition) = state_filtered_unmounted_only.first() else {
-                    return Navigation::None(Task::none());
-                };
-
-                let first_def = first_definition.definition.clone();
-                let task_mount_first =
-                    Task::perform(async { Message::Mount(first_def) }, GlobalMessage::Home);
-
-                // Schedule all others
-                for entity in state_filtered_unmounted_only.iter().skip(1) {
+                // Mounted filesystems get unmounted in the background first.
+                // Removing happens once that's done (see `Message::UnmountResult`).
+                if is_mounted {
                     self.state
-                        .mounting_scheduled_for_definitions
-                        .push(entity.definition.clone());
+                        .remove_after_unmount
+                        .insert(definition.id.clone());
+
+                    return Navigation::None(self.start_unmount(definition));
                 }
 
-                Navigation::None(task_mount_first)
+                Navigation::None(self.remove_definition(definition))
             }
-            Message::UnmountAll => {
-                // This performs synchronous unmounting of all mounted filesystems, by:
-                // - returning a task that immediately unmounts the first one
-                // - recording all others in `unmounting_scheduled_for_definitions` for later unmounting
-                //
-                // `unmounting_scheduled_for_definitions` is processed after successful unmounting in `Message::UnmountResult`
-                //
-                // We intentionally unmount synchronously (in the foreground) to be able to ask for SSH key passphrases, etc.
-                // If we background it, we can do none of that.
 
-                let state_filtered_mounted_only = self
-                    .filesystems_filtered()
-                    .into_iter()
-                    .filter(|item| item.mounted)
-                    .collect::<Vec<_>>();
-
-                let Some(first_definition) = state_filtered_mounted_only.first() else {
+            Message::MountAll => Navigation::None(self.mount_all(self.filesystems_filtered())),
+            Message::UnmountAll => Navigation::None(self.unmount_all(self.filesystems_filtered())),
+            Message::MountAllUnfiltered => {
+                Navigation::None(self.mount_all(self.state.filesystems.clone()))
+            }
+            Message::UnmountAllUnfiltered => {
+                Navigation::None(self.unmount_all(self.state.filesystems.clone()))
+            }
+            Message::RetryBatchFailures => {
+                let Some(batch) = self.state.batch_summary.take() else {
                     return Navigation::None(Task::none());
                 };
 
-                let first_def = first_definition.definition.clone();
-                let task_unmount_first =
-                    Task::perform(async { Message::Unmount(first_def) }, GlobalMessage::Home);
-
-                // Schedule all others
-                for entity in state_filtered_mounted_only.iter().skip(1) {
-                    self.state
-                        .unmounting_scheduled_for_definitions
-                        .push(entity.definition.clone());
-                }
+                let definitions = batch
+                    .failed()
+                    .into_iter()
+                    .map(|outcome| outcome.definition.clone())
+                    .collect::<Vec<_>>();
 
-                Navigation::None(task_unmount_first)
-            }
-            Message::Confirmation(confirmation) => {
-                self.state.confirmation = confirmation;
-                Navigation::None(Task::none())
+                Navigation::None(self.run_batch(batch.kind(), definitions))
             }
-            Message::Alert(alert) => {
-                self.state.alert = alert;
+            Message::DismissBatchSummary => {
+                self.state.batch_summary = None;
                 Navigation::None(Task::none())
             }
-            Message::PeriodicRefreshTicked(_instant) => {
-                // The fact that this message got triggered is enough to cause a re-render of whatever page we're on.
-                // There's no need to do anything here.
+            Message::Confirmation(confirmation) => {
+                self.state.confirmation = confirmation;
                 Navigation::None(Task::none())
             }
+            Message::ApplyCliOptions(options) => Navigation::None(self.apply_cli_options(&options)),
+            Message::MountStateChanged => Navigation::None(self.refresh()),
             Message::RunPreflightCheck => {
                 log::info!("Running preflight check");
 
+                let started_at = Instant::now();
+
                 if let Err(errors) = self.manager.preflight_check() {
                     self.state.preflight_check_errors = errors
                         .into_iter()
@@ -667,7 +1985,18 @@ impl Page for Home {
                     self.state.preflight_check_errors = Vec::new();
                 }
 
-                Navigation::None(Task::none())
+                let error = if self.state.preflight_check_errors.is_empty() {
+                    None
+                } else {
+                    Some(self.state.preflight_check_errors.join("\n"))
+                };
+
+                Navigation::None(log_activity(ActivityEntry::new(
+                    ActivityKind::PreflightCheck,
+                    None,
+                    started_at.elapsed(),
+                    error,
+                )))
             }
         }
     }
@@ -677,8 +2006,24 @@ impl Page for Home {
 
         if let Some(payload) = &self.state.confirmation {
             confirmation(container, payload)
-        } else if let Some(payload) = &self.state.alert {
-            alert(container, payload)
+        } else if let Some(batch) = &self.state.batch_summary {
+            alert(
+                container.into(),
+                &batch_summary_alert_config(batch),
+                GlobalMessage::Home(Message::DismissBatchSummary),
+            )
+        } else if let Some((definition, error)) = &self.state.busy_unmount {
+            alert(
+                container.into(),
+                &busy_unmount_alert_config(definition, error),
+                GlobalMessage::Home(Message::DismissBusyUnmount),
+            )
+        } else if let Some(export) = &self.state.export {
+            alert(
+                container.into(),
+                &export_alert_config(export),
+                GlobalMessage::Home(Message::DismissExport),
+            )
         } else if self.state.about_shown {
             about(container, theme)
         } else {
@@ -687,11 +2032,6 @@ impl Page for Home {
     }
 
     fn subscription(&self) -> Subscription<GlobalMessage> {
-        // This refreshes the Home page every X seconds, so that we can show up-to-date information about what's mounted.
-        // The About page is shown as a dialog on top of the home page, so we trigger a refresh on it as well.
-        let periodic_refresh_tick = time::every(Duration::from_millis(REFRESH_INTERVAL_MS))
-            .map(|instant| GlobalMessage::Home(Message::PeriodicRefreshTicked(instant)));
-
         fn handle_hotkey(
             key: keyboard::Key,
             modifiers: keyboard::Modifiers,
@@ -711,16 +2051,48 @@ impl Page for Home {
             }
         }
 
-        Subscription::batch(vec![
-            periodic_refresh_tick,
-            iced::event::listen_with(|event, _status, _window| {
-                 if let iced::event::Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. }) = event {
-                     handle_hotkey(key, modifiers)
-                 } else {
-                     None
-                 }
-            })
-        ])
+        iced::event::listen_with(|event, _status, _window| {
+            if let iced::event::Event::Keyboard(keyboard::Event::KeyPressed {
+                key,
+                modifiers,
+                ..
+            }) = event
+            {
+                handle_hotkey(key, modifiers)
+            } else {
+                None
+            }
+        })
+    }
+
+    fn background_subscription(&self) -> Subscription<GlobalMessage> {
+        let mut subscriptions = vec![];
+
+        // Dead sshfs mounts are still reported as mounted (see `crate::health`), so mounted filesystems get probed periodically.
+        if self.state.filesystems.iter().any(|item| item.mounted) {
+            subscriptions.push(
+                time::every(health::PROBE_INTERVAL)
+                    .map(|_| GlobalMessage::Home(Message::ProbeHealth)),
+            );
+        }
+
+        // The watchdog only needs to run while there are filesystems to reconnect (see `crate::reconnect`).
+        if !self.state.reconnecting.is_empty() {
+            subscriptions.push(
+                time::every(WATCHDOG_INTERVAL)
+                    .map(|_| GlobalMessage::Home(Message::WatchdogTicked)),
+            );
+        }
+
+        // Listening for sleep events also holds a delay inhibitor lock, so we only do it when it's needed.
+        #[cfg(feature = "logind")]
+        if self.settings.unmount_before_sleep != UnmountBeforeSleep::Never {
+            subscriptions.push(
+                logind::sleep_events().map(|event| GlobalMessage::Home(Message::Sleep(event))),
+            );
+        }
+
+        Subscription::batch(subscriptions)
     }
 }
 
@@ -757,3 +2129,289 @@ fn create_copy_error_button(error: String) -> Button {
 
     button
 }
+
+/// Builds the desktop notification reporting the outcome of a finished batch operation.
+///
+/// The outcomes themselves are listed in the summary dialog (see `batch_summary_alert_config()`).
+fn batch_desktop_notification(batch: &Batch) -> DesktopNotification {
+    if !batch.failed().is_empty() {
+        let title = match batch.kind() {
+            BatchKind::Mount => {
+                strings::batch_mount_summary_title(batch.succeeded().len(), batch.total())
+            }
+            BatchKind::Unmount => {
+                strings::batch_unmount_summary_title(batch.succeeded().len(), batch.total())
+            }
+        };
+
+        let failed = batch
+            .failed()
+            .iter()
+            .map(|o| o.definition.id.clone())
+            .collect::<Vec<_>>();
+
+        return DesktopNotification::new(
+            NotificationSeverity::Error,
+            title,
+            strings::batch_summary_failed_short_message(&failed),
+        );
+    }
+
+    let succeeded = batch
+        .succeeded()
+        .iter()
+        .map(|o| o.definition.id.clone())
+        .collect::<Vec<_>>();
+
+    let title = match batch.kind() {
+        BatchKind::Mount => strings::batch_mount_succeeded_notification_title(succeeded.len()),
+        BatchKind::Unmount => strings::batch_unmount_succeeded_notification_title(succeeded.len()),
+    };
+
+    DesktopNotification::new(
+        NotificationSeverity::Success,
+        title,
+        strings::batch_summary_message(&succeeded, &[]),
+    )
+}
+
+fn batch_summary_alert_config(batch: &Batch) -> AlertConfig {
+    let succeeded = batch
+        .succeeded()
+        .iter()
+        .map(|o| o.definition.id.clone())
+        .collect::<Vec<_>>();
+
+    let failed = batch
+        .failed()
+        .iter()
+        .map(|o| (o.definition.id.clone(), o.error.clone().unwrap_or_default()))
+        .collect::<Vec<_>>();
+
+    let title = match batch.kind() {
+        BatchKind::Mount => strings::batch_mount_summary_title(succeeded.len(), batch.total()),
+        BatchKind::Unmount => strings::batch_unmount_summary_title(succeeded.len(), batch.total()),
+    };
+
+    let mut alert_config =
+        AlertConfig::new(title, strings::batch_summary_message(&succeeded, &failed))
+            .with_width(MOUNT_ERROR_MODAL_WIDTH);
+
+    if failed.is_empty() {
+        #[cfg(feature = "icons")]
+        {
+            alert_config = alert_config.with_svg_icon_handle(svg::Handle::from_memory(
+                crate::assets::bootstrap_icons::CHECK_CIRCLE,
+            ));
+        }
+
+        return alert_config;
+    }
+
+    let mut btn_retry = Button::new(strings::batch_summary_retry_failed_button_label())
+        .with_style(ButtonStyle::Primary)
+        .with_on_press(Some(GlobalMessage::Home(Message::RetryBatchFailures)));
+
+    #[cfg(feature = "icons")]
+    {
+        btn_retry = btn_retry.with_svg_icon_handle(svg::Handle::from_memory(
+            crate::assets::bootstrap_icons::ARROW_CLOCKWISE,
+        ));
+    }
+
+    alert_config = alert_config.with_additional_buttons(vec![btn_retry]);
+
+    #[cfg(feature = "icons")]
+    {
+        alert_config = alert_config.with_svg_icon_handle(svg::Handle::from_memory(
+            crate::assets::bootstrap_icons::EXCLAMATION_TRIANGLE,
+        ));
+    }
+
+    alert_config
+}
+
+fn busy_unmount_alert_config(
+    definition: &FilesystemMountDefinition,
+    error: &UnmountError,
+) -> AlertConfig {
+    let processes = error
+        .busy_processes
+        .iter()
+        .map(|process| format!("- {} (PID {}): {}", process.name, process.pid, process.path))
+        .collect::<Vec<_>>();
+
+    let mut btn_lazy = Button::new(strings::unmount_busy_lazy_button_label())
+        .with_style(ButtonStyle::Danger)
+        .with_tooltip(strings::unmount_busy_lazy_button_tooltip())
+        .with_on_press(Some(GlobalMessage::Home(Message::UnmountBusyLazily)));
+
+    #[cfg(feature = "icons")]
+    {
+        btn_lazy = btn_lazy.with_svg_icon_handle(svg::Handle::from_memory(
+            crate::assets::bootstrap_icons::X_CIRCLE,
+        ));
+    }
+
+    let mut btn_retry = Button::new(strings::unmount_busy_retry_button_label())
+        .with_style(ButtonStyle::Primary)
+        .with_on_press(Some(GlobalMessage::Home(Message::RetryBusyUnmount)));
+
+    #[cfg(feature = "icons")]
+    {
+        btn_retry = btn_retry.with_svg_icon_handle(svg::Handle::from_memory(
+            crate::assets::bootstrap_icons::ARROW_CLOCKWISE,
+        ));
+    }
+
+    let mut alert_config = AlertConfig::new(
+        strings::unmount_failed_alert_title(&definition.id),
+        strings::unmount_busy_alert_message(&processes, &error.debug),
+    )
+    .with_width(MOUNT_ERROR_MODAL_WIDTH)
+    .with_additional_buttons(vec![btn_retry, btn_lazy]);
+
+    #[cfg(feature = "icons")]
+    {
+        alert_config = alert_config.with_svg_icon_handle(svg::Handle::from_memory(
+            crate::assets::bootstrap_icons::EXCLAMATION_TRIANGLE,
+        ));
+    }
+
+    alert_config
+}
+
+fn export_alert_config(export: &Export) -> AlertConfig {
+    let title = match export.format {
+        ExportFormat::Systemd => strings::export_systemd_title(&export.id),
+        ExportFormat::Fstab => strings::export_fstab_title(&export.id),
+    };
+
+    let mut btn_copy = Button::new(strings::export_copy_button_label())
+        .with_style(ButtonStyle::SecondaryOutlined)
+        .with_on_press(Some(GlobalMessage::Application(
+            ApplicationMessage::PutContentInClipboard(export.text()),
+        )));
+
+    #[cfg(feature = "icons")]
+    {
+        btn_copy = btn_copy.with_svg_icon_handle(svg::Handle::from_memory(
+            crate::assets::bootstrap_icons::CLIPBOARD_CHECK,
+        ));
+    }
+
+    #[cfg_attr(not(feature = "file-picker"), allow(unused_mut))]
+    let mut buttons = vec![btn_copy];
+
+    #[cfg(feature = "file-picker")]
+    {
+        let mut btn_save = Button::new(strings::export_save_button_label())
+            .with_style(ButtonStyle::SecondaryOutlined)
+            .with_on_press(Some(GlobalMessage::Home(Message::SaveExport)));
+
+        #[cfg(feature = "icons")]
+        {
+            btn_save = btn_save.with_svg_icon_handle(svg::Handle::from_memory(
+                crate::assets::bootstrap_icons::DOWNLOAD,
+            ));
+        }
+
+        buttons.push(btn_save);
+    }
+
+    AlertConfig::new(title, export.text())
+        .with_width(EXPORT_MODAL_WIDTH)
+        .with_additional_buttons(buttons)
+}
+
+/// Asks where to save the exported files and saves them there.
+///
+/// A single file is saved under a name of the user's choosing.
+/// Multiple files (e.g. a pair of systemd units) are saved into a directory of the user's choosing, under their own names.
+#[cfg(feature = "file-picker")]
+fn save_export(export: &Export) -> Task<GlobalMessage> {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    let mut paths = None;
+
+    rt.block_on(async {
+        paths = tokio::task::block_in_place(|| match export.files.as_slice() {
+            [file] => rfd::FileDialog::new()
+                .set_directory("~")
+                .set_file_name(&file.file_name)
+                .save_file()
+                .map(|path| vec![(path, file)]),
+            files => rfd::FileDialog::new()
+                .set_directory("~")
+                .pick_folder()
+                .map(|dir| {
+                    files
+                        .iter()
+                        .map(|file| (dir.join(&file.file_name), file))
+                        .collect()
+                }),
+        });
+    });
+
+    let Some(paths) = paths else {
+        return Task::none();
+    };
+
+    for (path, file) in &paths {
+        if let Err(e) = std::fs::write(path, &file.contents) {
+            log::error!("Failed to save {}: {}", path.display(), e);
+
+            return notify(
+                NotificationSeverity::Error,
+                AlertConfig::new(
+                    strings::export_save_failed_title(),
+                    format!("{}: {}", path.display(), e),
+                ),
+            );
+        }
+    }
+
+    let saved = paths
+        .iter()
+        .map(|(path, _)| path.display().to_string())
+        .collect::<Vec<_>>();
+
+    notify(
+        NotificationSeverity::Info,
+        AlertConfig::new(strings::export_save_succeeded_title(), saved.join("\n")),
+    )
+}
+
+fn notify_previous_definition_kept(
+    previous: &FilesystemMountDefinition,
+    definition: &FilesystemMountDefinition,
+) -> Task<GlobalMessage> {
+    log::warn!(
+        "{}: not removing the previous definition (saved as {}), as it's still mounted",
+        previous.id,
+        definition.id
+    );
+
+    notify(
+        NotificationSeverity::Warning,
+        AlertConfig::new(
+            strings::remount_previous_definition_kept_alert_title(&previous.id),
+            strings::remount_previous_definition_kept_alert_message(&previous.id, &definition.id),
+        ),
+    )
+}
+
+fn notify_unknown_filesystem(id: &str) -> Task<GlobalMessage> {
+    log::warn!(
+        "Ignoring unknown filesystem requested via the command line: {}",
+        id
+    );
+
+    notify(
+        NotificationSeverity::Warning,
+        AlertConfig::new(
+            strings::cli_unknown_filesystem_title(id),
+            strings::cli_unknown_filesystem_message(id),
+        ),
+    )
+}
@@ -1,11 +1,21 @@
 mod about;
+mod activity;
 mod alert;
 mod confirmation;
 mod home;
 mod record;
+mod remote_browser;
+mod settings;
+mod ssh_import;
+mod startup_error;
 
 pub use about::about;
+pub use activity::activity;
 pub use alert::{AlertConfig, alert};
 pub use confirmation::{ConfirmationConfig, confirmation};
 pub use home::{Home, Message as HomeMessage};
 pub use record::{Message as RecordMessage, Record};
+pub use remote_browser::{Message as RemoteBrowserMessage, RemoteBrowser, remote_browser};
+pub use settings::{Message as SettingsMessage, SettingsPage};
+pub use ssh_import::{Message as SshImportMessage, SshImport};
+pub use startup_error::{Message as StartupErrorMessage, StartupError};
@@ -1,7 +1,10 @@
+use std::fs;
+use std::time::Instant;
+
 use iced::Alignment::Center;
 use iced::widget::{
-    Column, Container, Row, Space, column, container, pick_list, row,
-    scrollable, text, text_input,
+    Column, Container, Row, Space, checkbox, column, container, pick_list, row, scrollable, text,
+    text_input,
 };
 use iced::{Element, Length, Padding, Subscription, Task, keyboard};
 
@@ -11,15 +14,21 @@ use iced::widget::svg;
 use libsftpman::validator::Validate;
 use libsftpman::{AuthType, FilesystemMountDefinition, Manager};
 
+use crate::activity_log::{ActivityEntry, ActivityKind, log_activity};
 use crate::application::{Navigation, Page};
+use crate::connection_test::{self, CheckResult};
 use crate::messages::Message as GlobalMessage;
-use crate::pages::{AlertConfig, alert};
+use crate::notifications::{NotificationSeverity, notify};
+use crate::pages::{AlertConfig, HomeMessage, RemoteBrowser, RemoteBrowserMessage, remote_browser};
+use crate::settings::{DEFAULT_MOUNT_TIMEOUT_SECONDS, DefinitionSettings, Settings};
+use crate::state::{definition_path, write_definition};
 use crate::strings;
+use crate::suggestions::{HostSuggestion, Suggestions};
 use crate::ui_config::{
     ICON_SIZE, ICON_TO_TEXT_SPACING, RECORD_LABEL_WIDTH, SCROLLBAR_RESERVED_SPACE,
     WIDGET_HORIZONTAL_SPACING, WIDGET_VERTICAL_SPACING,
 };
-use crate::utils::errors_to_string_list;
+use crate::utils::{errors_to_string_list, run_blocking};
 use crate::widgets::{Button, ButtonStyle, field_control_label, text_link};
 
 #[cfg(feature = "icons")]
@@ -32,15 +41,43 @@ pub enum Message {
 
     DefinitionUpdated(FilesystemMountDefinition),
     PortChanged(u16),
+    MountTimeoutChanged(Option<u64>),
+    MountAtStartupToggled(bool),
+    KeepMountedToggled(bool),
+    #[cfg(feature = "logind")]
+    UnmountBeforeSleepToggled(bool),
 
     #[cfg(feature = "file-picker")]
-    BrowseSshKeyTriggered(State),
+    BrowseSshKeyTriggered,
+
+    BrowseRemotePath,
+    RemoteBrowser(RemoteBrowserMessage),
+
+    TestConnection,
+    ConnectionTestFinished(connection_test::Report),
+    DismissConnectionTest,
 
     KeyboardTabPressed {
         shift: bool,
     },
 
-    Alert(Option<AlertConfig>),
+    ConfigurationChanged,
+    ReloadFromDisk,
+    DismissDiskChange,
+}
+
+// DiskChange describes how the definition being edited changed on disk (outside of this editor).
+#[derive(Clone, Debug)]
+enum DiskChange {
+    Modified(FilesystemMountDefinition),
+    Removed,
+}
+
+// ConnectionTest describes the progress of testing the connection (see `crate::connection_test`).
+#[derive(Clone, Debug)]
+enum ConnectionTest {
+    Running,
+    Finished(connection_test::Report),
 }
 
 #[derive(Clone, Debug)]
@@ -51,7 +88,20 @@ pub struct State {
 
     definition: FilesystemMountDefinition,
 
-    alert: Option<AlertConfig>,
+    // last_seen_on_disk is what we last saw on disk for the definition being edited (`None` if it's missing).
+    // We compare against it (instead of against `original_definition`), so that we only warn once per change.
+    last_seen_on_disk: Option<FilesystemMountDefinition>,
+
+    changed_on_disk: Option<DiskChange>,
+
+    // connection_test holds the last connection test's progress or results, until dismissed.
+    connection_test: Option<ConnectionTest>,
+
+    // definition_settings holds our own settings for this definition, which are not part of the sftpman definition.
+    definition_settings: DefinitionSettings,
+
+    // default_mount_timeout_seconds is the application-wide mount timeout, used when the definition doesn't override it.
+    default_mount_timeout_seconds: u64,
 }
 
 impl State {
@@ -60,11 +110,21 @@ impl State {
             is_existing,
             is_mounted,
             original_definition: definition.clone(),
+            last_seen_on_disk: is_existing.then(|| definition.clone()),
             definition,
-            alert: None,
+            changed_on_disk: None,
+            connection_test: None,
+            definition_settings: DefinitionSettings::default(),
+            default_mount_timeout_seconds: DEFAULT_MOUNT_TIMEOUT_SECONDS,
         }
     }
 
+    fn with_settings(mut self, settings: &Settings) -> Self {
+        self.definition_settings = settings.definition(&self.original_definition.id);
+        self.default_mount_timeout_seconds = settings.mount_timeout_seconds;
+        self
+    }
+
     fn with_definition(mut self, val: FilesystemMountDefinition) -> Self {
         self.definition = val;
         self
@@ -75,18 +135,45 @@ impl State {
     }
 }
 
+// SaveError describes why saving failed and how this should be reported to the user.
+struct SaveError {
+    severity: NotificationSeverity,
+    title: String,
+    message: String,
+}
+
+impl SaveError {
+    fn new(severity: NotificationSeverity, title: String, message: String) -> Self {
+        Self {
+            severity,
+            title,
+            message,
+        }
+    }
+}
+
 pub struct Record {
     manager: Manager,
     state: State,
+
+    // suggestions holds the hosts and users offered below the respective fields (loaded once, when the page opens).
+    suggestions: Suggestions,
+
+    // remote_browser is the (modal) browser for picking the remote path, while it's open.
+    remote_browser: Option<RemoteBrowser>,
 }
 
 impl Record {
     pub fn new(manager: Manager, definition: FilesystemMountDefinition, is_existing: bool) -> Self {
         let is_mounted = manager.is_definition_mounted(&definition).unwrap_or(false);
 
+        let suggestions = Suggestions::load(&manager.definitions().unwrap_or_default());
+
         Self {
             manager,
-            state: State::new(definition, is_existing, is_mounted),
+            state: State::new(definition, is_existing, is_mounted).with_settings(&Settings::load()),
+            suggestions,
+            remote_browser: None,
         }
     }
 
@@ -109,7 +196,11 @@ impl Record {
         definition
     }
 
-    fn save(&self) -> Navigation {
+    /// Persists the definition (and its settings), returning whether it was mounted before saving.
+    ///
+    /// Mounted filesystems need to be mounted again for changes to take effect,
+    /// which is left to the Home page, so that it happens in the background (see `HomeMessage::Remount`).
+    fn save(&self) -> Result<bool, SaveError> {
         let definition = self.state.definition();
 
         if let Err(errors) = definition.validate() {
@@ -122,10 +213,11 @@ impl Record {
                     .join("\n"),
             );
 
-            return self.alert(
+            return Err(SaveError::new(
+                NotificationSeverity::Warning,
                 strings::save_failed_alert_validation_failed_title(),
                 error_message,
-            );
+            ));
         }
 
         // Check for ID uniqueness when:
@@ -141,12 +233,13 @@ impl Record {
             // This is not perfect. Some errors (failing to read files or parse a definition)
             // may be interpretted as "no existing definition".
             if self.manager.definition(&self.state.definition.id).is_ok() {
-                return self.alert(
+                return Err(SaveError::new(
+                    NotificationSeverity::Warning,
                     strings::save_failed_id_check_failed_title(),
                     strings::save_failed_id_uniqueness_check_failed_message(
                         &self.state.definition.id,
                     ),
-                );
+                ));
             };
         }
 
@@ -164,71 +257,84 @@ impl Record {
             false
         };
 
-        if is_mounted_before_save {
-            let unmount_result = self.manager.umount(&self.state.original_definition);
-            if let Err(err) = unmount_result {
-                return self.alert(
-                    strings::operation_failed_alert_title(),
-                    strings::operation_failed_alert_message(&err.to_string()),
-                );
-            }
-        }
+        // `Manager::persist()` would unmount and mount the filesystem again (on this thread),
+        // so for mounted filesystems we only write the definition and leave remounting to the Home page.
+        let persist_result = if is_mounted_before_save {
+            write_definition(definition)
+        } else {
+            self.manager.persist(definition).map_err(|e| e.to_string())
+        };
 
-        if let Err(e) = self.manager.persist(definition) {
-            return self.alert(
+        if let Err(e) = persist_result {
+            return Err(SaveError::new(
+                NotificationSeverity::Error,
                 strings::save_failed_alert_persistence_failed_title(),
-                strings::save_failed_alert_persistence_failed_message(&e.to_string()),
-            );
+                strings::save_failed_alert_persistence_failed_message(&e),
+            ));
         }
 
-        if id_changed {
+        // Removing the definition under its previous id would unmount it (on this thread),
+        // so for mounted filesystems that's also left to the Home page, which does it after unmounting.
+        if id_changed && !is_mounted_before_save {
             let remove_result = self.manager.remove(&self.state.original_definition);
             if let Err(err) = remove_result {
-                return self.alert(
+                return Err(SaveError::new(
+                    NotificationSeverity::Error,
                     strings::operation_failed_alert_title(),
                     strings::operation_failed_alert_message(&err.to_string()),
-                );
+                ));
             }
         }
 
-        if is_mounted_before_save {
-            let mount_result = self.manager.mount(&self.state.definition);
-            if let Err(err) = mount_result {
-                return self.alert(
-                    strings::operation_failed_alert_title(),
-                    strings::operation_failed_alert_message(&err.to_string()),
-                );
+        if let Err(err) = self.save_settings() {
+            // The Home page won't be asked to remount (and thus, to remove the previous definition),
+            // so we take back the new definition instead of leaving both of them around.
+            if id_changed
+                && is_mounted_before_save
+                && let Some(path) = definition_path(&definition.id)
+                && let Err(err) = fs::remove_file(&path)
+            {
+                log::warn!("{}: failed to remove: {}", path.display(), err);
             }
+
+            return Err(SaveError::new(
+                NotificationSeverity::Error,
+                strings::save_failed_alert_persistence_failed_title(),
+                strings::save_failed_alert_persistence_failed_message(&err),
+            ));
         }
 
-        Navigation::Back(Task::none())
+        Ok(is_mounted_before_save)
     }
 
-    fn alert(&self, title: String, message: String) -> Navigation {
-        let alert_config = AlertConfig::new(
-            title,
-            message,
-            Box::new(GlobalMessage::Record(Message::Alert(None))),
-        );
+    fn save_settings(&self) -> Result<(), String> {
+        let mut settings = Settings::load();
 
-        let task = Task::perform(
-            async { GlobalMessage::Record(Message::Alert(Some(alert_config))) },
-            |m| m,
+        // Settings follow the definition when it gets renamed.
+        settings.remove_definition(&self.state.original_definition.id);
+        settings.set_definition(
+            &self.state.definition.id,
+            self.state.definition_settings.clone(),
         );
 
-        Navigation::None(task)
+        settings.save()
     }
 
     fn render(&self, theme: &iced::theme::Theme) -> Container<'static, GlobalMessage> {
         // We expand this to fill the entire window height, because alert/confirmation modals
         // are shown on top of it and a small base container would not result in a well-visible modal.
         let row_scrollable_main_column = scrollable(row![
-            record(self.state.clone(), theme),
+            record(self.state.clone(), &self.suggestions, theme),
             Space::new().width(SCROLLBAR_RESERVED_SPACE)
         ])
         .height(Length::Fill);
 
-        let footer_column = footer(self.state.is_mounted, theme);
+        let footer_column = footer(
+            self.state.is_mounted,
+            self.state.changed_on_disk.as_ref(),
+            self.state.connection_test.as_ref(),
+            theme,
+        );
 
         container(column![row_scrollable_main_column, footer_column])
     }
@@ -254,7 +360,7 @@ impl Page for Record {
                 Navigation::None(Task::none())
             }
             #[cfg(feature = "file-picker")]
-            Message::BrowseSshKeyTriggered(state) => {
+            Message::BrowseSshKeyTriggered => {
                 let rt = tokio::runtime::Runtime::new().unwrap();
 
                 let mut file = None;
@@ -266,14 +372,125 @@ impl Page for Record {
                 });
 
                 if let Some(file) = file {
-                    let mut def = state.definition.clone();
+                    let mut def = self.state.definition.clone();
                     def.ssh_key = file.as_os_str().to_str().unwrap().to_owned();
 
-                    let state = state.with_definition(def);
+                    self.state = self.state.clone().with_definition(def);
+                };
+
+                Navigation::None(Task::none())
+            }
+            Message::BrowseRemotePath => {
+                // Like the connection test, this uses what's in the form (without saving it).
+                let definition = self.post_process_definition(self.state.definition.clone());
+
+                let (browser, task) = RemoteBrowser::new(definition);
+                self.remote_browser = Some(browser);
+
+                Navigation::None(task)
+            }
+            Message::RemoteBrowser(RemoteBrowserMessage::Choose) => {
+                if let Some(path) = self
+                    .remote_browser
+                    .take()
+                    .and_then(|browser| browser.selected().map(|path| path.to_owned()))
+                {
+                    let mut def = self.state.definition.clone();
+                    def.remote_path = path;
+
+                    self.state = self.state.clone().with_definition(def);
+                }
+
+                Navigation::None(Task::none())
+            }
+            Message::RemoteBrowser(RemoteBrowserMessage::Close) => {
+                self.remote_browser = None;
+
+                Navigation::None(Task::none())
+            }
+            Message::RemoteBrowser(msg) => match &mut self.remote_browser {
+                Some(browser) => Navigation::None(browser.update(msg)),
+                // The browser got closed while a directory was still being listed.
+                None => Navigation::None(Task::none()),
+            },
+            Message::ConfigurationChanged => {
+                // New definitions don't exist on disk yet, so there's nothing to compare against.
+                if !self.state.is_existing {
+                    return Navigation::None(Task::none());
+                }
+
+                let on_disk = self
+                    .manager
+                    .definition(&self.state.original_definition.id)
+                    .ok();
 
-                    self.state = state;
+                let is_same = match (&on_disk, &self.state.last_seen_on_disk) {
+                    (Some(a), Some(b)) => is_same_definition(a, b),
+                    (None, None) => true,
+                    _ => false,
                 };
 
+                if is_same {
+                    return Navigation::None(Task::none());
+                }
+
+                log::info!(
+                    "{}: the definition being edited changed on disk",
+                    self.state.original_definition.id
+                );
+
+                self.state.changed_on_disk = Some(match &on_disk {
+                    Some(definition) => DiskChange::Modified(definition.clone()),
+                    None => DiskChange::Removed,
+                });
+                self.state.last_seen_on_disk = on_disk;
+
+                Navigation::None(Task::none())
+            }
+            Message::ReloadFromDisk => {
+                if let Some(DiskChange::Modified(definition)) = self.state.changed_on_disk.take() {
+                    let is_mounted = self
+                        .manager
+                        .is_definition_mounted(&definition)
+                        .unwrap_or(false);
+
+                    self.state = State {
+                        definition_settings: self.state.definition_settings.clone(),
+                        default_mount_timeout_seconds: self.state.default_mount_timeout_seconds,
+                        ..State::new(definition, true, is_mounted)
+                    };
+                }
+
+                Navigation::None(Task::none())
+            }
+            Message::DismissDiskChange => {
+                self.state.changed_on_disk = None;
+
+                Navigation::None(Task::none())
+            }
+            Message::TestConnection => {
+                if let Some(ConnectionTest::Running) = self.state.connection_test {
+                    return Navigation::None(Task::none());
+                }
+
+                self.state.connection_test = Some(ConnectionTest::Running);
+
+                // We test what's in the form, exactly as it would be saved (but without saving it).
+                let definition = self.post_process_definition(self.state.definition.clone());
+
+                Navigation::None(Task::perform(
+                    run_blocking(move || connection_test::run(&definition)),
+                    |report| GlobalMessage::Record(Message::ConnectionTestFinished(report)),
+                ))
+            }
+            Message::ConnectionTestFinished(report) => {
+                self.state.connection_test = Some(ConnectionTest::Finished(report));
+
+                Navigation::None(Task::none())
+            }
+            Message::DismissConnectionTest => {
+                self.state.connection_test = None;
+
                 Navigation::None(Task::none())
             }
             Message::PortChanged(port) => {
@@ -284,26 +501,88 @@ impl Page for Record {
 
                 Navigation::None(Task::none())
             }
-            Message::Alert(alert) => {
-                self.state.alert = alert;
+            Message::MountTimeoutChanged(seconds) => {
+                self.state.definition_settings.mount_timeout_seconds = seconds;
+
+                Navigation::None(Task::none())
+            }
+            Message::MountAtStartupToggled(value) => {
+                self.state.definition_settings.mount_at_startup = value;
+
+                Navigation::None(Task::none())
+            }
+            Message::KeepMountedToggled(value) => {
+                self.state.definition_settings.keep_mounted = value;
+
+                Navigation::None(Task::none())
+            }
+            #[cfg(feature = "logind")]
+            Message::UnmountBeforeSleepToggled(value) => {
+                self.state.definition_settings.unmount_before_sleep = value;
+
                 Navigation::None(Task::none())
             }
             Message::Save => {
                 self.state.definition = self.post_process_definition(self.state.definition.clone());
 
-                self.save()
+                let started_at = Instant::now();
+                let result = self.save();
+
+                let entry = ActivityEntry::new(
+                    ActivityKind::Save,
+                    Some(self.state.definition.id.clone()),
+                    started_at.elapsed(),
+                    result
+                        .as_ref()
+                        .err()
+                        .map(|e| format!("{}: {}", e.title, e.message)),
+                );
+
+                match result {
+                    Ok(is_mounted_before_save) => Navigation::Back(Task::batch([
+                        log_activity(entry),
+                        notify(
+                            NotificationSeverity::Info,
+                            AlertConfig::new(
+                                strings::save_succeeded_notification_title(
+                                    &self.state.definition.id,
+                                ),
+                                "".to_owned(),
+                            ),
+                        ),
+                        Task::perform(async {}, |_| {
+                            GlobalMessage::Home(HomeMessage::SettingsChanged)
+                        }),
+                        if is_mounted_before_save {
+                            let previous = self.state.original_definition.clone();
+                            let definition = self.state.definition.clone();
+
+                            Task::perform(async {}, move |_| {
+                                GlobalMessage::Home(HomeMessage::Remount(
+                                    previous.clone(),
+                                    definition.clone(),
+                                ))
+                            })
+                        } else {
+                            Task::none()
+                        },
+                    ])),
+                    Err(e) => Navigation::None(Task::batch([
+                        log_activity(entry),
+                        notify(e.severity, AlertConfig::new(e.title, e.message)),
+                    ])),
+                }
             }
             Message::Cancel => Navigation::Back(Task::none()),
         }
     }
 
     fn view(&self, theme: &iced::theme::Theme) -> iced::Element<'_, GlobalMessage> {
-        let container = self.render(theme);
+        let content = self.render(theme);
 
-        if let Some(payload) = &self.state.alert {
-            alert(container, payload)
-        } else {
-            container.into()
+        match &self.remote_browser {
+            Some(browser) => remote_browser(content.into(), browser, theme),
+            None => content.into(),
         }
     }
 
@@ -338,7 +617,11 @@ impl Page for Record {
     }
 }
 
-fn record(state: State, theme: &iced::theme::Theme) -> Container<'static, GlobalMessage> {
+fn record(
+    state: State,
+    suggestions: &Suggestions,
+    theme: &iced::theme::Theme,
+) -> Container<'static, GlobalMessage> {
     let definition = state.definition().clone();
 
     let def = definition.clone();
@@ -357,13 +640,38 @@ fn record(state: State, theme: &iced::theme::Theme) -> Container<'static, Global
     .spacing(WIDGET_HORIZONTAL_SPACING);
 
     let def = definition.clone();
-    let row_host = row![
-        field_control_label(strings::record_host_label(), true, theme).width(RECORD_LABEL_WIDTH),
-        text_input(&strings::record_host_placeholder(), &definition.host).on_input(move |v| {
+    let host_text_input = text_input(&strings::record_host_placeholder(), &definition.host)
+        .on_input(move |v| {
             let mut definition_clone = def.clone();
             definition_clone.host = v;
             GlobalMessage::Record(Message::DefinitionUpdated(definition_clone))
+        });
+
+    let host_suggestion_buttons = suggestions
+        .matching_hosts(&definition.host)
+        .into_iter()
+        .map(|suggestion| {
+            let mut definition_clone = definition.clone();
+            suggestion.apply(&mut definition_clone);
+
+            let mut btn = Button::new(suggestion.label())
+                .with_style(ButtonStyle::Link)
+                .with_on_press(Some(GlobalMessage::Record(Message::DefinitionUpdated(
+                    definition_clone,
+                ))));
+
+            if let HostSuggestion::SshConfig(_) = suggestion {
+                btn = btn.with_tooltip(strings::record_host_suggestion_ssh_config_tooltip());
+            }
+
+            btn
         })
+        .collect();
+
+    let row_host = row![
+        field_control_label(strings::record_host_label(), true, theme).width(RECORD_LABEL_WIDTH),
+        column![host_text_input, row_suggestions(host_suggestion_buttons)]
+            .spacing(WIDGET_VERTICAL_SPACING),
     ]
     .spacing(WIDGET_HORIZONTAL_SPACING);
 
@@ -382,15 +690,99 @@ fn record(state: State, theme: &iced::theme::Theme) -> Container<'static, Global
     ]
     .spacing(WIDGET_HORIZONTAL_SPACING);
 
-    let def = definition.clone();
-    let row_username = row![
-        field_control_label(strings::record_username_label(), true, theme)
+    let mount_timeout_seconds = state.definition_settings.mount_timeout_seconds;
+    let row_mount_timeout = row![
+        field_control_label(strings::record_mount_timeout_label(), false, theme)
+            .width(RECORD_LABEL_WIDTH),
+        text_input(
+            &strings::record_mount_timeout_placeholder(state.default_mount_timeout_seconds),
+            &mount_timeout_seconds
+                .map(|seconds| seconds.to_string())
+                .unwrap_or_default(),
+        )
+        .on_input(move |v| {
+            // An empty value means "use the application-wide default".
+            if v.is_empty() {
+                GlobalMessage::Record(Message::MountTimeoutChanged(None))
+            } else if let Ok(val) = v.parse::<u64>() {
+                GlobalMessage::Record(Message::MountTimeoutChanged(Some(val)))
+            } else {
+                GlobalMessage::Record(Message::MountTimeoutChanged(mount_timeout_seconds))
+            }
+        })
+    ]
+    .spacing(WIDGET_HORIZONTAL_SPACING);
+
+    let row_mount_at_startup = row![
+        field_control_label(strings::record_mount_at_startup_label(), false, theme)
+            .width(RECORD_LABEL_WIDTH),
+        column![
+            checkbox(state.definition_settings.mount_at_startup)
+                .label(strings::record_mount_at_startup_checkbox_label())
+                .on_toggle(|v| GlobalMessage::Record(Message::MountAtStartupToggled(v))),
+            text(strings::record_mount_at_startup_help_text()).size(14),
+        ]
+        .spacing(WIDGET_VERTICAL_SPACING),
+    ]
+    .spacing(WIDGET_HORIZONTAL_SPACING);
+
+    #[cfg_attr(not(feature = "logind"), allow(unused_mut))]
+    let mut column_keep_mounted = column![
+        checkbox(state.definition_settings.keep_mounted)
+            .label(strings::record_keep_mounted_checkbox_label())
+            .on_toggle(|v| GlobalMessage::Record(Message::KeepMountedToggled(v))),
+        text(strings::record_keep_mounted_help_text()).size(14),
+    ];
+
+    #[cfg(feature = "logind")]
+    {
+        column_keep_mounted = column_keep_mounted
+            .push(
+                checkbox(state.definition_settings.unmount_before_sleep)
+                    .label(strings::record_unmount_before_sleep_checkbox_label())
+                    .on_toggle(|v| GlobalMessage::Record(Message::UnmountBeforeSleepToggled(v))),
+            )
+            .push(text(strings::record_unmount_before_sleep_help_text()).size(14));
+    }
+
+    let row_keep_mounted = row![
+        field_control_label(strings::record_keep_mounted_label(), false, theme)
             .width(RECORD_LABEL_WIDTH),
-        text_input(&strings::record_username_placeholder(), &def.user).on_input(move |v| {
+        column_keep_mounted.spacing(WIDGET_VERTICAL_SPACING),
+    ]
+    .spacing(WIDGET_HORIZONTAL_SPACING);
+
+    let def = definition.clone();
+    let username_text_input = text_input(&strings::record_username_placeholder(), &def.user)
+        .on_input(move |v| {
             let mut definition_clone = def.clone();
             definition_clone.user = v;
             GlobalMessage::Record(Message::DefinitionUpdated(definition_clone))
+        });
+
+    let user_suggestion_buttons = suggestions
+        .matching_users(&definition.user)
+        .into_iter()
+        .map(|user| {
+            let mut definition_clone = definition.clone();
+            definition_clone.user = user.clone();
+
+            Button::new(user)
+                .with_style(ButtonStyle::Link)
+                .with_on_press(Some(GlobalMessage::Record(Message::DefinitionUpdated(
+                    definition_clone,
+                ))))
         })
+        .collect();
+
+    let row_username = row![
+        field_control_label(strings::record_username_label(), true, theme)
+            .width(RECORD_LABEL_WIDTH),
+        column![
+            username_text_input,
+            row_suggestions(user_suggestion_buttons)
+        ]
+        .spacing(WIDGET_VERTICAL_SPACING),
     ]
     .spacing(WIDGET_HORIZONTAL_SPACING);
 
@@ -431,9 +823,7 @@ fn record(state: State, theme: &iced::theme::Theme) -> Container<'static, Global
         {
             let mut btn_pick_ssh_key = Button::new(strings::browse_label())
                 .with_style(ButtonStyle::Secondary)
-                .with_on_press(Some(GlobalMessage::Record(Message::BrowseSshKeyTriggered(
-                    state,
-                ))));
+                .with_on_press(Some(GlobalMessage::Record(Message::BrowseSshKeyTriggered)));
 
             #[cfg(feature = "icons")]
             {
@@ -457,20 +847,35 @@ fn record(state: State, theme: &iced::theme::Theme) -> Container<'static, Global
     };
 
     let def = definition.clone();
+    let remote_path_text_input = text_input(
+        &strings::record_remote_path_placeholder(),
+        &def.remote_path.clone(),
+    )
+    .on_input(move |v| {
+        let mut definition_clone = def.clone();
+
+        definition_clone.remote_path = v;
+
+        GlobalMessage::Record(Message::DefinitionUpdated(definition_clone))
+    });
+
+    let mut btn_browse_remote_path = Button::new(strings::browse_label())
+        .with_style(ButtonStyle::Secondary)
+        .with_tooltip(strings::record_browse_remote_path_tooltip())
+        .with_on_press(Some(GlobalMessage::Record(Message::BrowseRemotePath)));
+
+    #[cfg(feature = "icons")]
+    {
+        btn_browse_remote_path = btn_browse_remote_path.with_svg_icon_handle(
+            svg::Handle::from_memory(crate::assets::bootstrap_icons::FOLDER2_OPEN),
+        );
+    }
+
     let row_remote_path = row![
         field_control_label(strings::record_remote_path_label(), true, theme)
             .width(RECORD_LABEL_WIDTH),
-        text_input(
-            &strings::record_remote_path_placeholder(),
-            &def.remote_path.clone(),
-        )
-        .on_input(move |v| {
-            let mut definition_clone = def.clone();
-
-            definition_clone.remote_path = v;
-
-            GlobalMessage::Record(Message::DefinitionUpdated(definition_clone))
-        }),
+        row![remote_path_text_input, btn_browse_remote_path.build()]
+            .spacing(WIDGET_HORIZONTAL_SPACING),
     ]
     .spacing(WIDGET_HORIZONTAL_SPACING);
 
@@ -551,6 +956,9 @@ fn record(state: State, theme: &iced::theme::Theme) -> Container<'static, Global
             row_mount_dest_path,
             row_sshfs_options,
             row_command_before_mount,
+            row_mount_timeout,
+            row_mount_at_startup,
+            row_keep_mounted,
         ]
         .spacing(WIDGET_VERTICAL_SPACING)
         .padding(
@@ -562,7 +970,12 @@ fn record(state: State, theme: &iced::theme::Theme) -> Container<'static, Global
     )
 }
 
-fn footer(is_mounted: bool, theme: &iced::theme::Theme) -> Container<'static, GlobalMessage> {
+fn footer(
+    is_mounted: bool,
+    changed_on_disk: Option<&DiskChange>,
+    connection_test: Option<&ConnectionTest>,
+    theme: &iced::theme::Theme,
+) -> Container<'static, GlobalMessage> {
     let mut footer_column = Column::new().spacing(WIDGET_VERTICAL_SPACING).padding(
         Padding::new(0.0)
             .left(SCROLLBAR_RESERVED_SPACE)
@@ -570,6 +983,14 @@ fn footer(is_mounted: bool, theme: &iced::theme::Theme) -> Container<'static, Gl
             .bottom(WIDGET_VERTICAL_SPACING),
     );
 
+    if let Some(changed_on_disk) = changed_on_disk {
+        footer_column = footer_column.push(row_changed_on_disk(changed_on_disk, theme));
+    }
+
+    if let Some(connection_test) = connection_test {
+        footer_column = footer_column.push(row_connection_test(connection_test, theme));
+    }
+
     if is_mounted {
         let mut warning_row = Row::new().spacing(ICON_TO_TEXT_SPACING).align_y(Center);
 
@@ -592,14 +1013,163 @@ fn footer(is_mounted: bool, theme: &iced::theme::Theme) -> Container<'static, Gl
         footer_column = footer_column.push(warning_row);
     }
 
-    let row_controls = row_controls();
+    let is_testing_connection = matches!(connection_test, Some(ConnectionTest::Running));
+
+    let row_controls = row_controls(is_testing_connection);
 
     footer_column = footer_column.push(row_controls);
 
     container(footer_column)
 }
 
-fn row_controls() -> Row<'static, GlobalMessage> {
+fn row_changed_on_disk(
+    changed_on_disk: &DiskChange,
+    theme: &iced::theme::Theme,
+) -> Row<'static, GlobalMessage> {
+    let mut warning_row = Row::new()
+        .spacing(ICON_TO_TEXT_SPACING)
+        .align_y(Center)
+        .width(Length::Fill);
+
+    #[cfg(feature = "icons")]
+    {
+        let icon = icon(
+            &svg::Handle::from_memory(crate::assets::bootstrap_icons::EXCLAMATION_TRIANGLE),
+            ICON_SIZE,
+            IconColor::Danger,
+        );
+
+        warning_row = warning_row.push(icon);
+    }
+
+    let warning_label = match changed_on_disk {
+        DiskChange::Modified(_) => strings::record_changed_on_disk_warning_label(),
+        DiskChange::Removed => strings::record_removed_on_disk_warning_label(),
+    };
+
+    warning_row = warning_row.push(
+        text(warning_label)
+            .color(theme.palette().danger)
+            .width(Length::Fill),
+    );
+
+    let mut buttons_row = Row::new().spacing(WIDGET_HORIZONTAL_SPACING);
+
+    if let DiskChange::Modified(_) = changed_on_disk {
+        let mut btn_reload = Button::new(strings::record_reload_from_disk_label())
+            .with_style(ButtonStyle::Primary)
+            .with_on_press(Some(GlobalMessage::Record(Message::ReloadFromDisk)));
+
+        #[cfg(feature = "icons")]
+        {
+            btn_reload = btn_reload.with_svg_icon_handle(svg::Handle::from_memory(
+                crate::assets::bootstrap_icons::ARROW_CLOCKWISE,
+            ));
+        }
+
+        buttons_row = buttons_row.push(btn_reload.build());
+    }
+
+    buttons_row = buttons_row.push(
+        Button::new(strings::record_keep_my_changes_label())
+            .with_style(ButtonStyle::SecondaryOutlined)
+            .with_on_press(Some(GlobalMessage::Record(Message::DismissDiskChange)))
+            .build(),
+    );
+
+    warning_row.push(buttons_row)
+}
+
+fn row_connection_test(
+    connection_test: &ConnectionTest,
+    theme: &iced::theme::Theme,
+) -> Row<'static, GlobalMessage> {
+    let results_column = match connection_test {
+        ConnectionTest::Running => {
+            column![text(strings::record_connection_test_running_label())]
+        }
+        ConnectionTest::Finished(report) => column![
+            row_check_result(
+                strings::record_connection_test_reachability_label(),
+                &report.reachability,
+                theme,
+            ),
+            row_check_result(
+                strings::record_connection_test_authentication_label(),
+                &report.authentication,
+                theme,
+            ),
+            row_check_result(
+                strings::record_connection_test_remote_path_label(),
+                &report.remote_path,
+                theme,
+            ),
+        ],
+    };
+
+    let mut btn_dismiss = Button::new(strings::record_connection_test_dismiss_label())
+        .with_style(ButtonStyle::SecondaryOutlined);
+
+    if let ConnectionTest::Finished(_) = connection_test {
+        btn_dismiss =
+            btn_dismiss.with_on_press(Some(GlobalMessage::Record(Message::DismissConnectionTest)));
+    }
+
+    row![
+        results_column
+            .spacing(WIDGET_VERTICAL_SPACING)
+            .width(Length::Fill),
+        btn_dismiss.build(),
+    ]
+    .spacing(WIDGET_HORIZONTAL_SPACING)
+    .align_y(Center)
+}
+
+fn row_check_result(
+    label: String,
+    result: &CheckResult,
+    theme: &iced::theme::Theme,
+) -> Row<'static, GlobalMessage> {
+    let palette = theme.palette();
+
+    let (status, color) = match result {
+        CheckResult::Passed => (
+            strings::record_connection_test_passed_label(),
+            palette.success,
+        ),
+        CheckResult::Failed(error) => (error.clone(), palette.danger),
+        CheckResult::Skipped => (
+            strings::record_connection_test_skipped_label(),
+            palette.text,
+        ),
+    };
+
+    let mut result_row = Row::new().spacing(ICON_TO_TEXT_SPACING).align_y(Center);
+
+    #[cfg(feature = "icons")]
+    {
+        let icon_handle = match result {
+            CheckResult::Failed(_) => crate::assets::bootstrap_icons::X_CIRCLE,
+            _ => crate::assets::bootstrap_icons::CHECK_CIRCLE,
+        };
+
+        let icon_color = match result {
+            CheckResult::Passed => IconColor::Primary,
+            CheckResult::Failed(_) => IconColor::Danger,
+            CheckResult::Skipped => IconColor::Text,
+        };
+
+        result_row = result_row.push(icon(
+            &svg::Handle::from_memory(icon_handle),
+            ICON_SIZE,
+            icon_color,
+        ));
+    }
+
+    result_row.push(text(format!("{}: {}", label, status)).color(color))
+}
+
+fn row_controls(is_testing_connection: bool) -> Row<'static, GlobalMessage> {
     let mut btn_save = Button::new(strings::record_save_label())
         .with_style(ButtonStyle::Primary)
         .with_on_press(Some(GlobalMessage::Record(Message::Save)));
@@ -622,7 +1192,44 @@ fn row_controls() -> Row<'static, GlobalMessage> {
         ));
     }
 
-    row![btn_save.build(), Space::new().width(Length::Fill), btn_cancel.build()]
+    let mut btn_test_connection = Button::new(strings::record_test_connection_label())
+        .with_style(ButtonStyle::Secondary)
+        .with_tooltip(strings::record_test_connection_tooltip())
+        .with_on_press(
+            (!is_testing_connection).then_some(GlobalMessage::Record(Message::TestConnection)),
+        );
+
+    #[cfg(feature = "icons")]
+    {
+        btn_test_connection = btn_test_connection.with_svg_icon_handle(svg::Handle::from_memory(
+            crate::assets::bootstrap_icons::CLIPBOARD_CHECK,
+        ));
+    }
+
+    row![
+        btn_save.build(),
+        btn_test_connection.build(),
+        Space::new().width(Length::Fill),
+        btn_cancel.build()
+    ]
+    .spacing(WIDGET_HORIZONTAL_SPACING)
+}
+
+// Lays out suggestion buttons (for picking a value for the field above them), wrapping them as necessary.
+fn row_suggestions(buttons: Vec<Button>) -> Element<'static, GlobalMessage> {
+    if buttons.is_empty() {
+        return container("").height(0).into();
+    }
+
+    let mut row_buttons = row![text(strings::record_suggestions_label()).size(14)]
+        .spacing(WIDGET_HORIZONTAL_SPACING)
+        .align_y(Center);
+
+    for button in buttons {
+        row_buttons = row_buttons.push(button.build());
+    }
+
+    row_buttons.wrap().into()
 }
 
 fn row_sshfs_options_help_text() -> Row<'static, GlobalMessage> {
@@ -647,3 +1254,11 @@ fn row_sshfs_options_help_text() -> Row<'static, GlobalMessage> {
 
     sshfs_options_help_text_row
 }
+
+// FilesystemMountDefinition doesn't implement PartialEq, so we compare serialized versions.
+fn is_same_definition(a: &FilesystemMountDefinition, b: &FilesystemMountDefinition) -> bool {
+    match (a.to_json_string(), b.to_json_string()) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => false,
+    }
+}
@@ -0,0 +1,374 @@
+use std::collections::{HashMap, HashSet};
+
+use iced::Alignment::Center;
+use iced::widget::{Column, Space, column, container, row, scrollable, text};
+use iced::{Element, Length, Task};
+
+#[cfg(feature = "icons")]
+use iced::widget::svg;
+
+use libsftpman::FilesystemMountDefinition;
+
+use crate::messages::Message as GlobalMessage;
+use crate::pages::RecordMessage;
+use crate::sftp;
+use crate::strings;
+use crate::ui_config::{
+    MODAL_MAX_HEIGHT, MODAL_PADDING, MODAL_TITLE_SIZE, MODAL_VERTICAL_SPACING,
+    REMOTE_BROWSER_INDENT, REMOTE_BROWSER_MODAL_WIDTH, SCROLLBAR_RESERVED_SPACE,
+    WIDGET_HORIZONTAL_SPACING,
+};
+use crate::utils::run_blocking;
+use crate::widgets::{Button, ButtonStyle, modal};
+
+// The directory browsing falls back to, if the home directory can't be determined.
+const ROOT_PATH: &str = "/";
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    // StartResolved carries the directory to start browsing at, once the home directory is known (see `RemoteBrowser::new()`).
+    StartResolved(Result<String, String>),
+    GoUp,
+    Toggle(String),
+    Listed(String, Result<Vec<String>, String>),
+    Select(String),
+
+    // Choose and Close are handled by the Record page, which owns the browser.
+    Choose,
+    Close,
+}
+
+// Listing is the state of listing the subdirectories of a directory.
+#[derive(Debug)]
+enum Listing {
+    Loading,
+    Loaded(Vec<String>),
+    Failed(String),
+}
+
+/// RemoteBrowser lets the user pick a directory on the server, browsing it over SFTP (see `sftp::list_directories()`).
+///
+/// Directories are listed lazily, when first expanded.
+pub struct RemoteBrowser {
+    // definition holds the connection details, as they were in the form when the browser got opened.
+    definition: FilesystemMountDefinition,
+
+    // root is the directory at the top of the tree (None while the one to start at is being determined).
+    root: Option<String>,
+
+    listings: HashMap<String, Listing>,
+    expanded: HashSet<String>,
+    selected: Option<String>,
+}
+
+impl RemoteBrowser {
+    /// Creates a browser for the server of the given definition, starting to list the definition's remote path.
+    ///
+    /// Relative (or empty) remote paths are resolved against the home directory, which needs to be determined first.
+    /// The definition's remote path (if any) starts out selected.
+    pub fn new(definition: FilesystemMountDefinition) -> (Self, Task<GlobalMessage>) {
+        let selected = (!definition.remote_path.is_empty()).then(|| definition.remote_path.clone());
+
+        let mut browser = Self {
+            definition,
+            root: None,
+            listings: HashMap::new(),
+            expanded: HashSet::new(),
+            selected,
+        };
+
+        if browser.definition.remote_path.starts_with('/') {
+            let path = browser.definition.remote_path.trim_end_matches('/');
+            let path = if path.is_empty() { ROOT_PATH } else { path };
+
+            let task = browser.open(path.to_owned());
+
+            return (browser, task);
+        }
+
+        let definition = browser.definition.clone();
+
+        let task = Task::perform(
+            run_blocking(move || {
+                let home = sftp::home_directory(&definition)?;
+
+                Ok(if definition.remote_path.is_empty() {
+                    home
+                } else {
+                    sftp::join(&home, &definition.remote_path)
+                })
+            }),
+            |result| {
+                GlobalMessage::Record(RecordMessage::RemoteBrowser(Message::StartResolved(result)))
+            },
+        );
+
+        (browser, task)
+    }
+
+    pub fn selected(&self) -> Option<&str> {
+        self.selected.as_deref()
+    }
+
+    pub fn update(&mut self, message: Message) -> Task<GlobalMessage> {
+        match message {
+            Message::StartResolved(Ok(path)) => self.open(path),
+            Message::StartResolved(Err(e)) => {
+                log::warn!("Failed to determine the home directory: {}", e);
+
+                // The error is shown in place of the root directory's listing, which can be retried from there.
+                self.root = Some(ROOT_PATH.to_owned());
+                self.expanded.insert(ROOT_PATH.to_owned());
+                self.listings
+                    .insert(ROOT_PATH.to_owned(), Listing::Failed(e));
+
+                Task::none()
+            }
+            Message::GoUp => match self.root.as_deref().and_then(sftp::parent) {
+                Some(parent) => self.open(parent),
+                None => Task::none(),
+            },
+            Message::Toggle(path) => self.toggle(path),
+            Message::Listed(path, result) => {
+                let listing = match result {
+                    Ok(names) => Listing::Loaded(names),
+                    Err(e) => {
+                        log::warn!("Failed to list {}: {}", path, e);
+                        Listing::Failed(e)
+                    }
+                };
+
+                self.listings.insert(path, listing);
+
+                Task::none()
+            }
+            Message::Select(path) => {
+                self.selected = Some(path);
+
+                Task::none()
+            }
+            Message::Choose | Message::Close => Task::none(),
+        }
+    }
+
+    // Makes the given directory the top of the tree, expanding it (which lists it, if needed).
+    fn open(&mut self, path: String) -> Task<GlobalMessage> {
+        self.root = Some(path.clone());
+        self.expanded.remove(&path);
+
+        self.toggle(path)
+    }
+
+    // Expands or collapses the given directory, listing it if it hasn't been listed successfully yet.
+    fn toggle(&mut self, path: String) -> Task<GlobalMessage> {
+        if self.expanded.remove(&path) {
+            return Task::none();
+        }
+
+        self.expanded.insert(path.clone());
+
+        if let Some(Listing::Loading | Listing::Loaded(_)) = self.listings.get(&path) {
+            return Task::none();
+        }
+
+        self.listings.insert(path.clone(), Listing::Loading);
+
+        let definition = self.definition.clone();
+
+        Task::perform(
+            run_blocking({
+                let path = path.clone();
+                move || sftp::list_directories(&definition, &path)
+            }),
+            move |result| {
+                GlobalMessage::Record(RecordMessage::RemoteBrowser(Message::Listed(
+                    path.clone(),
+                    result,
+                )))
+            },
+        )
+    }
+
+    // Adds rows for the given directory and (if it's expanded) everything under it.
+    fn push_rows(
+        &self,
+        mut col: Column<'static, GlobalMessage>,
+        path: &str,
+        name: &str,
+        depth: u16,
+        theme: &iced::theme::Theme,
+    ) -> Column<'static, GlobalMessage> {
+        let is_expanded = self.expanded.contains(path);
+
+        col = col.push(self.directory_row(path, name, depth, is_expanded));
+
+        if !is_expanded {
+            return col;
+        }
+
+        let indent = Space::new().width(REMOTE_BROWSER_INDENT * f32::from(depth + 1));
+
+        match self.listings.get(path) {
+            None | Some(Listing::Loading) => col.push(row![
+                indent,
+                text(strings::remote_browser_loading_label()).size(14)
+            ]),
+            Some(Listing::Failed(error)) => col.push(row![
+                indent,
+                text(error.clone())
+                    .size(14)
+                    .color(theme.palette().danger)
+                    .width(Length::Fill)
+            ]),
+            Some(Listing::Loaded(names)) if names.is_empty() => col.push(row![
+                indent,
+                text(strings::remote_browser_no_subdirectories_label()).size(14)
+            ]),
+            Some(Listing::Loaded(names)) => names.iter().fold(col, |col, name| {
+                self.push_rows(col, &sftp::join(path, name), name, depth + 1, theme)
+            }),
+        }
+    }
+
+    fn directory_row(
+        &self,
+        path: &str,
+        name: &str,
+        depth: u16,
+        is_expanded: bool,
+    ) -> Element<'static, GlobalMessage> {
+        let toggle_label = if is_expanded { "-" } else { "+" };
+
+        let btn_toggle = Button::new(toggle_label.to_owned())
+            .with_style(ButtonStyle::Link)
+            .with_on_press(Some(GlobalMessage::Record(RecordMessage::RemoteBrowser(
+                Message::Toggle(path.to_owned()),
+            ))));
+
+        let is_selected = self.selected.as_deref() == Some(path);
+
+        #[cfg_attr(not(feature = "icons"), allow(unused_mut))]
+        let mut btn_select = Button::new(name.to_owned())
+            .with_style(if is_selected {
+                ButtonStyle::Primary
+            } else {
+                ButtonStyle::Link
+            })
+            .with_on_press(Some(GlobalMessage::Record(RecordMessage::RemoteBrowser(
+                Message::Select(path.to_owned()),
+            ))));
+
+        #[cfg(feature = "icons")]
+        {
+            btn_select = btn_select.with_svg_icon_handle(svg::Handle::from_memory(
+                crate::assets::bootstrap_icons::FOLDER2_OPEN,
+            ));
+        }
+
+        row![
+            Space::new().width(REMOTE_BROWSER_INDENT * f32::from(depth)),
+            container(btn_toggle.build()).width(REMOTE_BROWSER_INDENT),
+            btn_select.build(),
+        ]
+        .align_y(Center)
+        .into()
+    }
+}
+
+pub fn remote_browser<'a>(
+    inner_content: Element<'a, GlobalMessage>,
+    browser: &RemoteBrowser,
+    theme: &iced::theme::Theme,
+) -> Element<'a, GlobalMessage> {
+    let definition = &browser.definition;
+
+    let tree_column = match &browser.root {
+        Some(root) => browser.push_rows(Column::new(), root, root, 0, theme),
+        None => Column::new().push(text(strings::remote_browser_loading_label()).size(14)),
+    };
+
+    #[cfg_attr(not(feature = "icons"), allow(unused_mut))]
+    let mut btn_up = Button::new(strings::remote_browser_parent_directory_button_label())
+        .with_style(ButtonStyle::SecondaryOutlined)
+        .with_on_press(
+            browser
+                .root
+                .as_deref()
+                .and_then(sftp::parent)
+                .map(|_| GlobalMessage::Record(RecordMessage::RemoteBrowser(Message::GoUp))),
+        );
+
+    #[cfg(feature = "icons")]
+    {
+        btn_up = btn_up.with_svg_icon_handle(svg::Handle::from_memory(
+            crate::assets::bootstrap_icons::CHEVRON_UP,
+        ));
+    }
+
+    let selected_label = match browser.selected() {
+        Some(path) => strings::remote_browser_selected_label(path),
+        None => strings::remote_browser_nothing_selected_label(),
+    };
+
+    let mut btn_choose = Button::new(strings::remote_browser_choose_button_label())
+        .with_style(ButtonStyle::Primary)
+        .with_on_press(
+            browser
+                .selected()
+                .map(|_| GlobalMessage::Record(RecordMessage::RemoteBrowser(Message::Choose))),
+        );
+
+    #[cfg(feature = "icons")]
+    {
+        btn_choose = btn_choose.with_svg_icon_handle(svg::Handle::from_memory(
+            crate::assets::bootstrap_icons::CHECK_CIRCLE,
+        ));
+    }
+
+    let mut btn_cancel = Button::new(strings::record_cancel_label())
+        .with_style(ButtonStyle::SecondaryOutlined)
+        .with_on_press(Some(GlobalMessage::Record(RecordMessage::RemoteBrowser(
+            Message::Close,
+        ))));
+
+    #[cfg(feature = "icons")]
+    {
+        btn_cancel = btn_cancel.with_svg_icon_handle(svg::Handle::from_memory(
+            crate::assets::bootstrap_icons::ARROW_RETURN_LEFT,
+        ));
+    }
+
+    let dialog = container(
+        column![
+            text(strings::remote_browser_title()).size(MODAL_TITLE_SIZE),
+            text(strings::remote_browser_help_text(
+                &definition.user,
+                &definition.host,
+                definition.port,
+            ))
+            .size(14),
+            btn_up.build(),
+            container(scrollable(
+                row![tree_column, Space::new().width(SCROLLBAR_RESERVED_SPACE)].width(Length::Fill),
+            ))
+            .max_height(MODAL_MAX_HEIGHT),
+            text(selected_label),
+            row![
+                btn_choose.build(),
+                Space::new().width(Length::Fill),
+                btn_cancel.build()
+            ]
+            .spacing(WIDGET_HORIZONTAL_SPACING),
+        ]
+        .spacing(MODAL_VERTICAL_SPACING),
+    )
+    .width(REMOTE_BROWSER_MODAL_WIDTH)
+    .padding(MODAL_PADDING)
+    .style(container::rounded_box);
+
+    modal(
+        inner_content,
+        dialog,
+        GlobalMessage::Record(RecordMessage::RemoteBrowser(Message::Close)),
+    )
+}
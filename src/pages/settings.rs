@@ -0,0 +1,442 @@
+use iced::widget::{
+    Column, Container, Row, Space, checkbox, column, container, row, scrollable, text, text_input,
+};
+use iced::{Length, Padding, Subscription, Task};
+
+#[cfg(feature = "icons")]
+use iced::widget::svg;
+
+#[cfg(feature = "logind")]
+use iced::widget::pick_list;
+
+use crate::application::{Navigation, Page};
+use crate::autostart;
+use crate::messages::Message as GlobalMessage;
+use crate::notifications::{NotificationSeverity, notify};
+use crate::pages::{AlertConfig, HomeMessage};
+use crate::settings::Settings;
+#[cfg(feature = "logind")]
+use crate::settings::UnmountBeforeSleep;
+use crate::strings;
+use crate::ui_config::{
+    RECORD_LABEL_WIDTH, SCROLLBAR_RESERVED_SPACE, WIDGET_HORIZONTAL_SPACING,
+    WIDGET_VERTICAL_SPACING,
+};
+use crate::widgets::{Button, ButtonStyle, field_control_label};
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    Save,
+    Cancel,
+
+    MountTimeoutChanged(u64),
+    ReconnectMaxFailuresChanged(u32),
+    TerminalCommandChanged(String),
+    OpenCommandChanged(String),
+    ParallelBatchOperationsToggled(bool),
+    StartOnLoginToggled(bool),
+    AutostartExitAfterMountingToggled(bool),
+    #[cfg(feature = "tray")]
+    CloseToTrayToggled(bool),
+    #[cfg(feature = "desktop-notifications")]
+    DesktopNotificationsToggled(bool),
+    #[cfg(feature = "logind")]
+    UnmountBeforeSleepChanged(UnmountBeforeSleep),
+}
+
+/// SettingsPage lets the user change application-wide settings (see `crate::settings::Settings`).
+pub struct SettingsPage {
+    settings: Settings,
+
+    // start_on_login tells whether an autostart entry should be installed (see `crate::autostart`).
+    // It's not part of the settings, because the entry itself is the source of truth.
+    start_on_login: bool,
+}
+
+impl SettingsPage {
+    pub fn new(settings: Settings) -> Self {
+        Self {
+            settings,
+            start_on_login: autostart::is_installed(),
+        }
+    }
+
+    fn render(&self, theme: &iced::theme::Theme) -> Container<'static, GlobalMessage> {
+        let row_scrollable_main_column = scrollable(row![
+            form(&self.settings, self.start_on_login, theme),
+            Space::new().width(SCROLLBAR_RESERVED_SPACE)
+        ])
+        .height(Length::Fill);
+
+        let footer_column = Column::new().push(row_controls()).padding(
+            Padding::new(0.0)
+                .left(SCROLLBAR_RESERVED_SPACE)
+                .right(SCROLLBAR_RESERVED_SPACE)
+                .bottom(WIDGET_VERTICAL_SPACING),
+        );
+
+        container(column![row_scrollable_main_column, footer_column])
+    }
+}
+
+impl Page for SettingsPage {
+    fn update(&mut self, message: GlobalMessage) -> Navigation {
+        let GlobalMessage::Settings(msg) = message else {
+            return Navigation::None(Task::none());
+        };
+
+        match msg {
+            Message::MountTimeoutChanged(seconds) => {
+                self.settings.mount_timeout_seconds = seconds;
+
+                Navigation::None(Task::none())
+            }
+            Message::ReconnectMaxFailuresChanged(value) => {
+                self.settings.reconnect_max_failures = value;
+
+                Navigation::None(Task::none())
+            }
+            Message::TerminalCommandChanged(value) => {
+                self.settings.terminal_command = value;
+
+                Navigation::None(Task::none())
+            }
+            Message::OpenCommandChanged(value) => {
+                self.settings.open_command = value;
+
+                Navigation::None(Task::none())
+            }
+            Message::ParallelBatchOperationsToggled(value) => {
+                self.settings.parallel_batch_operations = value;
+
+                Navigation::None(Task::none())
+            }
+            Message::StartOnLoginToggled(value) => {
+                self.start_on_login = value;
+
+                Navigation::None(Task::none())
+            }
+            Message::AutostartExitAfterMountingToggled(value) => {
+                self.settings.autostart_exit_after_mounting = value;
+
+                Navigation::None(Task::none())
+            }
+            #[cfg(feature = "tray")]
+            Message::CloseToTrayToggled(value) => {
+                self.settings.close_to_tray = value;
+
+                Navigation::None(Task::none())
+            }
+            #[cfg(feature = "desktop-notifications")]
+            Message::DesktopNotificationsToggled(value) => {
+                self.settings.desktop_notifications = value;
+
+                Navigation::None(Task::none())
+            }
+            #[cfg(feature = "logind")]
+            Message::UnmountBeforeSleepChanged(value) => {
+                self.settings.unmount_before_sleep = value;
+
+                Navigation::None(Task::none())
+            }
+            Message::Save => {
+                // Per-definition settings may have been changed (via the Record page) since we were opened,
+                // so we only apply what's editable here on top of the latest settings.
+                let mut settings = Settings::load();
+                settings.mount_timeout_seconds = self.settings.mount_timeout_seconds;
+                settings.reconnect_max_failures = self.settings.reconnect_max_failures;
+                settings.terminal_command = self.settings.terminal_command.trim().to_owned();
+                settings.open_command = self.settings.open_command.trim().to_owned();
+                settings.parallel_batch_operations = self.settings.parallel_batch_operations;
+                settings.close_to_tray = self.settings.close_to_tray;
+                settings.desktop_notifications = self.settings.desktop_notifications;
+                settings.unmount_before_sleep = self.settings.unmount_before_sleep;
+                settings.autostart_exit_after_mounting =
+                    self.settings.autostart_exit_after_mounting;
+
+                let result = settings.save().and_then(|()| {
+                    if self.start_on_login == autostart::is_installed() {
+                        return Ok(());
+                    }
+
+                    if self.start_on_login {
+                        autostart::install()
+                    } else {
+                        autostart::uninstall()
+                    }
+                });
+
+                match result {
+                    Ok(()) => Navigation::Back(Task::batch([
+                        notify(
+                            NotificationSeverity::Info,
+                            AlertConfig::new(
+                                strings::settings_save_succeeded_notification_title(),
+                                "".to_owned(),
+                            ),
+                        ),
+                        Task::perform(async {}, |_| {
+                            GlobalMessage::Home(HomeMessage::SettingsChanged)
+                        }),
+                    ])),
+                    Err(e) => {
+                        log::error!("Failed to save settings: {}", e);
+
+                        Navigation::None(notify(
+                            NotificationSeverity::Error,
+                            AlertConfig::new(
+                                strings::save_failed_alert_persistence_failed_title(),
+                                strings::save_failed_alert_persistence_failed_message(&e),
+                            ),
+                        ))
+                    }
+                }
+            }
+            Message::Cancel => Navigation::Back(Task::none()),
+        }
+    }
+
+    fn view(&self, theme: &iced::theme::Theme) -> iced::Element<'_, GlobalMessage> {
+        self.render(theme).into()
+    }
+
+    fn subscription(&self) -> Subscription<GlobalMessage> {
+        Subscription::none()
+    }
+}
+
+fn form(
+    settings: &Settings,
+    start_on_login: bool,
+    theme: &iced::theme::Theme,
+) -> Container<'static, GlobalMessage> {
+    let mount_timeout_seconds = settings.mount_timeout_seconds;
+    let row_mount_timeout = row![
+        field_control_label(strings::settings_mount_timeout_label(), true, theme)
+            .width(RECORD_LABEL_WIDTH),
+        column![
+            text_input("0", &mount_timeout_seconds.to_string()).on_input(move |v| {
+                if v.is_empty() {
+                    GlobalMessage::Settings(Message::MountTimeoutChanged(0))
+                } else if let Ok(val) = v.parse::<u64>() {
+                    GlobalMessage::Settings(Message::MountTimeoutChanged(val))
+                } else {
+                    GlobalMessage::Settings(Message::MountTimeoutChanged(mount_timeout_seconds))
+                }
+            }),
+            text(strings::settings_mount_timeout_help_text()).size(14),
+        ]
+        .spacing(WIDGET_VERTICAL_SPACING),
+    ]
+    .spacing(WIDGET_HORIZONTAL_SPACING);
+
+    let reconnect_max_failures = settings.reconnect_max_failures;
+    let row_reconnect_max_failures = row![
+        field_control_label(
+            strings::settings_reconnect_max_failures_label(),
+            true,
+            theme
+        )
+        .width(RECORD_LABEL_WIDTH),
+        column![
+            text_input("0", &reconnect_max_failures.to_string()).on_input(move |v| {
+                if v.is_empty() {
+                    GlobalMessage::Settings(Message::ReconnectMaxFailuresChanged(0))
+                } else if let Ok(val) = v.parse::<u32>() {
+                    GlobalMessage::Settings(Message::ReconnectMaxFailuresChanged(val))
+                } else {
+                    GlobalMessage::Settings(Message::ReconnectMaxFailuresChanged(
+                        reconnect_max_failures,
+                    ))
+                }
+            }),
+            text(strings::settings_reconnect_max_failures_help_text()).size(14),
+        ]
+        .spacing(WIDGET_VERTICAL_SPACING),
+    ]
+    .spacing(WIDGET_HORIZONTAL_SPACING);
+
+    let row_terminal_command = row![
+        field_control_label(strings::settings_terminal_command_label(), false, theme)
+            .width(RECORD_LABEL_WIDTH),
+        column![
+            text_input(
+                &strings::settings_terminal_command_placeholder(),
+                &settings.terminal_command
+            )
+            .on_input(|v| GlobalMessage::Settings(Message::TerminalCommandChanged(v))),
+            text(strings::settings_terminal_command_help_text()).size(14),
+        ]
+        .spacing(WIDGET_VERTICAL_SPACING),
+    ]
+    .spacing(WIDGET_HORIZONTAL_SPACING);
+
+    let row_open_command = row![
+        field_control_label(strings::settings_open_command_label(), false, theme)
+            .width(RECORD_LABEL_WIDTH),
+        column![
+            text_input(
+                &strings::settings_open_command_placeholder(),
+                &settings.open_command
+            )
+            .on_input(|v| GlobalMessage::Settings(Message::OpenCommandChanged(v))),
+            text(strings::settings_open_command_help_text()).size(14),
+        ]
+        .spacing(WIDGET_VERTICAL_SPACING),
+    ]
+    .spacing(WIDGET_HORIZONTAL_SPACING);
+
+    let row_parallel_batch_operations = row![
+        field_control_label(
+            strings::settings_parallel_batch_operations_label(),
+            false,
+            theme
+        )
+        .width(RECORD_LABEL_WIDTH),
+        column![
+            checkbox(settings.parallel_batch_operations)
+                .label(strings::settings_parallel_batch_operations_checkbox_label())
+                .on_toggle(|v| GlobalMessage::Settings(Message::ParallelBatchOperationsToggled(v))),
+            text(strings::settings_parallel_batch_operations_help_text()).size(14),
+        ]
+        .spacing(WIDGET_VERTICAL_SPACING),
+    ]
+    .spacing(WIDGET_HORIZONTAL_SPACING);
+
+    let row_start_on_login = row![
+        field_control_label(strings::settings_start_on_login_label(), false, theme)
+            .width(RECORD_LABEL_WIDTH),
+        column![
+            checkbox(start_on_login)
+                .label(strings::settings_start_on_login_checkbox_label())
+                .on_toggle(|v| GlobalMessage::Settings(Message::StartOnLoginToggled(v))),
+            checkbox(settings.autostart_exit_after_mounting)
+                .label(strings::settings_autostart_exit_after_mounting_checkbox_label())
+                .on_toggle(|v| {
+                    GlobalMessage::Settings(Message::AutostartExitAfterMountingToggled(v))
+                }),
+            text(strings::settings_start_on_login_help_text()).size(14),
+        ]
+        .spacing(WIDGET_VERTICAL_SPACING),
+    ]
+    .spacing(WIDGET_HORIZONTAL_SPACING);
+
+    #[cfg_attr(
+        not(any(
+            feature = "tray",
+            feature = "desktop-notifications",
+            feature = "logind"
+        )),
+        allow(unused_mut)
+    )]
+    let mut rows = column![
+        row_mount_timeout,
+        row_reconnect_max_failures,
+        row_parallel_batch_operations,
+        row_start_on_login,
+        row_open_command,
+        row_terminal_command
+    ];
+
+    // Without a tray icon, there'd be no way to bring a hidden window back.
+    #[cfg(feature = "tray")]
+    {
+        let row_close_to_tray = row![
+            field_control_label(strings::settings_close_to_tray_label(), false, theme)
+                .width(RECORD_LABEL_WIDTH),
+            column![
+                checkbox(settings.close_to_tray)
+                    .label(strings::settings_close_to_tray_checkbox_label())
+                    .on_toggle(|v| GlobalMessage::Settings(Message::CloseToTrayToggled(v))),
+                text(strings::settings_close_to_tray_help_text()).size(14),
+            ]
+            .spacing(WIDGET_VERTICAL_SPACING),
+        ]
+        .spacing(WIDGET_HORIZONTAL_SPACING);
+
+        rows = rows.push(row_close_to_tray);
+    }
+
+    #[cfg(feature = "desktop-notifications")]
+    {
+        let row_desktop_notifications = row![
+            field_control_label(
+                strings::settings_desktop_notifications_label(),
+                false,
+                theme
+            )
+            .width(RECORD_LABEL_WIDTH),
+            column![
+                checkbox(settings.desktop_notifications)
+                    .label(strings::settings_desktop_notifications_checkbox_label())
+                    .on_toggle(
+                        |v| GlobalMessage::Settings(Message::DesktopNotificationsToggled(v))
+                    ),
+                text(strings::settings_desktop_notifications_help_text()).size(14),
+            ]
+            .spacing(WIDGET_VERTICAL_SPACING),
+        ]
+        .spacing(WIDGET_HORIZONTAL_SPACING);
+
+        rows = rows.push(row_desktop_notifications);
+    }
+
+    #[cfg(feature = "logind")]
+    {
+        let row_unmount_before_sleep = row![
+            field_control_label(strings::settings_unmount_before_sleep_label(), false, theme)
+                .width(RECORD_LABEL_WIDTH),
+            column![
+                pick_list(
+                    &UnmountBeforeSleep::ALL[..],
+                    Some(settings.unmount_before_sleep),
+                    |v| GlobalMessage::Settings(Message::UnmountBeforeSleepChanged(v))
+                ),
+                text(strings::settings_unmount_before_sleep_help_text()).size(14),
+            ]
+            .spacing(WIDGET_VERTICAL_SPACING),
+        ]
+        .spacing(WIDGET_HORIZONTAL_SPACING);
+
+        rows = rows.push(row_unmount_before_sleep);
+    }
+
+    container(
+        rows.spacing(WIDGET_VERTICAL_SPACING).padding(
+            Padding::new(0.0)
+                .top(WIDGET_VERTICAL_SPACING)
+                .bottom(WIDGET_VERTICAL_SPACING)
+                .left(SCROLLBAR_RESERVED_SPACE),
+        ),
+    )
+}
+
+fn row_controls() -> Row<'static, GlobalMessage> {
+    let mut btn_save = Button::new(strings::record_save_label())
+        .with_style(ButtonStyle::Primary)
+        .with_on_press(Some(GlobalMessage::Settings(Message::Save)));
+
+    #[cfg(feature = "icons")]
+    {
+        btn_save = btn_save.with_svg_icon_handle(svg::Handle::from_memory(
+            crate::assets::bootstrap_icons::CHECK_CIRCLE,
+        ));
+    }
+
+    let mut btn_cancel = Button::new(strings::record_cancel_label())
+        .with_style(ButtonStyle::Secondary)
+        .with_on_press(Some(GlobalMessage::Settings(Message::Cancel)));
+
+    #[cfg(feature = "icons")]
+    {
+        btn_cancel = btn_cancel.with_svg_icon_handle(svg::Handle::from_memory(
+            crate::assets::bootstrap_icons::ARROW_RETURN_LEFT,
+        ));
+    }
+
+    row![
+        btn_save.build(),
+        Space::new().width(Length::Fill),
+        btn_cancel.build()
+    ]
+}
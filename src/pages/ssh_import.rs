@@ -0,0 +1,319 @@
+use std::collections::HashSet;
+use std::time::Instant;
+
+use iced::widget::{
+    Column, Container, Row, Space, checkbox, column, container, row, scrollable, text,
+};
+use iced::{Length, Padding, Subscription, Task};
+
+#[cfg(feature = "icons")]
+use iced::widget::svg;
+
+use libsftpman::validator::Validate;
+use libsftpman::{FilesystemMountDefinition, Manager};
+
+use crate::activity_log::{ActivityEntry, ActivityKind, log_activity};
+use crate::application::{Navigation, Page};
+use crate::messages::Message as GlobalMessage;
+use crate::notifications::{NotificationSeverity, notify};
+use crate::pages::AlertConfig;
+use crate::ssh_config;
+use crate::state::definition_path;
+use crate::strings;
+use crate::ui_config::{SCROLLBAR_RESERVED_SPACE, WIDGET_VERTICAL_SPACING};
+use crate::utils::errors_to_string_list;
+use crate::widgets::{Button, ButtonStyle};
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    Import,
+    Cancel,
+
+    Toggled(usize, bool),
+}
+
+// Candidate is a host from `~/.ssh/config`, along with the definition it would be imported as.
+struct Candidate {
+    alias: String,
+    definition: FilesystemMountDefinition,
+    selected: bool,
+
+    // conflict tells whether a definition with the same id already exists (or comes earlier in the list).
+    // Such hosts can't be selected, because importing them would overwrite the existing definition.
+    conflict: bool,
+}
+
+/// SshImport lets the user pick hosts from `~/.ssh/config` to import as new definitions (see `crate::ssh_config`).
+pub struct SshImport {
+    manager: Manager,
+    candidates: Vec<Candidate>,
+
+    // load_error holds the reason why the SSH config could not be loaded (if it couldn't).
+    load_error: Option<String>,
+}
+
+impl SshImport {
+    pub fn new(manager: Manager) -> Self {
+        let (candidates, load_error) = match ssh_config::load() {
+            Ok(hosts) => (candidates(&manager, hosts), None),
+            Err(e) => {
+                log::warn!("Failed to load the SSH config: {}", e);
+                (vec![], Some(e))
+            }
+        };
+
+        Self {
+            manager,
+            candidates,
+            load_error,
+        }
+    }
+
+    /// Persists the selected definitions, returning how many got imported and the errors (one per failed definition).
+    fn import(&mut self) -> (usize, Vec<String>, Vec<Task<GlobalMessage>>) {
+        let mut imported_count = 0;
+        let mut errors = vec![];
+        let mut tasks = vec![];
+
+        for candidate in self.candidates.iter_mut().filter(|c| c.selected) {
+            let started_at = Instant::now();
+
+            let result = import_definition(&self.manager, &candidate.definition);
+
+            if result.is_ok() {
+                imported_count += 1;
+
+                // It exists now, so importing it again would conflict.
+                candidate.selected = false;
+                candidate.conflict = true;
+            }
+
+            tasks.push(log_activity(ActivityEntry::new(
+                ActivityKind::Save,
+                Some(candidate.definition.id.clone()),
+                started_at.elapsed(),
+                result.as_ref().err().cloned(),
+            )));
+
+            if let Err(e) = result {
+                errors.push(format!("- {}: {}", candidate.definition.id, e));
+            }
+        }
+
+        (imported_count, errors, tasks)
+    }
+
+    fn render(&self, theme: &iced::theme::Theme) -> Container<'static, GlobalMessage> {
+        let row_scrollable_main_column = scrollable(row![
+            self.candidates_column(theme),
+            Space::new().width(SCROLLBAR_RESERVED_SPACE)
+        ])
+        .height(Length::Fill);
+
+        let selected_count = self.candidates.iter().filter(|c| c.selected).count();
+
+        let footer_column = Column::new().push(row_controls(selected_count)).padding(
+            Padding::new(0.0)
+                .left(SCROLLBAR_RESERVED_SPACE)
+                .right(SCROLLBAR_RESERVED_SPACE)
+                .bottom(WIDGET_VERTICAL_SPACING),
+        );
+
+        container(column![row_scrollable_main_column, footer_column])
+    }
+
+    fn candidates_column(&self, theme: &iced::theme::Theme) -> Column<'static, GlobalMessage> {
+        let mut col = Column::new().spacing(WIDGET_VERTICAL_SPACING).padding(
+            Padding::new(0.0)
+                .top(WIDGET_VERTICAL_SPACING)
+                .bottom(WIDGET_VERTICAL_SPACING)
+                .left(SCROLLBAR_RESERVED_SPACE),
+        );
+
+        col = col.push(text(strings::ssh_import_help_text()).size(14));
+
+        if let Some(error) = &self.load_error {
+            return col.push(
+                text(strings::ssh_import_load_failed_message(error)).color(theme.palette().danger),
+            );
+        }
+
+        if self.candidates.is_empty() {
+            return col.push(text(strings::ssh_import_no_hosts_message()));
+        }
+
+        for (index, candidate) in self.candidates.iter().enumerate() {
+            col = col.push(candidate_row(index, candidate, theme));
+        }
+
+        col
+    }
+}
+
+impl Page for SshImport {
+    fn update(&mut self, message: GlobalMessage) -> Navigation {
+        let GlobalMessage::SshImport(msg) = message else {
+            return Navigation::None(Task::none());
+        };
+
+        match msg {
+            Message::Toggled(index, value) => {
+                if let Some(candidate) = self.candidates.get_mut(index)
+                    && !candidate.conflict
+                {
+                    candidate.selected = value;
+                }
+
+                Navigation::None(Task::none())
+            }
+            Message::Import => {
+                let (imported_count, errors, mut tasks) = self.import();
+
+                if errors.is_empty() {
+                    tasks.push(notify(
+                        NotificationSeverity::Info,
+                        AlertConfig::new(
+                            strings::ssh_import_succeeded_notification_title(imported_count),
+                            "".to_owned(),
+                        ),
+                    ));
+
+                    return Navigation::Back(Task::batch(tasks));
+                }
+
+                // Staying on the page, so that the failed hosts can be unselected (or fixed in the SSH config).
+                tasks.push(notify(
+                    NotificationSeverity::Error,
+                    AlertConfig::new(
+                        strings::ssh_import_failed_alert_title(),
+                        strings::ssh_import_failed_alert_message(
+                            imported_count,
+                            &errors.join("\n"),
+                        ),
+                    ),
+                ));
+
+                Navigation::None(Task::batch(tasks))
+            }
+            Message::Cancel => Navigation::Back(Task::none()),
+        }
+    }
+
+    fn view(&self, theme: &iced::theme::Theme) -> iced::Element<'_, GlobalMessage> {
+        self.render(theme).into()
+    }
+
+    fn subscription(&self) -> Subscription<GlobalMessage> {
+        Subscription::none()
+    }
+}
+
+fn candidates(manager: &Manager, hosts: Vec<ssh_config::SshHost>) -> Vec<Candidate> {
+    let mut seen_ids = HashSet::new();
+
+    hosts
+        .into_iter()
+        .map(|host| {
+            let definition = host.to_definition();
+
+            let conflict = !seen_ids.insert(definition.id.clone())
+                || definition_exists(manager, &definition.id);
+
+            Candidate {
+                alias: host.alias,
+                definition,
+                selected: false,
+                conflict,
+            }
+        })
+        .collect()
+}
+
+fn import_definition(
+    manager: &Manager,
+    definition: &FilesystemMountDefinition,
+) -> Result<(), String> {
+    if let Err(errors) = definition.validate() {
+        return Err(errors_to_string_list(errors).join(" "));
+    }
+
+    // The list may be outdated (e.g. if the definition got created in the meantime).
+    if definition_exists(manager, &definition.id) {
+        return Err(strings::save_failed_id_uniqueness_check_failed_message(
+            &definition.id,
+        ));
+    }
+
+    manager.persist(definition).map_err(|e| e.to_string())
+}
+
+// Unlike the Record page, we don't only consider definitions which can be loaded.
+// A definition file which fails to parse (see `crate::state::InvalidDefinition`) is still shown in the list,
+// so that the user can fix it, and importing over it would silently lose it.
+fn definition_exists(manager: &Manager, id: &str) -> bool {
+    manager.definition(id).is_ok() || definition_path(id).is_some_and(|path| path.exists())
+}
+
+fn candidate_row(
+    index: usize,
+    candidate: &Candidate,
+    theme: &iced::theme::Theme,
+) -> Column<'static, GlobalMessage> {
+    let definition = &candidate.definition;
+
+    let mut checkbox = checkbox(candidate.selected).label(candidate.alias.clone());
+    if !candidate.conflict {
+        checkbox =
+            checkbox.on_toggle(move |v| GlobalMessage::SshImport(Message::Toggled(index, v)));
+    }
+
+    let details = strings::ssh_import_host_details(
+        &definition.id,
+        &definition.user,
+        &definition.host,
+        definition.port,
+        &definition.ssh_key,
+    );
+
+    let mut col = column![checkbox, text(details).size(14)].spacing(2);
+
+    if candidate.conflict {
+        col = col.push(
+            text(strings::ssh_import_conflict_message(&definition.id))
+                .size(14)
+                .color(theme.palette().danger),
+        );
+    }
+
+    col
+}
+
+fn row_controls(selected_count: usize) -> Row<'static, GlobalMessage> {
+    let mut btn_import = Button::new(strings::ssh_import_button_label(selected_count))
+        .with_style(ButtonStyle::Primary)
+        .with_on_press((selected_count > 0).then_some(GlobalMessage::SshImport(Message::Import)));
+
+    #[cfg(feature = "icons")]
+    {
+        btn_import = btn_import.with_svg_icon_handle(svg::Handle::from_memory(
+            crate::assets::bootstrap_icons::DOWNLOAD,
+        ));
+    }
+
+    let mut btn_cancel = Button::new(strings::record_cancel_label())
+        .with_style(ButtonStyle::Secondary)
+        .with_on_press(Some(GlobalMessage::SshImport(Message::Cancel)));
+
+    #[cfg(feature = "icons")]
+    {
+        btn_cancel = btn_cancel.with_svg_icon_handle(svg::Handle::from_memory(
+            crate::assets::bootstrap_icons::ARROW_RETURN_LEFT,
+        ));
+    }
+
+    row![
+        btn_import.build(),
+        Space::new().width(Length::Fill),
+        btn_cancel.build()
+    ]
+}
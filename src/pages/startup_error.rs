@@ -0,0 +1,154 @@
+use iced::Alignment::Center;
+use iced::widget::{Container, Space, column, container, row, text};
+use iced::{Length, Subscription, Task};
+
+#[cfg(feature = "icons")]
+use iced::widget::svg;
+
+use crate::application::{ApplicationMessage, Navigation, Page};
+use crate::messages::Message as GlobalMessage;
+use crate::pages::{Home, HomeMessage};
+use crate::strings;
+use crate::ui_config::{
+    MODAL_MESSAGE_SIZE, MODAL_TITLE_SIZE, MODAL_VERTICAL_SPACING, MODAL_WIDTH,
+    WIDGET_HORIZONTAL_SPACING,
+};
+use crate::utils::sftpman_config_dir;
+use crate::widgets::{Button, ButtonStyle};
+
+#[cfg(feature = "icons")]
+use crate::widgets::{IconColor, icon};
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    Retry,
+    OpenConfigDirectory,
+}
+
+/// StartupError is shown instead of the Home page when the sftpman configuration can't be loaded.
+pub struct StartupError {
+    error: String,
+}
+
+impl StartupError {
+    pub fn new(error: String) -> Self {
+        Self { error }
+    }
+
+    fn render(&self) -> Container<'static, GlobalMessage> {
+        // Intentionally not using ICON_TO_TEXT_SPACING here, because the heading is large and needs more spacing.
+        let mut heading_row = row![].spacing(WIDGET_HORIZONTAL_SPACING).align_y(Center);
+
+        #[cfg(feature = "icons")]
+        {
+            heading_row = heading_row.push(icon(
+                &svg::Handle::from_memory(crate::assets::bootstrap_icons::EXCLAMATION_TRIANGLE),
+                MODAL_TITLE_SIZE,
+                IconColor::Danger,
+            ));
+        }
+
+        heading_row = heading_row.push(text(strings::startup_error_title()).size(MODAL_TITLE_SIZE));
+
+        let mut btn_retry = Button::new(strings::button_retry())
+            .with_style(ButtonStyle::Primary)
+            .with_on_press(Some(GlobalMessage::StartupError(Message::Retry)));
+
+        #[cfg(feature = "icons")]
+        {
+            btn_retry = btn_retry.with_svg_icon_handle(svg::Handle::from_memory(
+                crate::assets::bootstrap_icons::ARROW_CLOCKWISE,
+            ));
+        }
+
+        let config_dir = sftpman_config_dir();
+
+        let mut btn_open_config_directory =
+            Button::new(strings::startup_error_open_config_directory_button_label())
+                .with_style(ButtonStyle::Secondary)
+                .with_on_press(
+                    config_dir
+                        .is_some()
+                        .then_some(GlobalMessage::StartupError(Message::OpenConfigDirectory)),
+                );
+
+        if let Some(config_dir) = config_dir {
+            btn_open_config_directory =
+                btn_open_config_directory.with_tooltip(config_dir.display().to_string());
+        }
+
+        #[cfg(feature = "icons")]
+        {
+            btn_open_config_directory = btn_open_config_directory.with_svg_icon_handle(
+                svg::Handle::from_memory(crate::assets::bootstrap_icons::FOLDER2_OPEN),
+            );
+        }
+
+        let content = column![
+            heading_row,
+            text(strings::startup_error_message(&self.error)).size(MODAL_MESSAGE_SIZE),
+            row![
+                btn_open_config_directory.build(),
+                Space::new().width(Length::Fill),
+                btn_retry.build(),
+            ]
+            .spacing(WIDGET_HORIZONTAL_SPACING),
+        ]
+        .spacing(MODAL_VERTICAL_SPACING)
+        .width(MODAL_WIDTH);
+
+        container(content).center(Length::Fill)
+    }
+}
+
+impl Page for StartupError {
+    fn update(&mut self, message: GlobalMessage) -> Navigation {
+        let GlobalMessage::StartupError(msg) = message else {
+            return Navigation::None(Task::none());
+        };
+
+        match msg {
+            Message::Retry => match Home::load() {
+                Ok(home) => Navigation::Replace(
+                    Box::new(home),
+                    Task::perform(async {}, |_| {
+                        GlobalMessage::Home(HomeMessage::RunPreflightCheck)
+                    }),
+                ),
+                Err(error) => {
+                    log::error!("Failed to load the sftpman configuration: {}", error);
+
+                    self.error = error;
+                    Navigation::None(Task::none())
+                }
+            },
+            Message::OpenConfigDirectory => {
+                let Some(config_dir) = sftpman_config_dir() else {
+                    return Navigation::None(Task::none());
+                };
+
+                // The directory may not exist yet (if nothing was ever saved) and opening it would fail.
+                if let Err(e) = std::fs::create_dir_all(&config_dir) {
+                    log::warn!("Failed to create {}: {}", config_dir.display(), e);
+                }
+
+                Navigation::None(Task::perform(
+                    async move {
+                        GlobalMessage::Application(ApplicationMessage::OpenLink(
+                            config_dir.display().to_string(),
+                        ))
+                    },
+                    |m| m,
+                ))
+            }
+        }
+    }
+
+    fn view(&self, _theme: &iced::theme::Theme) -> iced::Element<'_, GlobalMessage> {
+        self.render().into()
+    }
+
+    fn subscription(&self) -> Subscription<GlobalMessage> {
+        Subscription::none()
+    }
+}
@@ -0,0 +1,93 @@
+use std::time::{Duration, Instant};
+
+/// How often the watchdog checks whether reconnect attempts are due.
+pub const WATCHDOG_INTERVAL: Duration = Duration::from_secs(1);
+
+// The first attempt is delayed a little, because losing a mount often coincides with the network changing.
+const INITIAL_DELAY: Duration = Duration::from_secs(5);
+
+const MAX_DELAY: Duration = Duration::from_secs(300);
+
+/// Reconnect tracks the automatic remounting of a "keep mounted" filesystem which got lost or broken
+/// (see `DefinitionSettings::keep_mounted`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reconnect {
+    // failures is the number of attempts that failed so far.
+    pub failures: u32,
+
+    // next_attempt_at is when the next attempt is due (see `backoff()`).
+    pub next_attempt_at: Instant,
+}
+
+impl Reconnect {
+    pub fn new(now: Instant) -> Self {
+        Self {
+            failures: 0,
+            next_attempt_at: now + backoff(0),
+        }
+    }
+
+    /// Returns the (1-based) number of the current (or upcoming) attempt.
+    pub fn attempt(&self) -> u32 {
+        self.failures + 1
+    }
+
+    pub fn is_due(&self, now: Instant) -> bool {
+        now >= self.next_attempt_at
+    }
+
+    /// Records a failed attempt and schedules the next one.
+    ///
+    /// Returns `false` if there should be no more attempts (`max_failures` of 0 means unlimited attempts).
+    pub fn record_failure(&mut self, now: Instant, max_failures: u32) -> bool {
+        self.failures += 1;
+        self.next_attempt_at = now + backoff(self.failures);
+
+        max_failures == 0 || self.failures < max_failures
+    }
+}
+
+/// Returns how long to wait before an attempt, given the number of attempts that failed before it.
+///
+/// The delay doubles with each failure (5s, 10s, 20s, ..), up to `MAX_DELAY`.
+pub fn backoff(failures: u32) -> Duration {
+    INITIAL_DELAY
+        .checked_mul(2_u32.saturating_pow(failures))
+        .map_or(MAX_DELAY, |delay| delay.min(MAX_DELAY))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::{Reconnect, backoff};
+
+    #[test]
+    fn test_backoff() {
+        assert_eq!(backoff(0), Duration::from_secs(5));
+        assert_eq!(backoff(1), Duration::from_secs(10));
+        assert_eq!(backoff(3), Duration::from_secs(40));
+        assert_eq!(backoff(6), Duration::from_secs(300));
+        assert_eq!(backoff(100), Duration::from_secs(300));
+    }
+
+    #[test]
+    fn test_record_failure() {
+        let now = Instant::now();
+
+        let mut reconnect = Reconnect::new(now);
+        assert!(!reconnect.is_due(now));
+        assert!(reconnect.is_due(now + Duration::from_secs(5)));
+
+        assert!(reconnect.record_failure(now, 2));
+        assert_eq!(reconnect.attempt(), 2);
+        assert_eq!(reconnect.next_attempt_at, now + Duration::from_secs(10));
+
+        assert!(!reconnect.record_failure(now, 2));
+
+        let mut unlimited = Reconnect::new(now);
+        for _ in 0..100 {
+            assert!(unlimited.record_failure(now, 0));
+        }
+    }
+}
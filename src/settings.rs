@@ -0,0 +1,383 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::strings;
+use crate::utils::sftpman_iced_config_dir;
+
+const SETTINGS_FILE_NAME: &str = "settings.json";
+
+pub const DEFAULT_MOUNT_TIMEOUT_SECONDS: u64 = 60;
+
+pub const DEFAULT_RECONNECT_MAX_FAILURES: u32 = 5;
+
+/// Settings holds our own (application-specific) configuration.
+///
+/// It's stored separately from the sftpman configuration (in `~/.config/sftpman-iced/settings.json`, see `sftpman_iced_config_dir()`),
+/// because libsftpman doesn't know about (and would not preserve) additional fields in definition files.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    // mount_timeout_seconds is how long we wait for mounting to complete before aborting it.
+    // This includes the time spent waiting for the user to enter a passphrase in an askpass dialog.
+    // A value of 0 disables the timeout.
+    pub mount_timeout_seconds: u64,
+
+    // parallel_batch_operations makes "Mount all" and "Unmount all" operate on all filesystems at once.
+    // This is off by default, because askpass dialogs for different filesystems would pile up.
+    pub parallel_batch_operations: bool,
+
+    // close_to_tray makes closing the window hide it instead of quitting, as long as a tray icon is shown.
+    pub close_to_tray: bool,
+
+    // desktop_notifications makes mount outcomes and lost filesystems also get reported via desktop notifications.
+    pub desktop_notifications: bool,
+
+    // autostart_exit_after_mounting makes `--autostart` quit once mounting is done, instead of staying minimized.
+    pub autostart_exit_after_mounting: bool,
+
+    // reconnect_max_failures is how many failed reconnect attempts it takes for us to give up
+    // on a "keep mounted" filesystem (see `DefinitionSettings::keep_mounted`).
+    // A value of 0 means that we never give up.
+    pub reconnect_max_failures: u32,
+
+    // unmount_before_sleep controls which filesystems get unmounted before the system goes to sleep
+    // (and mounted again after it resumes), because mounts that survive a suspend usually come back broken.
+    pub unmount_before_sleep: UnmountBeforeSleep,
+
+    // terminal_command is the terminal emulator to launch (with the command to run in it appended).
+    // An empty value means auto-detecting one (see `crate::terminal::terminal_command()`).
+    pub terminal_command: String,
+
+    // open_command is the command template for opening mount points (in a file manager) and links,
+    // with `{path}` standing for what's being opened (see `crate::opener`).
+    // An empty value means using `xdg-open`.
+    pub open_command: String,
+
+    // definitions holds per-definition settings, keyed by definition id.
+    pub definitions: BTreeMap<String, DefinitionSettings>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            mount_timeout_seconds: DEFAULT_MOUNT_TIMEOUT_SECONDS,
+            parallel_batch_operations: false,
+            close_to_tray: false,
+            desktop_notifications: false,
+            autostart_exit_after_mounting: false,
+            reconnect_max_failures: DEFAULT_RECONNECT_MAX_FAILURES,
+            unmount_before_sleep: UnmountBeforeSleep::default(),
+            terminal_command: String::new(),
+            open_command: String::new(),
+            definitions: BTreeMap::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DefinitionSettings {
+    // mount_timeout_seconds overrides `Settings::mount_timeout_seconds` for this definition.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mount_timeout_seconds: Option<u64>,
+
+    // mount_at_startup makes this definition get mounted when we're started with `--autostart`.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub mount_at_startup: bool,
+
+    // keep_mounted makes this definition get mounted again (see `crate::reconnect`),
+    // whenever it gets unmounted or broken without us unmounting it (e.g. due to a lost connection).
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub keep_mounted: bool,
+
+    // unmount_before_sleep marks this definition for unmounting before the system goes to sleep,
+    // when `Settings::unmount_before_sleep` is `UnmountBeforeSleep::Marked`.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub unmount_before_sleep: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UnmountBeforeSleep {
+    #[default]
+    Never,
+    All,
+
+    // Marked only applies to definitions with `DefinitionSettings::unmount_before_sleep` enabled.
+    Marked,
+}
+
+#[cfg(feature = "logind")]
+impl UnmountBeforeSleep {
+    pub const ALL: [UnmountBeforeSleep; 3] = [Self::Never, Self::All, Self::Marked];
+}
+
+impl fmt::Display for UnmountBeforeSleep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            Self::Never => strings::unmount_before_sleep_never_label(),
+            Self::All => strings::unmount_before_sleep_all_label(),
+            Self::Marked => strings::unmount_before_sleep_marked_label(),
+        };
+
+        write!(f, "{}", label)
+    }
+}
+
+impl Settings {
+    /// Loads the settings from disk, falling back to the defaults if they can't be loaded.
+    pub fn load() -> Self {
+        let Some(path) = settings_path() else {
+            log::warn!("Could not determine the settings path. Using default settings");
+            return Self::default();
+        };
+
+        if !path.exists() {
+            return Self::default();
+        }
+
+        let result = fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|contents| serde_json::from_str(&contents).map_err(|e| e.to_string()));
+
+        match result {
+            Ok(settings) => settings,
+            Err(e) => {
+                log::error!(
+                    "Failed to load settings from {}: {}. Using default settings",
+                    path.display(),
+                    e
+                );
+                Self::default()
+            }
+        }
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let Some(path) = settings_path() else {
+            return Err("Could not determine the settings path. Is $HOME set?".to_owned());
+        };
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+        }
+
+        let contents = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+
+        fs::write(&path, contents).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    pub fn definition(&self, id: &str) -> DefinitionSettings {
+        self.definitions.get(id).cloned().unwrap_or_default()
+    }
+
+    pub fn set_definition(&mut self, id: &str, definition_settings: DefinitionSettings) {
+        // We don't store defaults, to keep the settings file small and readable.
+        if definition_settings == DefinitionSettings::default() {
+            self.definitions.remove(id);
+        } else {
+            self.definitions.insert(id.to_owned(), definition_settings);
+        }
+    }
+
+    pub fn remove_definition(&mut self, id: &str) {
+        self.definitions.remove(id);
+    }
+
+    /// Returns the ids of the definitions which should be mounted when we're started with `--autostart`.
+    pub fn mount_at_startup_ids(&self) -> Vec<String> {
+        self.definitions
+            .iter()
+            .filter(|(_, definition_settings)| definition_settings.mount_at_startup)
+            .map(|(id, _)| id.clone())
+            .collect()
+    }
+
+    /// Tells whether the given definition should be kept mounted (see `DefinitionSettings::keep_mounted`).
+    pub fn keep_mounted(&self, id: &str) -> bool {
+        self.definitions
+            .get(id)
+            .is_some_and(|definition_settings| definition_settings.keep_mounted)
+    }
+
+    /// Tells whether the given definition should be unmounted before the system goes to sleep (see `UnmountBeforeSleep`).
+    #[cfg(feature = "logind")]
+    pub fn unmount_before_sleep(&self, id: &str) -> bool {
+        match self.unmount_before_sleep {
+            UnmountBeforeSleep::Never => false,
+            UnmountBeforeSleep::All => true,
+            UnmountBeforeSleep::Marked => self.definition(id).unmount_before_sleep,
+        }
+    }
+
+    /// Returns the mount timeout for the given definition (`None` means no timeout).
+    pub fn mount_timeout(&self, id: &str) -> Option<Duration> {
+        let seconds = self
+            .definition(id)
+            .mount_timeout_seconds
+            .unwrap_or(self.mount_timeout_seconds);
+
+        (seconds > 0).then(|| Duration::from_secs(seconds))
+    }
+}
+
+fn settings_path() -> Option<PathBuf> {
+    sftpman_iced_config_dir().map(|dir| dir.join(SETTINGS_FILE_NAME))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{DEFAULT_MOUNT_TIMEOUT_SECONDS, DefinitionSettings, Settings, UnmountBeforeSleep};
+
+    #[test]
+    fn test_mount_timeout() {
+        let mut settings = Settings::default();
+
+        assert_eq!(
+            settings.mount_timeout("any"),
+            Some(Duration::from_secs(DEFAULT_MOUNT_TIMEOUT_SECONDS))
+        );
+
+        settings.set_definition(
+            "slow",
+            DefinitionSettings {
+                mount_timeout_seconds: Some(300),
+                ..DefinitionSettings::default()
+            },
+        );
+        settings.set_definition(
+            "patient",
+            DefinitionSettings {
+                mount_timeout_seconds: Some(0),
+                ..DefinitionSettings::default()
+            },
+        );
+
+        assert_eq!(
+            settings.mount_timeout("slow"),
+            Some(Duration::from_secs(300))
+        );
+        assert_eq!(settings.mount_timeout("patient"), None);
+
+        settings.mount_timeout_seconds = 0;
+        assert_eq!(settings.mount_timeout("any"), None);
+    }
+
+    #[test]
+    fn test_defaults_are_not_stored_and_missing_fields_are_defaulted() {
+        let mut settings = Settings::default();
+
+        settings.set_definition(
+            "one",
+            DefinitionSettings {
+                mount_timeout_seconds: Some(5),
+                ..DefinitionSettings::default()
+            },
+        );
+        settings.set_definition("one", DefinitionSettings::default());
+
+        assert!(settings.definitions.is_empty());
+
+        let parsed: Settings = serde_json::from_str("{}").unwrap();
+        assert_eq!(parsed, Settings::default());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut settings = Settings {
+            reconnect_max_failures: 0,
+            unmount_before_sleep: UnmountBeforeSleep::Marked,
+            ..Settings::default()
+        };
+
+        settings.set_definition(
+            "one",
+            DefinitionSettings {
+                mount_timeout_seconds: Some(5),
+                mount_at_startup: true,
+                keep_mounted: true,
+                unmount_before_sleep: true,
+            },
+        );
+        settings.set_definition(
+            "two",
+            DefinitionSettings {
+                mount_at_startup: true,
+                ..DefinitionSettings::default()
+            },
+        );
+
+        let serialized = serde_json::to_string(&settings).unwrap();
+        let parsed: Settings = serde_json::from_str(&serialized).unwrap();
+
+        assert_eq!(parsed, settings);
+    }
+
+    #[test]
+    fn test_mount_at_startup_ids() {
+        let mut settings = Settings::default();
+
+        settings.set_definition(
+            "one",
+            DefinitionSettings {
+                mount_at_startup: true,
+                ..DefinitionSettings::default()
+            },
+        );
+        settings.set_definition(
+            "two",
+            DefinitionSettings {
+                mount_timeout_seconds: Some(5),
+                ..DefinitionSettings::default()
+            },
+        );
+
+        assert_eq!(settings.mount_at_startup_ids(), vec!["one"]);
+    }
+
+    #[test]
+    fn test_keep_mounted() {
+        let mut settings = Settings::default();
+
+        settings.set_definition(
+            "one",
+            DefinitionSettings {
+                keep_mounted: true,
+                ..DefinitionSettings::default()
+            },
+        );
+
+        assert!(settings.keep_mounted("one"));
+        assert!(!settings.keep_mounted("two"));
+    }
+
+    #[test]
+    #[cfg(feature = "logind")]
+    fn test_unmount_before_sleep() {
+        let mut settings = Settings::default();
+
+        settings.set_definition(
+            "one",
+            DefinitionSettings {
+                unmount_before_sleep: true,
+                ..DefinitionSettings::default()
+            },
+        );
+
+        assert!(!settings.unmount_before_sleep("one"));
+
+        settings.unmount_before_sleep = UnmountBeforeSleep::Marked;
+        assert!(settings.unmount_before_sleep("one"));
+        assert!(!settings.unmount_before_sleep("two"));
+
+        settings.unmount_before_sleep = UnmountBeforeSleep::All;
+        assert!(settings.unmount_before_sleep("two"));
+    }
+}
@@ -0,0 +1,366 @@
+use std::io::Write;
+use std::os::unix::process::CommandExt;
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use libsftpman::{AuthType, FilesystemMountDefinition};
+
+use crate::mount::{output_file, read_output_file, terminate};
+use crate::strings;
+
+// Like libsftpman (when mounting), we give up on connecting after this many seconds.
+const SSH_CONNECT_TIMEOUT_SECONDS: u64 = 10;
+
+// How often we check whether sftp finished or timed out.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+// How long listing a directory (see `list_directories()`) may take, including connecting.
+const LIST_TIMEOUT: Duration = Duration::from_secs(20);
+
+// sftp echoes each command it runs in batch mode, prefixed with this.
+const ECHO_PREFIX: &str = "sftp> ";
+
+/// sftp prints this (followed by the path) in response to `pwd`.
+pub const PWD_RESPONSE_PREFIX: &str = "Remote working directory:";
+
+/// BatchOutput is what running `sftp` in batch mode (see `run_batch()`) produced.
+#[derive(Debug, Clone, Default)]
+pub struct BatchOutput {
+    // success tells whether sftp connected and ran all commands successfully.
+    pub success: bool,
+
+    // timed_out tells whether sftp had to be terminated, because it took too long.
+    pub timed_out: bool,
+
+    pub stdout: String,
+    pub stderr: String,
+}
+
+/// Runs the given `sftp` commands non-interactively, connecting the way libsftpman's `sshfs` invocation does
+/// (the same host, port, user and authentication type), and terminating `sftp` if it takes longer than `timeout`.
+///
+/// sftp stops at the first failing command (see "-b" in sftp(1)).
+/// `ssh_options` are passed to ssh as-is (e.g. `["-o", "StrictHostKeyChecking=accept-new"]`).
+/// An error is only returned if `sftp` could not be run at all.
+pub fn run_batch(
+    definition: &FilesystemMountDefinition,
+    commands: &[String],
+    ssh_options: &[String],
+    timeout: Duration,
+) -> Result<BatchOutput, String> {
+    let mut cmd = sftp_command(definition, ssh_options);
+
+    log::debug!("Running {:?} with commands: {:?}", cmd, commands);
+
+    // Like when mounting, the output goes to files, because ssh may outlive sftp (if terminated) and hold on to pipes.
+    let (stdout, stderr) = match (output_file(), output_file()) {
+        (Ok(stdout), Ok(stderr)) => (stdout, stderr),
+        (Err(err), _) | (_, Err(err)) => return Err(err.to_string()),
+    };
+
+    let (stdout_clone, stderr_clone) = match (stdout.try_clone(), stderr.try_clone()) {
+        (Ok(stdout_clone), Ok(stderr_clone)) => (stdout_clone, stderr_clone),
+        (Err(err), _) | (_, Err(err)) => return Err(err.to_string()),
+    };
+
+    let mut child = cmd
+        .stdin(Stdio::piped())
+        .stdout(stdout_clone)
+        .stderr(stderr_clone)
+        .process_group(0)
+        .spawn()
+        .map_err(|err| format!("sftp: {}", err))?;
+
+    // The batch is small, so writing it all before sftp starts reading doesn't block.
+    // Dropping stdin afterwards lets sftp know there are no more commands.
+    if let Some(mut stdin) = child.stdin.take() {
+        let batch = commands
+            .iter()
+            .map(|command| format!("{}\n", command))
+            .collect::<String>();
+
+        if let Err(err) = stdin.write_all(batch.as_bytes()) {
+            log::debug!("Failed to pass commands to sftp: {}", err);
+        }
+    }
+
+    let started_at = Instant::now();
+
+    let (success, timed_out) = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break (status.success(), false),
+            Ok(None) => {}
+            Err(err) => {
+                terminate(&mut child);
+                return Err(format!("sftp: {}", err));
+            }
+        }
+
+        if started_at.elapsed() >= timeout {
+            terminate(&mut child);
+            break (false, true);
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    };
+
+    Ok(BatchOutput {
+        success,
+        timed_out,
+        stdout: String::from_utf8_lossy(&read_output_file(stdout)).to_string(),
+        stderr: String::from_utf8_lossy(&read_output_file(stderr)).to_string(),
+    })
+}
+
+/// Lists the subdirectories of the given remote directory, sorted by name.
+///
+/// Hidden directories are left out. Symbolic links are included, as they may point to directories.
+/// This is a blocking call (each listing makes a new connection).
+pub fn list_directories(
+    definition: &FilesystemMountDefinition,
+    path: &str,
+) -> Result<Vec<String>, String> {
+    // Listing the current directory makes sftp print plain names (instead of paths).
+    let commands = [format!("cd {}", quote(path)?), "ls -l".to_owned()];
+
+    let output = run_batch(definition, &commands, &[], LIST_TIMEOUT)?;
+
+    if !output.success {
+        return Err(failure_message(&output, LIST_TIMEOUT));
+    }
+
+    let mut names = output
+        .stdout
+        .lines()
+        .filter(|line| !line.starts_with(ECHO_PREFIX))
+        .filter_map(parse_directory_entry)
+        .filter(|name| !name.starts_with('.'))
+        .collect::<Vec<_>>();
+
+    names.sort();
+
+    Ok(names)
+}
+
+/// Returns the directory sftp sessions start in (the user's home directory, usually).
+///
+/// This is a blocking call, like `list_directories()`.
+pub fn home_directory(definition: &FilesystemMountDefinition) -> Result<String, String> {
+    let output = run_batch(definition, &["pwd".to_owned()], &[], LIST_TIMEOUT)?;
+
+    if !output.success {
+        return Err(failure_message(&output, LIST_TIMEOUT));
+    }
+
+    output
+        .stdout
+        .lines()
+        .find_map(|line| line.strip_prefix(PWD_RESPONSE_PREFIX))
+        .map(|path| path.trim().to_owned())
+        .filter(|path| !path.is_empty())
+        .ok_or_else(strings::sftp_unknown_error)
+}
+
+// Returns the name from an `ls -l` line, if it's a directory (or a symbolic link).
+//
+// Lines look like `drwxr-xr-x    2 user     group        4096 Jan  1 00:00 name`,
+// so the name is what follows the 8th field (and may contain spaces).
+fn parse_directory_entry(line: &str) -> Option<String> {
+    if !line.starts_with(['d', 'l']) {
+        return None;
+    }
+
+    let mut rest = line;
+    for _ in 0..8 {
+        rest = rest.trim_start();
+        rest = &rest[rest.find(char::is_whitespace)?..];
+    }
+
+    // Some servers print paths instead of names. Names can't contain slashes, so we take the last component.
+    let name = rest.trim_start().rsplit('/').next()?;
+
+    (!name.is_empty()).then(|| name.to_owned())
+}
+
+/// Joins a remote directory path and the name of an entry in it.
+pub fn join(path: &str, name: &str) -> String {
+    if path.ends_with('/') {
+        format!("{}{}", path, name)
+    } else {
+        format!("{}/{}", path, name)
+    }
+}
+
+/// Returns the parent of the given (absolute) remote directory path, unless it's the root directory.
+pub fn parent(path: &str) -> Option<String> {
+    let path = path.trim_end_matches('/');
+
+    match path.rsplit_once('/')? {
+        ("", "") => None,
+        ("", _) => Some("/".to_owned()),
+        (parent, _) => Some(parent.to_owned()),
+    }
+}
+
+/// Quotes a path for use as an argument in sftp commands.
+///
+/// Glob characters are escaped too, as sftp expands them even in quoted arguments.
+/// Line breaks can't be escaped and are rejected, because each line of a batch is a command of its own
+/// (and lines starting with `!` get run by a local shell).
+pub fn quote(path: &str) -> Result<String, String> {
+    if path.contains(['\n', '\r']) {
+        return Err(strings::sftp_path_line_break_error());
+    }
+
+    let mut quoted = String::from("\"");
+
+    for c in path.chars() {
+        if "\\\"*?[".contains(c) {
+            quoted.push('\\');
+        }
+
+        quoted.push(c);
+    }
+
+    quoted.push('"');
+
+    Ok(quoted)
+}
+
+/// Describes why a batch (see `run_batch()`) failed, given the timeout it ran with.
+pub fn failure_message(output: &BatchOutput, timeout: Duration) -> String {
+    if output.timed_out {
+        return strings::sftp_timed_out_error(timeout.as_secs());
+    }
+
+    let message = error_message(output);
+
+    if message.is_empty() {
+        strings::sftp_unknown_error()
+    } else {
+        message
+    }
+}
+
+// Returns the error messages sftp (and ssh) printed, on a single line.
+//
+// sftp's own "Connected to .." notice is left out, as it's not an error.
+fn error_message(output: &BatchOutput) -> String {
+    output
+        .stderr
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty() && !line.starts_with("Connected to "))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn sftp_command(definition: &FilesystemMountDefinition, ssh_options: &[String]) -> Command {
+    let mut cmd = Command::new("sftp");
+
+    cmd.arg("-b")
+        .arg("-")
+        .arg("-P")
+        .arg(definition.port.to_string())
+        .arg("-o")
+        .arg("BatchMode=yes")
+        .arg("-o")
+        .arg(format!("ConnectTimeout={}", SSH_CONNECT_TIMEOUT_SECONDS));
+
+    // This mirrors `FilesystemMountDefinition::mount_commands()`.
+    match &definition.auth_type {
+        AuthType::PublicKey => {
+            cmd.arg("-o")
+                .arg(format!(
+                    "PreferredAuthentications={}",
+                    AuthType::PublicKey.to_static_str()
+                ))
+                .arg("-i")
+                .arg(&definition.ssh_key);
+        }
+        AuthType::AuthenticationAgent => {}
+        any_other => {
+            cmd.arg("-o").arg(format!(
+                "PreferredAuthentications={}",
+                any_other.to_static_str()
+            ));
+        }
+    }
+
+    cmd.args(ssh_options);
+
+    // Like libsftpman, we wrap the host in `[]` (for IPv6 addresses).
+    // sftp only recognizes this syntax when followed by a path, so we pass the home directory (`.`),
+    // which is where the session starts anyway.
+    cmd.arg(format!("{}@[{}]:.", definition.user, definition.host));
+
+    cmd
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BatchOutput, error_message, join, parent, parse_directory_entry, quote};
+
+    #[test]
+    fn test_quote() {
+        assert_eq!(quote("/srv/data").unwrap(), "\"/srv/data\"");
+        assert_eq!(
+            quote("/srv/my \"files\"").unwrap(),
+            "\"/srv/my \\\"files\\\"\""
+        );
+        assert_eq!(quote("/srv/*.d").unwrap(), "\"/srv/\\*.d\"");
+        assert!(quote("/srv\n!touch /tmp/pwned").is_err());
+        assert!(quote("/srv\r").is_err());
+    }
+
+    #[test]
+    fn test_parse_directory_entry() {
+        assert_eq!(
+            parse_directory_entry(
+                "drwxr-xr-x    2 user     group        4096 Jan  1 00:00 my  files"
+            ),
+            Some("my  files".to_owned())
+        );
+        assert_eq!(
+            parse_directory_entry("lrwxrwxrwx    1 0        0               7 Mar  4  2024 /bin"),
+            Some("bin".to_owned())
+        );
+        assert_eq!(
+            parse_directory_entry(
+                "-rw-r--r--    1 user     group         220 Jan  1 00:00 .profile"
+            ),
+            None
+        );
+        assert_eq!(parse_directory_entry("drwxr-xr-x 2 user"), None);
+    }
+
+    #[test]
+    fn test_join() {
+        assert_eq!(join("/", "srv"), "/srv");
+        assert_eq!(join("/srv", "data"), "/srv/data");
+    }
+
+    #[test]
+    fn test_parent() {
+        assert_eq!(parent("/srv/data"), Some("/srv".to_owned()));
+        assert_eq!(parent("/srv/data/"), Some("/srv".to_owned()));
+        assert_eq!(parent("/srv"), Some("/".to_owned()));
+        assert_eq!(parent("/"), None);
+    }
+
+    #[test]
+    fn test_error_message() {
+        let output = BatchOutput {
+            stderr:
+                "Connected to example.com.\nCan't change directory: \"/srv\" is not a directory\n"
+                    .to_owned(),
+            ..BatchOutput::default()
+        };
+
+        assert_eq!(
+            error_message(&output),
+            "Can't change directory: \"/srv\" is not a directory"
+        );
+    }
+}
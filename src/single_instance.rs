@@ -0,0 +1,217 @@
+use std::io::{self, BufRead, BufReader, Write};
+use std::os::unix::ffi::OsStrExt;
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+use iced::Subscription;
+use iced::futures::Stream;
+use iced::futures::channel::mpsc;
+use iced::stream;
+
+use crate::utils::sftpman_config_dir;
+
+// How long a second instance waits for the running one to acknowledge its arguments.
+const FORWARD_TIMEOUT_MS: u64 = 3000;
+
+const ACKNOWLEDGEMENT: &str = "ok";
+
+// LISTENER holds the socket we're listening on (if we're the primary instance),
+// until the subscription (see `forwarded_invocations()`) takes it over.
+static LISTENER: Mutex<Option<UnixListener>> = Mutex::new(None);
+
+pub enum Instance {
+    // Primary means this is the only running instance. It will receive invocations from others.
+    Primary,
+
+    // Secondary means that another instance is already running and our arguments were forwarded to it.
+    Secondary,
+}
+
+/// Makes this the primary instance, or forwards the given arguments to the instance which already is.
+///
+/// Instances using different configuration directories (see `--config-dir`) don't interfere with one another.
+pub fn acquire(args: &[String]) -> io::Result<Instance> {
+    let path = socket_path();
+
+    match forward(&path, args) {
+        Ok(()) => return Ok(Instance::Secondary),
+        Err(e) => log::debug!("No running instance found at {}: {}", path.display(), e),
+    }
+
+    // Nobody is listening, so the socket file (if any) was left behind by an instance which didn't exit cleanly.
+    let _ = std::fs::remove_file(&path);
+
+    let listener = match UnixListener::bind(&path) {
+        Ok(listener) => listener,
+        Err(e) if e.kind() == io::ErrorKind::AddrInUse => {
+            // Another instance started at the same time as us and won.
+            forward(&path, args)?;
+            return Ok(Instance::Secondary);
+        }
+        Err(e) => return Err(e),
+    };
+
+    log::debug!("Listening for other instances on {}", path.display());
+
+    *LISTENER.lock().unwrap() = Some(listener);
+
+    Ok(Instance::Primary)
+}
+
+fn forward(path: &PathBuf, args: &[String]) -> io::Result<()> {
+    let mut stream = UnixStream::connect(path)?;
+
+    stream.set_read_timeout(Some(Duration::from_millis(FORWARD_TIMEOUT_MS)))?;
+    stream.set_write_timeout(Some(Duration::from_millis(FORWARD_TIMEOUT_MS)))?;
+
+    let payload = serde_json::to_string(args).map_err(io::Error::other)?;
+    writeln!(stream, "{}", payload)?;
+
+    let mut response = String::new();
+    BufReader::new(stream).read_line(&mut response)?;
+
+    if response.trim() != ACKNOWLEDGEMENT {
+        return Err(io::Error::other(format!(
+            "Unexpected response from the running instance: {:?}",
+            response
+        )));
+    }
+
+    Ok(())
+}
+
+fn socket_path() -> PathBuf {
+    // The configuration directory is part of the name, so that `--config-dir` gives a separate instance.
+    let name = format!(
+        "{}-{:016x}.sock",
+        env!("CARGO_PKG_NAME"),
+        config_dir_hash(sftpman_config_dir().as_deref())
+    );
+
+    match std::env::var_os("XDG_RUNTIME_DIR") {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir).join(name),
+        // The temporary directory is shared with other users, so the name needs to be unique per user.
+        _ => std::env::temp_dir().join(format!("{}-{}", nix::unistd::getuid(), name)),
+    }
+}
+
+// Hashes the configuration directory (with FNV-1a), for naming the socket.
+//
+// The hash needs to be the same for all builds (which may find each other's sockets),
+// so we can't use `DefaultHasher`, whose algorithm may change between Rust releases.
+// The path itself may be too long for a socket name, so we don't use it as is either.
+fn config_dir_hash(dir: Option<&Path>) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x100000001b3;
+
+    dir.map(|dir| dir.as_os_str().as_bytes())
+        .unwrap_or_default()
+        .iter()
+        .fold(OFFSET_BASIS, |hash, byte| {
+            (hash ^ u64::from(*byte)).wrapping_mul(PRIME)
+        })
+}
+
+/// Returns a subscription which produces the command-line arguments of each instance started after us.
+///
+/// Nothing is produced if we're not the primary instance (see `acquire()`).
+pub fn forwarded_invocations() -> Subscription<Vec<String>> {
+    Subscription::run(listen)
+}
+
+fn listen() -> impl Stream<Item = Vec<String>> {
+    stream::channel(16, async |output: mpsc::Sender<Vec<String>>| {
+        if let Some(listener) = LISTENER.lock().unwrap().take() {
+            // Accepting is blocking, so it happens on a separate thread which lives as long as the subscription.
+            thread::spawn(move || listen_blocking(listener, output));
+        }
+
+        std::future::pending::<()>().await
+    })
+}
+
+fn listen_blocking(listener: UnixListener, mut output: mpsc::Sender<Vec<String>>) {
+    for stream in listener.incoming() {
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(e) => {
+                log::warn!("Failed to accept a connection from another instance: {}", e);
+                continue;
+            }
+        };
+
+        let args = match receive(stream) {
+            Ok(args) => args,
+            Err(e) => {
+                log::warn!("Failed to receive arguments from another instance: {}", e);
+                continue;
+            }
+        };
+
+        log::info!("Received an invocation from another instance: {:?}", args);
+
+        if let Err(e) = output.try_send(args) {
+            if e.is_disconnected() {
+                return;
+            }
+
+            log::warn!("Dropping an invocation from another instance: {}", e);
+        }
+    }
+}
+
+fn receive(stream: UnixStream) -> io::Result<Vec<String>> {
+    stream.set_read_timeout(Some(Duration::from_millis(FORWARD_TIMEOUT_MS)))?;
+
+    let mut reader = BufReader::new(stream);
+
+    let mut payload = String::new();
+    reader.read_line(&mut payload)?;
+
+    let args = serde_json::from_str(&payload).map_err(io::Error::other)?;
+
+    writeln!(reader.get_mut(), "{}", ACKNOWLEDGEMENT)?;
+
+    Ok(args)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::os::unix::net::UnixListener;
+    use std::path::Path;
+    use std::thread;
+
+    use super::{config_dir_hash, forward, receive};
+
+    #[test]
+    fn test_config_dir_hash() {
+        assert_eq!(config_dir_hash(None), 0xcbf29ce484222325);
+        assert_eq!(config_dir_hash(Some(Path::new("a"))), 0xaf63dc4c8601ec8c);
+        assert_ne!(
+            config_dir_hash(Some(Path::new("/home/user/.config/sftpman"))),
+            config_dir_hash(Some(Path::new("/tmp/sftpman")))
+        );
+    }
+
+    #[test]
+    fn test_forward_round_trip() {
+        let path = std::env::temp_dir().join(format!(
+            "sftpman-iced-single-instance-test-{}.sock",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+
+        let listener = UnixListener::bind(&path).unwrap();
+        let handle = thread::spawn(move || receive(listener.accept().unwrap().0).unwrap());
+
+        let args = vec!["--mount".to_owned(), "one".to_owned()];
+        forward(&path, &args).unwrap();
+
+        assert_eq!(handle.join().unwrap(), args);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
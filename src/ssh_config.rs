@@ -0,0 +1,473 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use libsftpman::{AuthType, FilesystemMountDefinition};
+
+// How deeply `Include` directives may nest, which guards against include loops.
+const MAX_INCLUDE_DEPTH: usize = 16;
+
+/// SshHost is a concrete (non-wildcard) host alias from `~/.ssh/config`, with the options that apply to it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SshHost {
+    pub alias: String,
+    pub host_name: Option<String>,
+    pub user: Option<String>,
+    pub port: Option<u16>,
+    pub identity_file: Option<String>,
+}
+
+impl SshHost {
+    /// Converts the host into a new filesystem definition (mounting the server's root directory).
+    ///
+    /// Options which are not set fall back to what `ssh` would use (the alias as the host name, the local user, port 22).
+    pub fn to_definition(&self) -> FilesystemMountDefinition {
+        let identity_file = self.identity_file.clone().unwrap_or_default();
+
+        FilesystemMountDefinition {
+            id: definition_id(&self.alias),
+            host: self.host_name.clone().unwrap_or_else(|| self.alias.clone()),
+            port: self.port.unwrap_or(22),
+            user: self.user.clone().unwrap_or_else(local_user),
+            remote_path: "/".to_owned(),
+            // Without an explicit key, we leave it to ssh (and its agent) to pick one.
+            auth_type: if identity_file.is_empty() {
+                AuthType::AuthenticationAgent
+            } else {
+                AuthType::PublicKey
+            },
+            ssh_key: identity_file,
+            ..FilesystemMountDefinition::default()
+        }
+    }
+}
+
+// Block is a `Host` section (or the options before the first one, which apply to all hosts).
+#[derive(Debug, Default)]
+struct Block {
+    patterns: Vec<String>,
+    options: Vec<(String, String)>,
+
+    // is_match_block marks `Match` sections, which we can't evaluate, so they're never applied.
+    is_match_block: bool,
+}
+
+impl Block {
+    fn applies_to(&self, alias: &str) -> bool {
+        if self.is_match_block {
+            return false;
+        }
+
+        let mut matched = false;
+
+        for pattern in &self.patterns {
+            if let Some(negated) = pattern.strip_prefix('!') {
+                if wildcard_match(negated, alias) {
+                    return false;
+                }
+            } else if wildcard_match(pattern, alias) {
+                matched = true;
+            }
+        }
+
+        matched
+    }
+}
+
+/// Returns the hosts defined in the user's `~/.ssh/config` (following `Include` directives),
+/// skipping wildcard patterns (e.g. `Host *` or `Host *.example.com`), which don't name a specific host.
+pub fn load() -> Result<Vec<SshHost>, String> {
+    let ssh_dir = home_dir()
+        .map(|home| home.join(".ssh"))
+        .ok_or_else(|| "Could not determine the home directory. Is $HOME set?".to_owned())?;
+
+    let path = ssh_dir.join("config");
+
+    let contents = fs::read_to_string(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+
+    let mut blocks = vec![global_block()];
+    parse(&contents, &ssh_dir, 0, &mut blocks);
+
+    Ok(resolve(&blocks))
+}
+
+fn global_block() -> Block {
+    Block {
+        patterns: vec!["*".to_owned()],
+        ..Block::default()
+    }
+}
+
+// Parses the config contents into `blocks`, continuing the last block (like ssh does for included files).
+fn parse(contents: &str, ssh_dir: &Path, depth: usize, blocks: &mut Vec<Block>) {
+    for line in contents.lines() {
+        let Some((keyword, value)) = split_line(line) else {
+            continue;
+        };
+
+        match keyword.as_str() {
+            "host" => blocks.push(Block {
+                patterns: value.split_whitespace().map(|p| p.to_owned()).collect(),
+                ..Block::default()
+            }),
+            "match" => blocks.push(Block {
+                is_match_block: true,
+                ..Block::default()
+            }),
+            "include" => {
+                if depth >= MAX_INCLUDE_DEPTH {
+                    log::warn!(
+                        "Not following SSH config Include (too deeply nested): {}",
+                        value
+                    );
+                    continue;
+                }
+
+                for pattern in value.split_whitespace() {
+                    for path in expand_include(pattern, ssh_dir) {
+                        match fs::read_to_string(&path) {
+                            Ok(contents) => parse(&contents, ssh_dir, depth + 1, blocks),
+                            Err(e) => log::warn!("Failed to read {}: {}", path.display(), e),
+                        }
+                    }
+                }
+            }
+            _ => {
+                if let Some(block) = blocks.last_mut() {
+                    block.options.push((keyword, unquote(&value).to_owned()));
+                }
+            }
+        }
+    }
+}
+
+// Splits a config line into its (lowercased) keyword and value.
+// Both `Keyword value` and `Keyword=value` are valid. Comments and blank lines yield `None`.
+fn split_line(line: &str) -> Option<(String, String)> {
+    let line = line.trim();
+
+    if line.is_empty() || line.starts_with('#') {
+        return None;
+    }
+
+    let (keyword, value) = line.split_once(|c: char| c.is_whitespace() || c == '=')?;
+
+    let value = value.trim_start();
+    let value = value.strip_prefix('=').unwrap_or(value).trim();
+
+    Some((keyword.to_lowercase(), value.to_owned()))
+}
+
+fn unquote(value: &str) -> &str {
+    value
+        .strip_prefix('"')
+        .and_then(|v| v.strip_suffix('"'))
+        .unwrap_or(value)
+}
+
+// Resolves the options of each concrete host alias.
+// Like ssh, the first value found (in file order) for each option wins.
+fn resolve(blocks: &[Block]) -> Vec<SshHost> {
+    let mut aliases: Vec<&str> = vec![];
+
+    for block in blocks.iter().filter(|block| !block.is_match_block) {
+        for pattern in &block.patterns {
+            let is_concrete = !pattern.starts_with('!') && !has_wildcard(pattern);
+
+            if is_concrete && !aliases.contains(&pattern.as_str()) {
+                aliases.push(pattern);
+            }
+        }
+    }
+
+    aliases
+        .into_iter()
+        .map(|alias| {
+            let option = |keyword: &str| {
+                blocks
+                    .iter()
+                    .filter(|block| block.applies_to(alias))
+                    .flat_map(|block| block.options.iter())
+                    .find(|(key, _)| key == keyword)
+                    .map(|(_, value)| value.clone())
+            };
+
+            let host_name = option("hostname").map(|v| expand_tokens(&v, alias, None));
+            let user = option("user");
+            let port = option("port").and_then(|v| v.parse::<u16>().ok());
+            let identity_file = option("identityfile")
+                .map(|v| expand_tokens(&v, host_name.as_deref().unwrap_or(alias), user.as_deref()));
+
+            SshHost {
+                alias: alias.to_owned(),
+                host_name,
+                user,
+                port,
+                identity_file,
+            }
+        })
+        .collect()
+}
+
+// Expands `~` and the most common tokens (see TOKENS in ssh_config(5)) in a value.
+fn expand_tokens(value: &str, host: &str, remote_user: Option<&str>) -> String {
+    let home = home_dir()
+        .map(|home| home.display().to_string())
+        .unwrap_or_default();
+
+    let value = match value.strip_prefix("~/") {
+        Some(rest) => format!("{}/{}", home, rest),
+        None => value.to_owned(),
+    };
+
+    let mut expanded = String::with_capacity(value.len());
+    let mut chars = value.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            expanded.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('%') => expanded.push('%'),
+            Some('d') => expanded.push_str(&home),
+            Some('h') => expanded.push_str(host),
+            Some('r') => expanded.push_str(&remote_user.map_or_else(local_user, |u| u.to_owned())),
+            Some('u') => expanded.push_str(&local_user()),
+            Some(other) => {
+                expanded.push('%');
+                expanded.push(other);
+            }
+            None => expanded.push('%'),
+        }
+    }
+
+    expanded
+}
+
+// Returns the files an `Include` pattern refers to, in lexical order.
+// Relative paths are relative to `~/.ssh`. Wildcards are supported in the file name (e.g. `config.d/*`).
+fn expand_include(pattern: &str, ssh_dir: &Path) -> Vec<PathBuf> {
+    let pattern = match pattern.strip_prefix("~/") {
+        Some(rest) => home_dir().unwrap_or_default().join(rest),
+        None => ssh_dir.join(pattern),
+    };
+
+    let Some(file_pattern) = pattern.file_name().and_then(|name| name.to_str()) else {
+        return vec![];
+    };
+
+    if !has_wildcard(file_pattern) {
+        return if pattern.is_file() {
+            vec![pattern]
+        } else {
+            vec![]
+        };
+    }
+
+    let Some(dir) = pattern.parent() else {
+        return vec![];
+    };
+
+    let Ok(entries) = fs::read_dir(dir) else {
+        return vec![];
+    };
+
+    let mut paths = entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| {
+            entry.file_name().to_str().is_some_and(|name| {
+                // Like shell globs, wildcards don't match hidden files.
+                !name.starts_with('.') && wildcard_match(file_pattern, name)
+            })
+        })
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file())
+        .collect::<Vec<_>>();
+
+    paths.sort();
+
+    paths
+}
+
+fn has_wildcard(pattern: &str) -> bool {
+    pattern.contains(['*', '?'])
+}
+
+// Matches text against a pattern, where `*` matches any number of characters and `?` matches one.
+fn wildcard_match(pattern: &str, text: &str) -> bool {
+    let pattern = pattern.chars().collect::<Vec<_>>();
+    let text = text.chars().collect::<Vec<_>>();
+
+    let (mut p, mut t) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, t));
+            p += 1;
+        } else if let Some((star_p, star_t)) = backtrack {
+            p = star_p + 1;
+            t = star_t + 1;
+            backtrack = Some((star_p, star_t + 1));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+// Turns a host alias into a valid definition id (alphanumeric characters, underscores, dashes and dots).
+fn definition_id(alias: &str) -> String {
+    alias
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || "_-.".contains(c) {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect()
+}
+
+fn home_dir() -> Option<PathBuf> {
+    directories::BaseDirs::new().map(|dirs| dirs.home_dir().to_path_buf())
+}
+
+fn local_user() -> String {
+    env::var("USER").unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use libsftpman::AuthType;
+
+    use super::{SshHost, global_block, parse, resolve, wildcard_match};
+
+    #[test]
+    fn test_wildcard_match() {
+        assert!(wildcard_match("*", "anything"));
+        assert!(wildcard_match("*.example.com", "a.example.com"));
+        assert!(!wildcard_match("*.example.com", "example.com"));
+        assert!(wildcard_match("web-?", "web-1"));
+        assert!(!wildcard_match("web-?", "web-10"));
+    }
+
+    #[test]
+    fn test_parse_and_resolve() {
+        let contents = r#"
+# Global options apply to all hosts (unless set earlier).
+Port 2200
+
+Host web db
+    HostName %h.example.com
+    User deploy
+
+Host db
+    Port=5522
+    IdentityFile "/keys/db key"
+
+Host *.internal !secret.internal
+    User internal
+
+Host secret.internal
+    HostName 10.0.0.5
+
+Match host web
+    User ignored
+"#;
+
+        let dir = std::env::temp_dir();
+
+        let mut blocks = vec![global_block()];
+        parse(contents, &dir, 0, &mut blocks);
+
+        let hosts = resolve(&blocks);
+
+        assert_eq!(
+            hosts,
+            vec![
+                SshHost {
+                    alias: "web".to_owned(),
+                    host_name: Some("web.example.com".to_owned()),
+                    user: Some("deploy".to_owned()),
+                    port: Some(2200),
+                    identity_file: None,
+                },
+                SshHost {
+                    alias: "db".to_owned(),
+                    host_name: Some("db.example.com".to_owned()),
+                    user: Some("deploy".to_owned()),
+                    // The global `Port` comes first, so it wins (like it would for ssh).
+                    port: Some(2200),
+                    identity_file: Some("/keys/db key".to_owned()),
+                },
+                SshHost {
+                    alias: "secret.internal".to_owned(),
+                    host_name: Some("10.0.0.5".to_owned()),
+                    user: None,
+                    port: Some(2200),
+                    identity_file: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_include() {
+        let dir =
+            std::env::temp_dir().join(format!("sftpman-iced-ssh-config-{}", std::process::id()));
+        fs::create_dir_all(dir.join("config.d")).unwrap();
+
+        fs::write(dir.join("config.d/b.conf"), "Host b\n  User bob\n").unwrap();
+        fs::write(dir.join("config.d/a.conf"), "Host a\n  User alice\n").unwrap();
+        fs::write(dir.join("config.d/.hidden"), "Host hidden\n").unwrap();
+
+        let mut blocks = vec![global_block()];
+        parse("Include config.d/*.conf\n", &dir, 0, &mut blocks);
+
+        fs::remove_dir_all(&dir).unwrap();
+
+        let aliases = resolve(&blocks)
+            .into_iter()
+            .map(|host| host.alias)
+            .collect::<Vec<_>>();
+
+        assert_eq!(aliases, ["a", "b"]);
+    }
+
+    #[test]
+    fn test_to_definition() {
+        let host = SshHost {
+            alias: "my host".to_owned(),
+            host_name: Some("example.com".to_owned()),
+            user: Some("user".to_owned()),
+            port: None,
+            identity_file: Some("/home/user/.ssh/id_ed25519".to_owned()),
+        };
+
+        let definition = host.to_definition();
+
+        assert_eq!(definition.id, "my-host");
+        assert_eq!(definition.host, "example.com");
+        assert_eq!(definition.port, 22);
+        assert_eq!(definition.remote_path, "/");
+        assert_eq!(definition.auth_type, AuthType::PublicKey);
+
+        let definition = SshHost {
+            identity_file: None,
+            ..host
+        }
+        .to_definition();
+
+        assert_eq!(definition.auth_type, AuthType::AuthenticationAgent);
+    }
+}
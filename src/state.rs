@@ -0,0 +1,249 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use libsftpman::{FilesystemMountDefinition, Manager, MountState};
+
+use crate::utils::sftpman_mounts_config_dir;
+
+/// InvalidDefinition represents a filesystem definition file which could not be read or parsed.
+#[derive(Debug, Clone)]
+pub struct InvalidDefinition {
+    // id is derived from the file name, because we can't trust the file's contents.
+    pub id: String,
+    pub path: PathBuf,
+    pub error: String,
+}
+
+/// FilesystemsState is the state of all filesystems, as found in the sftpman configuration directory.
+#[derive(Debug, Clone, Default)]
+pub struct FilesystemsState {
+    pub filesystems: Vec<MountState>,
+    pub invalid_definitions: Vec<InvalidDefinition>,
+}
+
+/// Loads the state of all filesystems.
+///
+/// Unlike `Manager::full_state()`, this doesn't give up on the first definition which fails to load.
+/// Such definitions are reported in `FilesystemsState::invalid_definitions` instead.
+/// An error is only returned if the configuration directory itself can't be read.
+pub fn load_state(manager: &Manager) -> Result<FilesystemsState, String> {
+    let Some(dir) = sftpman_mounts_config_dir() else {
+        return Err("Could not determine the configuration directory. Is $HOME set?".to_owned());
+    };
+
+    load_state_from_dir(manager, &dir)
+}
+
+fn load_state_from_dir(manager: &Manager, dir: &Path) -> Result<FilesystemsState, String> {
+    let mut state = FilesystemsState::default();
+
+    // Like libsftpman, we treat a missing directory as having no definitions.
+    if !dir.is_dir() {
+        return Ok(state);
+    }
+
+    let entries = fs::read_dir(dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+
+    for entry in entries {
+        let path = entry
+            .map_err(|e| format!("{}: {}", dir.display(), e))?
+            .path();
+
+        if !path.is_file() || path.extension().is_none_or(|ext| ext != "json") {
+            continue;
+        }
+
+        match load_definition(&path) {
+            Ok(definition) => {
+                let mounted = manager.is_definition_mounted(&definition).unwrap_or(false);
+                state.filesystems.push(MountState::new(definition, mounted));
+            }
+            Err(error) => {
+                log::warn!("Failed to load {}: {}", path.display(), error);
+
+                state.invalid_definitions.push(InvalidDefinition {
+                    id: path
+                        .file_stem()
+                        .map(|stem| stem.to_string_lossy().to_string())
+                        .unwrap_or_default(),
+                    path,
+                    error,
+                });
+            }
+        }
+    }
+
+    state
+        .filesystems
+        .sort_by(|a, b| a.definition.id.cmp(&b.definition.id));
+    state.invalid_definitions.sort_by(|a, b| a.id.cmp(&b.id));
+
+    Ok(state)
+}
+
+/// Returns the filesystems which are mounted according to `before`, but no longer mounted according to `after`.
+///
+/// Filesystems which are no longer defined (e.g. were removed) are not included.
+pub fn no_longer_mounted(
+    before: &[MountState],
+    after: &[MountState],
+) -> Vec<FilesystemMountDefinition> {
+    after
+        .iter()
+        .filter(|current| !current.mounted)
+        .filter(|current| {
+            before
+                .iter()
+                .any(|previous| previous.definition.id == current.definition.id && previous.mounted)
+        })
+        .map(|current| current.definition.clone())
+        .collect()
+}
+
+/// Returns the path to the file holding the definition with the given id (`{config_dir}/mounts/{id}.json`).
+///
+/// libsftpman doesn't expose where it keeps definitions, so we replicate its layout here (and only here).
+/// The tests make sure that we agree with it (see `write_definition_to_dir()`).
+pub fn definition_path(id: &str) -> Option<PathBuf> {
+    sftpman_mounts_config_dir().map(|dir| definition_path_in_dir(&dir, id))
+}
+
+fn definition_path_in_dir(dir: &Path, id: &str) -> PathBuf {
+    dir.join(format!("{}.json", id))
+}
+
+/// Writes the definition to its file in the configuration directory, like `Manager::persist()` does.
+///
+/// Unlike `Manager::persist()`, this doesn't unmount and mount again a filesystem which is mounted (on the calling thread),
+/// so callers can take care of that in the background (see `crate::pages::HomeMessage::Remount`).
+pub fn write_definition(definition: &FilesystemMountDefinition) -> Result<(), String> {
+    let Some(dir) = sftpman_mounts_config_dir() else {
+        return Err("Could not determine the configuration directory. Is $HOME set?".to_owned());
+    };
+
+    write_definition_to_dir(&dir, definition)
+}
+
+fn write_definition_to_dir(
+    dir: &Path,
+    definition: &FilesystemMountDefinition,
+) -> Result<(), String> {
+    fs::create_dir_all(dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+
+    let path = definition_path_in_dir(dir, &definition.id);
+
+    let serialized = definition.to_json_string().map_err(|e| e.to_string())?;
+
+    fs::write(&path, serialized).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+fn load_definition(path: &Path) -> Result<FilesystemMountDefinition, String> {
+    let contents = fs::read_to_string(path).map_err(|e| e.to_string())?;
+
+    FilesystemMountDefinition::from_json_string(&contents).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+
+    use libsftpman::{FilesystemMountDefinition, Manager, MountState};
+
+    use super::{load_state_from_dir, no_longer_mounted, write_definition_to_dir};
+
+    #[test]
+    fn test_invalid_definitions_do_not_prevent_loading() {
+        let dir =
+            std::env::temp_dir().join(format!("sftpman-iced-state-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let definition = FilesystemMountDefinition {
+            id: "valid".to_owned(),
+            host: "example.com".to_owned(),
+            user: "user".to_owned(),
+            remote_path: "/".to_owned(),
+            ..FilesystemMountDefinition::default()
+        };
+
+        fs::write(dir.join("valid.json"), definition.to_json_string().unwrap()).unwrap();
+        fs::write(dir.join("broken.json"), "{ not json").unwrap();
+        fs::write(dir.join("notes.txt"), "ignored").unwrap();
+
+        let state = load_state_from_dir(&Manager::default(), &dir);
+
+        fs::remove_dir_all(&dir).unwrap();
+
+        let state = state.unwrap();
+
+        assert_eq!(state.filesystems.len(), 1);
+        assert_eq!(state.filesystems[0].definition.id, "valid");
+
+        assert_eq!(state.invalid_definitions.len(), 1);
+        assert_eq!(state.invalid_definitions[0].id, "broken");
+        assert!(!state.invalid_definitions[0].error.is_empty());
+    }
+
+    #[test]
+    fn test_written_definitions_are_found_by_libsftpman() {
+        // A default manager's configuration directory is the current directory (the crate's, during tests),
+        // so it looks for definitions in `./mounts`.
+        let manager = Manager::default();
+        let dir = Path::new("mounts");
+
+        let definition = FilesystemMountDefinition {
+            id: format!("sftpman-iced-state-test-{}", std::process::id()),
+            host: "example.com".to_owned(),
+            user: "user".to_owned(),
+            remote_path: "/".to_owned(),
+            ..FilesystemMountDefinition::default()
+        };
+
+        let result = write_definition_to_dir(dir, &definition);
+        let loaded = manager.definition(&definition.id);
+
+        let _ = fs::remove_file(dir.join(format!("{}.json", definition.id)));
+        // This only succeeds if the directory is empty, so it doesn't get in the way of anything else.
+        let _ = fs::remove_dir(dir);
+
+        result.unwrap();
+        assert_eq!(
+            loaded.unwrap().to_json_string().unwrap(),
+            definition.to_json_string().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_no_longer_mounted() {
+        let state = |id: &str, mounted: bool| {
+            MountState::new(
+                FilesystemMountDefinition {
+                    id: id.to_owned(),
+                    ..FilesystemMountDefinition::default()
+                },
+                mounted,
+            )
+        };
+
+        let before = vec![
+            state("lost", true),
+            state("still-mounted", true),
+            state("removed", true),
+            state("never-mounted", false),
+        ];
+
+        let after = vec![
+            state("lost", false),
+            state("still-mounted", true),
+            state("never-mounted", false),
+            state("new", false),
+        ];
+
+        let ids = no_longer_mounted(&before, &after)
+            .into_iter()
+            .map(|definition| definition.id)
+            .collect::<Vec<_>>();
+
+        assert_eq!(ids, vec!["lost"]);
+    }
+}
@@ -38,6 +38,14 @@ pub fn control_bar_about_label() -> String {
     t!("control_bar_about_label").to_string()
 }
 
+pub fn control_bar_activity_label() -> String {
+    t!("control_bar_activity_label").to_string()
+}
+
+pub fn control_bar_activity_tooltip() -> String {
+    t!("control_bar_activity_tooltip").to_string()
+}
+
 pub fn fs_list_open_label() -> String {
     t!("fs_list_open_label").to_string()
 }
@@ -50,6 +58,47 @@ pub fn fs_list_empty_list_label() -> String {
     t!("fs_list_empty_list_label").to_string()
 }
 
+pub fn fs_list_invalid_definition_label(id: &str) -> String {
+    t!("fs_list_invalid_definition_label", id = id).to_string()
+}
+
+pub fn fs_list_open_file_label() -> String {
+    t!("fs_list_open_file_label").to_string()
+}
+
+pub fn startup_error_title() -> String {
+    t!("startup_error_title").to_string()
+}
+
+pub fn startup_error_message(error: &str) -> String {
+    format!(
+        "{}\n\n    {}\n\n{}",
+        t!("startup_error_message"),
+        error,
+        t!("startup_error_hint")
+    )
+}
+
+pub fn startup_error_open_config_directory_button_label() -> String {
+    t!("startup_error_open_config_directory_button_label").to_string()
+}
+
+pub fn record_changed_on_disk_warning_label() -> String {
+    t!("record_changed_on_disk_warning_label").to_string()
+}
+
+pub fn record_removed_on_disk_warning_label() -> String {
+    t!("record_removed_on_disk_warning_label").to_string()
+}
+
+pub fn record_reload_from_disk_label() -> String {
+    t!("record_reload_from_disk_label").to_string()
+}
+
+pub fn record_keep_my_changes_label() -> String {
+    t!("record_keep_my_changes_label").to_string()
+}
+
 pub fn record_mounted_will_be_remounted_warning_label() -> String {
     t!("record_mounted_will_be_remounted_warning_label").to_string()
 }
@@ -82,6 +131,14 @@ pub fn record_username_placeholder() -> String {
     t!("record_username_placeholder").to_string()
 }
 
+pub fn record_suggestions_label() -> String {
+    t!("record_suggestions_label").to_string()
+}
+
+pub fn record_host_suggestion_ssh_config_tooltip() -> String {
+    t!("record_host_suggestion_ssh_config_tooltip").to_string()
+}
+
 pub fn record_ssh_key_label() -> String {
     t!("record_ssh_key_label").to_string()
 }
@@ -148,6 +205,109 @@ pub fn record_command_before_mount_placeholder() -> String {
     .to_string()
 }
 
+pub fn record_test_connection_label() -> String {
+    t!("record_test_connection_label").to_string()
+}
+
+pub fn record_test_connection_tooltip() -> String {
+    t!("record_test_connection_tooltip").to_string()
+}
+
+pub fn record_connection_test_running_label() -> String {
+    t!("record_connection_test_running_label").to_string()
+}
+
+pub fn record_connection_test_reachability_label() -> String {
+    t!("record_connection_test_reachability_label").to_string()
+}
+
+pub fn record_connection_test_authentication_label() -> String {
+    t!("record_connection_test_authentication_label").to_string()
+}
+
+pub fn record_connection_test_remote_path_label() -> String {
+    t!("record_connection_test_remote_path_label").to_string()
+}
+
+pub fn record_connection_test_passed_label() -> String {
+    t!("record_connection_test_passed_label").to_string()
+}
+
+pub fn record_connection_test_skipped_label() -> String {
+    t!("record_connection_test_skipped_label").to_string()
+}
+
+pub fn record_connection_test_dismiss_label() -> String {
+    t!("record_connection_test_dismiss_label").to_string()
+}
+
+pub fn connection_test_unreachable_error(host: &str, port: u16) -> String {
+    t!(
+        "connection_test_unreachable_error",
+        host = host,
+        port = port
+    )
+    .to_string()
+}
+
+pub fn sftp_timed_out_error(seconds: u64) -> String {
+    t!("sftp_timed_out_error", seconds = seconds).to_string()
+}
+
+pub fn sftp_unknown_error() -> String {
+    t!("sftp_unknown_error").to_string()
+}
+
+pub fn sftp_path_line_break_error() -> String {
+    t!("sftp_path_line_break_error").to_string()
+}
+
+pub fn connection_test_empty_remote_path_error() -> String {
+    t!("connection_test_empty_remote_path_error").to_string()
+}
+
+pub fn record_browse_remote_path_tooltip() -> String {
+    t!("record_browse_remote_path_tooltip").to_string()
+}
+
+pub fn remote_browser_title() -> String {
+    t!("remote_browser_title").to_string()
+}
+
+pub fn remote_browser_help_text(user: &str, host: &str, port: u16) -> String {
+    t!(
+        "remote_browser_help_text",
+        user = user,
+        host = host,
+        port = port
+    )
+    .to_string()
+}
+
+pub fn remote_browser_loading_label() -> String {
+    t!("remote_browser_loading_label").to_string()
+}
+
+pub fn remote_browser_parent_directory_button_label() -> String {
+    t!("remote_browser_parent_directory_button_label").to_string()
+}
+
+pub fn remote_browser_no_subdirectories_label() -> String {
+    t!("remote_browser_no_subdirectories_label").to_string()
+}
+
+pub fn remote_browser_selected_label(path: &str) -> String {
+    t!("remote_browser_selected_label", path = path).to_string()
+}
+
+pub fn remote_browser_nothing_selected_label() -> String {
+    t!("remote_browser_nothing_selected_label").to_string()
+}
+
+pub fn remote_browser_choose_button_label() -> String {
+    t!("remote_browser_choose_button_label").to_string()
+}
+
 pub fn record_save_label() -> String {
     t!("record_save_label").to_string()
 }
@@ -160,6 +320,10 @@ pub fn browse_label() -> String {
     t!("browse_label").to_string()
 }
 
+pub fn save_succeeded_notification_title(id: &str) -> String {
+    t!("save_succeeded_notification_title", id = id).to_string()
+}
+
 pub fn save_failed_alert_validation_failed_title() -> String {
     t!("save_failed_alert_validation_failed_title").to_string()
 }
@@ -220,6 +384,10 @@ pub fn remove_confirmation_cancellation_button_label() -> String {
     t!("remove_confirmation_cancellation_button_label").to_string()
 }
 
+pub fn remove_succeeded_notification_title(id: &str) -> String {
+    t!("remove_succeeded_notification_title", id = id).to_string()
+}
+
 pub fn remove_failed_alert_title(id: &str) -> String {
     t!("remove_failed_alert_title", id = id).to_string()
 }
@@ -228,6 +396,10 @@ pub fn remove_failed_alert_message(error: &str) -> String {
     error.to_string()
 }
 
+pub fn remove_busy_error() -> String {
+    t!("remove_busy_error").to_string()
+}
+
 pub fn mount_failed_alert_title(id: &str) -> String {
     t!("mount_failed_alert_title", id = id).to_string()
 }
@@ -296,6 +468,46 @@ pub fn unmount_failed_alert_message(
     err
 }
 
+pub fn unmount_busy_short_message(count: usize) -> String {
+    t!("unmount_busy_short_message", count = count).to_string()
+}
+
+pub fn unmount_busy_alert_message(processes: &[String], error: &str) -> String {
+    format!(
+        "{}\n{}\n\n{}\n\n{}\n    {}",
+        t!("unmount_busy_alert_message_processes_heading"),
+        processes.join("\n"),
+        t!("unmount_busy_alert_message_hint"),
+        t!("mount_unmount_failed_alert_message_error_debug"),
+        error
+    )
+}
+
+pub fn unmount_busy_retry_button_label() -> String {
+    t!("unmount_busy_retry_button_label").to_string()
+}
+
+pub fn unmount_busy_lazy_button_label() -> String {
+    t!("unmount_busy_lazy_button_label").to_string()
+}
+
+pub fn unmount_busy_lazy_button_tooltip() -> String {
+    t!("unmount_busy_lazy_button_tooltip").to_string()
+}
+
+pub fn remount_previous_definition_kept_alert_title(id: &str) -> String {
+    t!("remount_previous_definition_kept_alert_title", id = id).to_string()
+}
+
+pub fn remount_previous_definition_kept_alert_message(previous_id: &str, id: &str) -> String {
+    t!(
+        "remount_previous_definition_kept_alert_message",
+        previous_id = previous_id,
+        id = id
+    )
+    .to_string()
+}
+
 pub fn open_failed_alert_title(id: &str) -> String {
     t!("open_failed_alert_title", id = id).to_string()
 }
@@ -362,10 +574,123 @@ pub fn filesystem_definition_name_unmounting_label(name: &str) -> String {
     )
 }
 
+pub fn filesystem_definition_name_broken_label(name: &str) -> String {
+    format!(
+        "{} {}",
+        name,
+        t!(
+            "filesystem_definition_name_broken_label_broken_suffix",
+            name = name
+        )
+    )
+}
+
+pub fn filesystem_definition_name_reconnecting_label(
+    name: &str,
+    attempt: u32,
+    max_attempts: u32,
+) -> String {
+    // A maximum of 0 means unlimited attempts (see `Settings::reconnect_max_failures`).
+    let suffix = if max_attempts == 0 {
+        t!(
+            "filesystem_definition_name_reconnecting_label_reconnecting_suffix",
+            attempt = attempt
+        )
+    } else {
+        t!(
+            "filesystem_definition_name_reconnecting_label_reconnecting_suffix_limited",
+            attempt = attempt,
+            max_attempts = max_attempts
+        )
+    };
+
+    format!("{} {}", name, suffix)
+}
+
 pub fn alert_close_button_label() -> String {
     t!("alert_close_button_label").to_string()
 }
 
+pub fn notification_details_button_label() -> String {
+    t!("notification_details_button_label").to_string()
+}
+
+pub fn notification_dismiss_all_button_label() -> String {
+    t!("notification_dismiss_all_button_label").to_string()
+}
+
+pub fn notification_stack_more_label(count: usize) -> String {
+    t!("notification_stack_more_label", count = count).to_string()
+}
+
+pub fn activity_log_title() -> String {
+    t!("activity_log_title").to_string()
+}
+
+pub fn activity_log_filter_all_label() -> String {
+    t!("activity_log_filter_all_label").to_string()
+}
+
+pub fn activity_log_empty_message() -> String {
+    t!("activity_log_empty_message").to_string()
+}
+
+pub fn activity_log_kind_mount_label() -> String {
+    t!("activity_log_kind_mount_label").to_string()
+}
+
+pub fn activity_log_kind_unmount_label() -> String {
+    t!("activity_log_kind_unmount_label").to_string()
+}
+
+pub fn activity_log_kind_save_label() -> String {
+    t!("activity_log_kind_save_label").to_string()
+}
+
+pub fn activity_log_kind_remove_label() -> String {
+    t!("activity_log_kind_remove_label").to_string()
+}
+
+pub fn activity_log_kind_preflight_check_label() -> String {
+    t!("activity_log_kind_preflight_check_label").to_string()
+}
+
+pub fn activity_log_kind_reconnect_label() -> String {
+    t!("activity_log_kind_reconnect_label").to_string()
+}
+
+pub fn activity_log_outcome_success_label() -> String {
+    t!("activity_log_outcome_success_label").to_string()
+}
+
+pub fn activity_log_outcome_failure_label() -> String {
+    t!("activity_log_outcome_failure_label").to_string()
+}
+
+pub fn activity_log_command_label() -> String {
+    t!("activity_log_command_label").to_string()
+}
+
+pub fn activity_log_error_label() -> String {
+    t!("activity_log_error_label").to_string()
+}
+
+pub fn activity_log_copy_button_label() -> String {
+    t!("activity_log_copy_button_label").to_string()
+}
+
+pub fn activity_log_save_button_label() -> String {
+    t!("activity_log_save_button_label").to_string()
+}
+
+pub fn activity_log_export_failed_title() -> String {
+    t!("activity_log_export_failed_title").to_string()
+}
+
+pub fn activity_log_export_succeeded_title(path: &str) -> String {
+    t!("activity_log_export_succeeded_title", path = path).to_string()
+}
+
 pub fn confirmation_confirmation_button_label() -> String {
     t!("confirmation_confirmation_button_label").to_string()
 }
@@ -385,3 +710,513 @@ pub fn field_control_label_required_tooltip() -> String {
 pub fn button_retry() -> String {
     t!("button_retry").to_string()
 }
+
+pub fn control_bar_menu_label() -> String {
+    t!("control_bar_menu_label").to_string()
+}
+
+pub fn control_bar_settings_label() -> String {
+    t!("control_bar_settings_label").to_string()
+}
+
+pub fn control_bar_settings_tooltip() -> String {
+    t!("control_bar_settings_tooltip").to_string()
+}
+
+pub fn fs_list_cancel_mount_label() -> String {
+    t!("fs_list_cancel_mount_label").to_string()
+}
+
+pub fn fs_list_cancel_mount_tooltip() -> String {
+    t!("fs_list_cancel_mount_tooltip").to_string()
+}
+
+pub fn fs_list_repair_label() -> String {
+    t!("fs_list_repair_label").to_string()
+}
+
+pub fn fs_list_repair_tooltip(reason: &str) -> String {
+    t!("fs_list_repair_tooltip", reason = reason).to_string()
+}
+
+pub fn health_not_responding_error(seconds: u64) -> String {
+    t!("health_not_responding_error", seconds = seconds).to_string()
+}
+
+pub fn repair_failed_alert_title(id: &str) -> String {
+    t!("repair_failed_alert_title", id = id).to_string()
+}
+
+pub fn fs_list_export_systemd_label() -> String {
+    t!("fs_list_export_systemd_label").to_string()
+}
+
+pub fn fs_list_export_fstab_label() -> String {
+    t!("fs_list_export_fstab_label").to_string()
+}
+
+pub fn export_systemd_title(id: &str) -> String {
+    t!("export_systemd_title", id = id).to_string()
+}
+
+pub fn export_fstab_title(id: &str) -> String {
+    t!("export_fstab_title", id = id).to_string()
+}
+
+pub fn export_copy_button_label() -> String {
+    t!("export_copy_button_label").to_string()
+}
+
+pub fn export_save_button_label() -> String {
+    t!("export_save_button_label").to_string()
+}
+
+pub fn export_save_succeeded_title() -> String {
+    t!("export_save_succeeded_title").to_string()
+}
+
+pub fn export_save_failed_title() -> String {
+    t!("export_save_failed_title").to_string()
+}
+
+pub fn mount_cancelled_notification_title(id: &str) -> String {
+    t!("mount_cancelled_notification_title", id = id).to_string()
+}
+
+pub fn mount_timed_out_error(seconds: u64) -> String {
+    t!("mount_timed_out_error", seconds = seconds).to_string()
+}
+
+pub fn record_mount_timeout_label() -> String {
+    t!("record_mount_timeout_label").to_string()
+}
+
+pub fn record_mount_timeout_placeholder(default_seconds: u64) -> String {
+    t!(
+        "record_mount_timeout_placeholder",
+        seconds = default_seconds
+    )
+    .to_string()
+}
+
+pub fn record_mount_at_startup_label() -> String {
+    t!("record_mount_at_startup_label").to_string()
+}
+
+pub fn record_mount_at_startup_checkbox_label() -> String {
+    t!("record_mount_at_startup_checkbox_label").to_string()
+}
+
+pub fn record_mount_at_startup_help_text() -> String {
+    t!("record_mount_at_startup_help_text").to_string()
+}
+
+pub fn record_keep_mounted_label() -> String {
+    t!("record_keep_mounted_label").to_string()
+}
+
+pub fn record_keep_mounted_checkbox_label() -> String {
+    t!("record_keep_mounted_checkbox_label").to_string()
+}
+
+pub fn record_keep_mounted_help_text() -> String {
+    t!("record_keep_mounted_help_text").to_string()
+}
+
+pub fn settings_mount_timeout_label() -> String {
+    t!("settings_mount_timeout_label").to_string()
+}
+
+pub fn settings_mount_timeout_help_text() -> String {
+    t!("settings_mount_timeout_help_text").to_string()
+}
+
+pub fn settings_reconnect_max_failures_label() -> String {
+    t!("settings_reconnect_max_failures_label").to_string()
+}
+
+pub fn settings_reconnect_max_failures_help_text() -> String {
+    t!("settings_reconnect_max_failures_help_text").to_string()
+}
+
+pub fn settings_save_succeeded_notification_title() -> String {
+    t!("settings_save_succeeded_notification_title").to_string()
+}
+
+pub fn mount_cancelled_error() -> String {
+    t!("mount_cancelled_error").to_string()
+}
+
+pub fn batch_mount_summary_title(succeeded: usize, total: usize) -> String {
+    t!(
+        "batch_mount_summary_title",
+        succeeded = succeeded,
+        total = total
+    )
+    .to_string()
+}
+
+pub fn batch_unmount_summary_title(succeeded: usize, total: usize) -> String {
+    t!(
+        "batch_unmount_summary_title",
+        succeeded = succeeded,
+        total = total
+    )
+    .to_string()
+}
+
+pub fn batch_mount_succeeded_notification_title(count: usize) -> String {
+    t!("batch_mount_succeeded_notification_title", count = count).to_string()
+}
+
+pub fn batch_unmount_succeeded_notification_title(count: usize) -> String {
+    t!("batch_unmount_succeeded_notification_title", count = count).to_string()
+}
+
+pub fn batch_summary_message(succeeded: &[String], failed: &[(String, String)]) -> String {
+    let mut sections = vec![];
+
+    if !failed.is_empty() {
+        sections.push(format!(
+            "{}
+{}",
+            t!("batch_summary_failed_heading"),
+            failed
+                .iter()
+                .map(|(id, error)| format!("- {}: {}", id, error))
+                .collect::<Vec<_>>()
+                .join("\n")
+        ));
+    }
+
+    if !succeeded.is_empty() {
+        sections.push(format!(
+            "{}
+{}",
+            t!("batch_summary_succeeded_heading"),
+            succeeded
+                .iter()
+                .map(|id| format!("- {}", id))
+                .collect::<Vec<_>>()
+                .join("\n")
+        ));
+    }
+
+    sections.join("\n\n")
+}
+
+pub fn batch_busy_error() -> String {
+    t!("batch_busy_error").to_string()
+}
+
+pub fn batch_summary_retry_failed_button_label() -> String {
+    t!("batch_summary_retry_failed_button_label").to_string()
+}
+
+pub fn settings_parallel_batch_operations_label() -> String {
+    t!("settings_parallel_batch_operations_label").to_string()
+}
+
+pub fn settings_parallel_batch_operations_checkbox_label() -> String {
+    t!("settings_parallel_batch_operations_checkbox_label").to_string()
+}
+
+pub fn settings_parallel_batch_operations_help_text() -> String {
+    t!("settings_parallel_batch_operations_help_text").to_string()
+}
+
+pub fn cli_unknown_filesystem_title(id: &str) -> String {
+    t!("cli_unknown_filesystem_title", id = id).to_string()
+}
+
+pub fn cli_unknown_filesystem_message(id: &str) -> String {
+    t!("cli_unknown_filesystem_message", id = id).to_string()
+}
+
+pub fn settings_start_on_login_label() -> String {
+    t!("settings_start_on_login_label").to_string()
+}
+
+pub fn settings_start_on_login_checkbox_label() -> String {
+    t!("settings_start_on_login_checkbox_label").to_string()
+}
+
+pub fn settings_autostart_exit_after_mounting_checkbox_label() -> String {
+    t!("settings_autostart_exit_after_mounting_checkbox_label").to_string()
+}
+
+pub fn settings_start_on_login_help_text() -> String {
+    t!("settings_start_on_login_help_text").to_string()
+}
+
+#[cfg(feature = "tray")]
+pub fn settings_close_to_tray_label() -> String {
+    t!("settings_close_to_tray_label").to_string()
+}
+
+#[cfg(feature = "tray")]
+pub fn settings_close_to_tray_checkbox_label() -> String {
+    t!("settings_close_to_tray_checkbox_label").to_string()
+}
+
+#[cfg(feature = "tray")]
+pub fn settings_close_to_tray_help_text() -> String {
+    t!("settings_close_to_tray_help_text").to_string()
+}
+
+#[cfg(feature = "tray")]
+pub fn tray_tooltip_description(mounted: usize, total: usize) -> String {
+    t!("tray_tooltip_description", mounted = mounted, total = total).to_string()
+}
+
+#[cfg(feature = "tray")]
+pub fn tray_no_filesystems_label() -> String {
+    t!("tray_no_filesystems_label").to_string()
+}
+
+#[cfg(feature = "tray")]
+pub fn tray_show_window_label() -> String {
+    t!("tray_show_window_label").to_string()
+}
+
+#[cfg(feature = "tray")]
+pub fn tray_quit_label() -> String {
+    t!("tray_quit_label").to_string()
+}
+
+#[cfg(feature = "desktop-notifications")]
+pub fn settings_desktop_notifications_label() -> String {
+    t!("settings_desktop_notifications_label").to_string()
+}
+
+#[cfg(feature = "desktop-notifications")]
+pub fn settings_desktop_notifications_checkbox_label() -> String {
+    t!("settings_desktop_notifications_checkbox_label").to_string()
+}
+
+#[cfg(feature = "desktop-notifications")]
+pub fn settings_desktop_notifications_help_text() -> String {
+    t!("settings_desktop_notifications_help_text").to_string()
+}
+
+#[cfg(feature = "desktop-notifications")]
+pub fn desktop_notification_show_details_action_label() -> String {
+    t!("desktop_notification_show_details_action_label").to_string()
+}
+
+pub fn mount_succeeded_notification_title(id: &str) -> String {
+    t!("mount_succeeded_notification_title", id = id).to_string()
+}
+
+pub fn mount_succeeded_notification_message(path: &str) -> String {
+    t!("mount_succeeded_notification_message", path = path).to_string()
+}
+
+pub fn filesystem_lost_notification_title(id: &str) -> String {
+    t!("filesystem_lost_notification_title", id = id).to_string()
+}
+
+pub fn filesystem_lost_notification_message() -> String {
+    t!("filesystem_lost_notification_message").to_string()
+}
+
+pub fn filesystem_lost_reconnecting_notification_message() -> String {
+    t!("filesystem_lost_reconnecting_notification_message").to_string()
+}
+
+pub fn reconnect_succeeded_notification_title(id: &str) -> String {
+    t!("reconnect_succeeded_notification_title", id = id).to_string()
+}
+
+pub fn reconnect_gave_up_notification_title(id: &str) -> String {
+    t!("reconnect_gave_up_notification_title", id = id).to_string()
+}
+
+pub fn reconnect_gave_up_notification_message(failures: u32, error: &str) -> String {
+    t!(
+        "reconnect_gave_up_notification_message",
+        failures = failures,
+        error = error
+    )
+    .to_string()
+}
+
+#[cfg(feature = "logind")]
+pub fn settings_unmount_before_sleep_label() -> String {
+    t!("settings_unmount_before_sleep_label").to_string()
+}
+
+#[cfg(feature = "logind")]
+pub fn settings_unmount_before_sleep_help_text() -> String {
+    t!("settings_unmount_before_sleep_help_text").to_string()
+}
+
+pub fn unmount_before_sleep_never_label() -> String {
+    t!("unmount_before_sleep_never_label").to_string()
+}
+
+pub fn unmount_before_sleep_all_label() -> String {
+    t!("unmount_before_sleep_all_label").to_string()
+}
+
+pub fn unmount_before_sleep_marked_label() -> String {
+    t!("unmount_before_sleep_marked_label").to_string()
+}
+
+#[cfg(feature = "logind")]
+pub fn record_unmount_before_sleep_checkbox_label() -> String {
+    t!("record_unmount_before_sleep_checkbox_label").to_string()
+}
+
+#[cfg(feature = "logind")]
+pub fn record_unmount_before_sleep_help_text() -> String {
+    t!("record_unmount_before_sleep_help_text").to_string()
+}
+
+#[cfg(feature = "logind")]
+pub fn logind_inhibitor_reason() -> String {
+    t!("logind_inhibitor_reason").to_string()
+}
+
+pub fn fs_list_open_terminal_label() -> String {
+    t!("fs_list_open_terminal_label").to_string()
+}
+
+pub fn fs_list_open_terminal_tooltip() -> String {
+    t!("fs_list_open_terminal_tooltip").to_string()
+}
+
+pub fn fs_list_ssh_label() -> String {
+    t!("fs_list_ssh_label").to_string()
+}
+
+pub fn fs_list_ssh_tooltip(remote_path: &str) -> String {
+    t!("fs_list_ssh_tooltip", remote_path = remote_path).to_string()
+}
+
+pub fn terminal_failed_alert_title(id: &str) -> String {
+    t!("terminal_failed_alert_title", id = id).to_string()
+}
+
+pub fn terminal_not_found_error() -> String {
+    t!("terminal_not_found_error").to_string()
+}
+
+pub fn settings_terminal_command_label() -> String {
+    t!("settings_terminal_command_label").to_string()
+}
+
+pub fn settings_terminal_command_placeholder() -> String {
+    t!("settings_terminal_command_placeholder").to_string()
+}
+
+pub fn settings_terminal_command_help_text() -> String {
+    t!("settings_terminal_command_help_text").to_string()
+}
+
+pub fn open_command_failed_error(command: &str, error: &str) -> String {
+    t!(
+        "open_command_failed_error",
+        command = command,
+        error = error
+    )
+    .to_string()
+}
+
+pub fn open_link_failed_alert_title() -> String {
+    t!("open_link_failed_alert_title").to_string()
+}
+
+pub fn settings_open_command_label() -> String {
+    t!("settings_open_command_label").to_string()
+}
+
+pub fn settings_open_command_placeholder() -> String {
+    t!("settings_open_command_placeholder").to_string()
+}
+
+pub fn settings_open_command_help_text() -> String {
+    t!("settings_open_command_help_text").to_string()
+}
+
+pub fn control_bar_import_label() -> String {
+    t!("control_bar_import_label").to_string()
+}
+
+pub fn control_bar_import_tooltip() -> String {
+    t!("control_bar_import_tooltip").to_string()
+}
+
+pub fn ssh_import_help_text() -> String {
+    t!("ssh_import_help_text").to_string()
+}
+
+pub fn ssh_import_load_failed_message(error: &str) -> String {
+    t!("ssh_import_load_failed_message", error = error).to_string()
+}
+
+pub fn ssh_import_no_hosts_message() -> String {
+    t!("ssh_import_no_hosts_message").to_string()
+}
+
+pub fn ssh_import_host_details(
+    id: &str,
+    user: &str,
+    host: &str,
+    port: u16,
+    ssh_key: &str,
+) -> String {
+    let details = t!(
+        "ssh_import_host_details",
+        id = id,
+        user = user,
+        host = host,
+        port = port
+    )
+    .to_string();
+
+    if ssh_key.is_empty() {
+        return details;
+    }
+
+    format!(
+        "{} {}",
+        details,
+        t!("ssh_import_host_details_key", ssh_key = ssh_key)
+    )
+}
+
+pub fn ssh_import_conflict_message(id: &str) -> String {
+    t!("ssh_import_conflict_message", id = id).to_string()
+}
+
+pub fn ssh_import_button_label(count: usize) -> String {
+    t!("ssh_import_button_label", count = count).to_string()
+}
+
+pub fn ssh_import_succeeded_notification_title(count: usize) -> String {
+    t!("ssh_import_succeeded_notification_title", count = count).to_string()
+}
+
+pub fn ssh_import_failed_alert_title() -> String {
+    t!("ssh_import_failed_alert_title").to_string()
+}
+
+pub fn ssh_import_failed_alert_message(imported_count: usize, errors: &str) -> String {
+    format!(
+        "{}\n{}",
+        t!(
+            "ssh_import_failed_alert_message",
+            imported_count = imported_count
+        ),
+        errors
+    )
+}
+
+pub fn batch_summary_failed_short_message(failed_ids: &[String]) -> String {
+    format!(
+        "{} {}",
+        t!("batch_summary_failed_heading"),
+        failed_ids.join(", ")
+    )
+}
@@ -0,0 +1,317 @@
+use std::env;
+use std::fs;
+
+use libsftpman::{AuthType, FilesystemMountDefinition};
+
+use crate::ssh_config::{self, SshHost};
+
+// How many suggestions to offer for a field at most.
+const MAX_SUGGESTIONS: usize = 5;
+
+/// HostSuggestion is a host which the filesystem editor offers for picking.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HostSuggestion {
+    // An alias from `~/.ssh/config`. Picking it also fills in the port, user and SSH key (see `apply()`).
+    SshConfig(SshHost),
+
+    // A host seen in `~/.ssh/known_hosts` or in existing definitions.
+    Host(String),
+}
+
+impl HostSuggestion {
+    /// Returns the text shown for the suggestion (the alias, for hosts from `~/.ssh/config`).
+    pub fn label(&self) -> String {
+        match self {
+            Self::SshConfig(ssh_host) => ssh_host.alias.clone(),
+            Self::Host(host) => host.clone(),
+        }
+    }
+
+    /// Fills in the definition with what the suggestion knows about the host.
+    ///
+    /// For `~/.ssh/config` aliases, this follows `SshHost::to_definition()`, but keeps the current user if the alias doesn't set one.
+    pub fn apply(&self, definition: &mut FilesystemMountDefinition) {
+        match self {
+            Self::SshConfig(ssh_host) => {
+                let imported = ssh_host.to_definition();
+
+                definition.host = imported.host;
+                definition.port = imported.port;
+
+                if ssh_host.user.is_some() {
+                    definition.user = imported.user;
+                }
+
+                if !imported.ssh_key.is_empty() {
+                    definition.auth_type = AuthType::PublicKey;
+                    definition.ssh_key = imported.ssh_key;
+                }
+            }
+            Self::Host(host) => definition.host = host.clone(),
+        }
+    }
+
+    fn host(&self) -> String {
+        match self {
+            Self::SshConfig(ssh_host) => ssh_host
+                .host_name
+                .clone()
+                .unwrap_or_else(|| ssh_host.alias.clone()),
+            Self::Host(host) => host.clone(),
+        }
+    }
+}
+
+/// Suggestions holds the hosts and users offered while editing a filesystem definition.
+#[derive(Debug, Clone, Default)]
+pub struct Suggestions {
+    hosts: Vec<HostSuggestion>,
+    users: Vec<String>,
+}
+
+impl Suggestions {
+    /// Collects suggestions from `~/.ssh/config`, `~/.ssh/known_hosts` and the given (existing) definitions.
+    ///
+    /// Files which can't be read are skipped, as suggestions are merely a convenience.
+    pub fn load(definitions: &[FilesystemMountDefinition]) -> Self {
+        let ssh_hosts = ssh_config::load().unwrap_or_else(|e| {
+            log::debug!("Not suggesting hosts from the SSH config: {}", e);
+            vec![]
+        });
+
+        let known_hosts = known_hosts_path()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .map(|contents| parse_known_hosts(&contents))
+            .unwrap_or_default();
+
+        Self::new(ssh_hosts, known_hosts, definitions)
+    }
+
+    fn new(
+        ssh_hosts: Vec<SshHost>,
+        known_hosts: Vec<String>,
+        definitions: &[FilesystemMountDefinition],
+    ) -> Self {
+        let mut suggestions = Self::default();
+
+        for ssh_host in ssh_hosts {
+            if let Some(user) = &ssh_host.user {
+                suggestions.add_user(user);
+            }
+
+            suggestions.hosts.push(HostSuggestion::SshConfig(ssh_host));
+        }
+
+        for definition in definitions {
+            suggestions.add_host(&definition.host);
+            suggestions.add_user(&definition.user);
+        }
+
+        for host in known_hosts {
+            suggestions.add_host(&host);
+        }
+
+        if let Ok(user) = env::var("USER") {
+            suggestions.add_user(&user);
+        }
+
+        suggestions
+    }
+
+    // Adds a host, unless it (or an alias for it) is already suggested.
+    fn add_host(&mut self, host: &str) {
+        if host.is_empty()
+            || self
+                .hosts
+                .iter()
+                .any(|suggestion| suggestion.label() == host || suggestion.host() == host)
+        {
+            return;
+        }
+
+        self.hosts.push(HostSuggestion::Host(host.to_owned()));
+    }
+
+    fn add_user(&mut self, user: &str) {
+        if !user.is_empty() && !self.users.iter().any(|u| u == user) {
+            self.users.push(user.to_owned());
+        }
+    }
+
+    /// Returns the host suggestions matching what's been typed so far (or the first few, if nothing has been).
+    ///
+    /// Suggestions which the value already equals are left out, as picking them would change nothing.
+    pub fn matching_hosts(&self, value: &str) -> Vec<HostSuggestion> {
+        matching(&self.hosts, value, |suggestion| {
+            vec![suggestion.label(), suggestion.host()]
+        })
+    }
+
+    /// Returns the user suggestions matching what's been typed so far (see `matching_hosts()`).
+    pub fn matching_users(&self, value: &str) -> Vec<String> {
+        matching(&self.users, value, |user| vec![user.clone()])
+    }
+}
+
+// Returns the (up to `MAX_SUGGESTIONS`) items for which any of the given texts contains the value (case-insensitively).
+fn matching<T: Clone>(items: &[T], value: &str, texts: impl Fn(&T) -> Vec<String>) -> Vec<T> {
+    let value = value.trim().to_lowercase();
+
+    items
+        .iter()
+        .filter(|item| {
+            let texts = texts(item)
+                .into_iter()
+                .map(|text| text.to_lowercase())
+                .collect::<Vec<_>>();
+
+            !texts.contains(&value) && texts.iter().any(|text| text.contains(&value))
+        })
+        .take(MAX_SUGGESTIONS)
+        .cloned()
+        .collect()
+}
+
+fn known_hosts_path() -> Option<std::path::PathBuf> {
+    directories::BaseDirs::new().map(|dirs| dirs.home_dir().join(".ssh").join("known_hosts"))
+}
+
+// Extracts the host names from `known_hosts` contents.
+//
+// Hashed entries (see `HashKnownHosts` in ssh_config(5)) can't be turned back into host names, so they're skipped.
+// So are patterns (which don't name a single host) and revoked keys.
+fn parse_known_hosts(contents: &str) -> Vec<String> {
+    let mut hosts: Vec<String> = vec![];
+
+    for line in contents.lines() {
+        let mut fields = line.split_whitespace();
+
+        let Some(mut field) = fields.next() else {
+            continue;
+        };
+
+        if field.starts_with('#') || field == "@revoked" {
+            continue;
+        }
+
+        if field.starts_with('@') {
+            let Some(next) = fields.next() else {
+                continue;
+            };
+            field = next;
+        }
+
+        if field.starts_with('|') {
+            continue;
+        }
+
+        for pattern in field.split(',') {
+            // Hosts on non-standard ports are written as `[host]:port`.
+            let host = pattern
+                .strip_prefix('[')
+                .and_then(|rest| rest.split_once("]:"))
+                .map_or(pattern, |(host, _port)| host);
+
+            if host.is_empty() || host.contains(['*', '?', '!']) {
+                continue;
+            }
+
+            if !hosts.iter().any(|h| h == host) {
+                hosts.push(host.to_owned());
+            }
+        }
+    }
+
+    hosts
+}
+
+#[cfg(test)]
+mod tests {
+    use libsftpman::{AuthType, FilesystemMountDefinition};
+
+    use super::{HostSuggestion, Suggestions, parse_known_hosts};
+    use crate::ssh_config::SshHost;
+
+    #[test]
+    fn test_parse_known_hosts() {
+        let contents = "\
+# A comment
+example.com,192.0.2.10 ssh-ed25519 AAAA
+[backup.example.com]:2222 ssh-ed25519 AAAA
+|1|F1E1KeoE/eEWhi10WpGv4OdiO6Y=|3988QV0VE8wmZL7suNrYQLITLCg= ssh-ed25519 AAAA
+@cert-authority *.example.org ssh-ed25519 AAAA
+@revoked revoked.example.com ssh-ed25519 AAAA
+example.com ssh-rsa AAAA
+";
+
+        assert_eq!(
+            parse_known_hosts(contents),
+            ["example.com", "192.0.2.10", "backup.example.com"]
+        );
+    }
+
+    #[test]
+    fn test_matching_hosts() {
+        let ssh_host = SshHost {
+            alias: "web".to_owned(),
+            host_name: Some("web.example.com".to_owned()),
+            ..SshHost::default()
+        };
+
+        let definition = FilesystemMountDefinition {
+            host: "db.example.com".to_owned(),
+            user: "admin".to_owned(),
+            ..FilesystemMountDefinition::default()
+        };
+
+        let suggestions = Suggestions::new(
+            vec![ssh_host.clone()],
+            vec![
+                "web.example.com".to_owned(),
+                "backup.example.com".to_owned(),
+            ],
+            &[definition],
+        );
+
+        assert_eq!(
+            suggestions.matching_hosts("EXAMPLE"),
+            [
+                HostSuggestion::SshConfig(ssh_host),
+                HostSuggestion::Host("db.example.com".to_owned()),
+                HostSuggestion::Host("backup.example.com".to_owned()),
+            ]
+        );
+
+        assert!(suggestions.matching_hosts("db.example.com").is_empty());
+        assert!(
+            suggestions
+                .matching_users("adm")
+                .contains(&"admin".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_apply() {
+        let suggestion = HostSuggestion::SshConfig(SshHost {
+            alias: "web".to_owned(),
+            host_name: Some("web.example.com".to_owned()),
+            port: Some(2222),
+            identity_file: Some("/home/user/.ssh/id_ed25519".to_owned()),
+            ..SshHost::default()
+        });
+
+        let mut definition = FilesystemMountDefinition {
+            user: "admin".to_owned(),
+            auth_type: AuthType::AuthenticationAgent,
+            ..FilesystemMountDefinition::default()
+        };
+
+        suggestion.apply(&mut definition);
+
+        assert_eq!(definition.host, "web.example.com");
+        assert_eq!(definition.port, 2222);
+        assert_eq!(definition.user, "admin");
+        assert_eq!(definition.auth_type, AuthType::PublicKey);
+        assert_eq!(definition.ssh_key, "/home/user/.ssh/id_ed25519");
+    }
+}
@@ -0,0 +1,174 @@
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+use libsftpman::{AuthType, FilesystemMountDefinition};
+
+use crate::strings;
+
+// Terminal emulators we look for (in order) when no terminal command is configured,
+// along with the arguments which make them run the command that follows.
+const KNOWN_TERMINALS: [(&str, &[&str]); 9] = [
+    ("x-terminal-emulator", &["-e"]),
+    ("kitty", &[]),
+    ("alacritty", &["-e"]),
+    ("foot", &[]),
+    ("wezterm", &["start", "--"]),
+    ("konsole", &["-e"]),
+    ("gnome-terminal", &["--"]),
+    ("xfce4-terminal", &["-x"]),
+    ("xterm", &["-e"]),
+];
+
+/// Returns the command line (program and arguments) for launching a terminal emulator,
+/// to which the command to run in it is to be appended.
+///
+/// The configured command (see `Settings::terminal_command`) is split on whitespace.
+/// If it's empty, the first terminal emulator (from a list of known ones) found in `$PATH` is used.
+pub fn terminal_command(configured: &str) -> Result<Vec<String>, String> {
+    let configured = configured
+        .split_whitespace()
+        .map(|arg| arg.to_owned())
+        .collect::<Vec<_>>();
+
+    if !configured.is_empty() {
+        return Ok(configured);
+    }
+
+    KNOWN_TERMINALS
+        .iter()
+        .find(|(program, _)| is_in_path(program))
+        .map(|(program, args)| {
+            std::iter::once(*program)
+                .chain(args.iter().copied())
+                .map(|arg| arg.to_owned())
+                .collect()
+        })
+        .ok_or_else(strings::terminal_not_found_error)
+}
+
+/// Returns the command for starting the user's shell (`$SHELL`, falling back to `/bin/sh`).
+pub fn shell_command() -> Vec<String> {
+    vec![env::var("SHELL").unwrap_or_else(|_| "/bin/sh".to_owned())]
+}
+
+/// Returns the command for opening an SSH session to the filesystem's host, in its remote path.
+///
+/// Authentication mirrors the way sshfs gets invoked by libsftpman (the SSH key is only passed for `AuthType::PublicKey`).
+pub fn ssh_command(definition: &FilesystemMountDefinition) -> Vec<String> {
+    let mut cmd = vec![
+        "ssh".to_owned(),
+        "-t".to_owned(),
+        "-p".to_owned(),
+        definition.port.to_string(),
+    ];
+
+    if definition.auth_type == AuthType::PublicKey && !definition.ssh_key.is_empty() {
+        cmd.push("-i".to_owned());
+        cmd.push(definition.ssh_key.clone());
+    }
+
+    cmd.push(format!("{}@{}", definition.user, definition.host));
+
+    // The remote command is interpreted by the remote user's shell, so the path needs quoting.
+    cmd.push(format!(
+        "cd {} && exec \"$SHELL\" -l",
+        shell_quote(&definition.remote_path)
+    ));
+
+    cmd
+}
+
+/// Launches the given command in a terminal emulator (see `terminal_command()`), in the given working directory.
+pub fn open(
+    configured_terminal_command: &str,
+    command: Vec<String>,
+    cwd: &str,
+) -> Result<(), String> {
+    let mut args = terminal_command(configured_terminal_command)?;
+    args.extend(command);
+
+    log::info!("Opening a terminal (in {}): {:?}", cwd, args);
+
+    Command::new(&args[0])
+        .args(&args[1..])
+        .current_dir(cwd)
+        .spawn()
+        .map(|_| ())
+        .map_err(|e| format!("{}: {}", args[0], e))
+}
+
+fn is_in_path(program: &str) -> bool {
+    env::var_os("PATH").is_some_and(|paths| {
+        env::split_paths(&paths).any(|dir| Path::new(&dir).join(program).is_file())
+    })
+}
+
+// Quotes a value for POSIX shells, unless it's made only of characters which need no quoting.
+fn shell_quote(value: &str) -> String {
+    if !value.is_empty()
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "/._-~+".contains(c))
+    {
+        return value.to_owned();
+    }
+
+    format!("'{}'", value.replace('\'', "'\\''"))
+}
+
+#[cfg(test)]
+mod tests {
+    use libsftpman::{AuthType, FilesystemMountDefinition};
+
+    use super::{shell_quote, ssh_command, terminal_command};
+
+    #[test]
+    fn test_terminal_command() {
+        assert_eq!(
+            terminal_command("  gnome-terminal   -- ").unwrap(),
+            ["gnome-terminal", "--"]
+        );
+    }
+
+    #[test]
+    fn test_ssh_command() {
+        let definition = FilesystemMountDefinition {
+            id: "example".to_owned(),
+            host: "example.com".to_owned(),
+            port: 2222,
+            user: "user".to_owned(),
+            remote_path: "/srv/my files".to_owned(),
+            ssh_key: "/home/user/.ssh/id_ed25519".to_owned(),
+            ..FilesystemMountDefinition::default()
+        };
+
+        assert_eq!(
+            ssh_command(&definition),
+            [
+                "ssh",
+                "-t",
+                "-p",
+                "2222",
+                "-i",
+                "/home/user/.ssh/id_ed25519",
+                "user@example.com",
+                "cd '/srv/my files' && exec \"$SHELL\" -l",
+            ]
+        );
+
+        let definition = FilesystemMountDefinition {
+            auth_type: AuthType::AuthenticationAgent,
+            ..definition
+        };
+
+        assert!(!ssh_command(&definition).contains(&"-i".to_owned()));
+    }
+
+    #[test]
+    fn test_shell_quote() {
+        assert_eq!(shell_quote("/srv/data"), "/srv/data");
+        assert_eq!(shell_quote("/srv/it's"), "'/srv/it'\\''s'");
+        assert_eq!(shell_quote("/srv/$HOME"), "'/srv/$HOME'");
+    }
+}
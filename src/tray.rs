@@ -0,0 +1,251 @@
+use std::fmt;
+
+use iced::Subscription;
+use iced::futures::Stream;
+use iced::futures::channel::mpsc;
+use iced::stream;
+use ksni::menu::{CheckmarkItem, StandardItem};
+use ksni::{MenuItem, OfflineReason, ToolTip, TrayMethods};
+use libsftpman::{FilesystemMountDefinition, Manager, MountState};
+
+use crate::state::load_state;
+use crate::strings;
+use crate::utils::{run_blocking, sftpman_manager};
+
+const ICON_NAME: &str = env!("CARGO_PKG_NAME");
+
+#[derive(Debug, Clone)]
+pub enum TrayEvent {
+    // Ready is produced once the tray icon is registered. The handle can be used to update it.
+    Ready(TrayHandle),
+
+    // Available tells whether the tray icon can currently be shown (a system tray is running).
+    Available(bool),
+
+    Mount(FilesystemMountDefinition),
+    Unmount(FilesystemMountDefinition),
+    MountAll,
+    UnmountAll,
+    ShowWindow,
+    Quit,
+}
+
+struct Tray {
+    filesystems: Vec<MountState>,
+    output: mpsc::Sender<TrayEvent>,
+}
+
+impl Tray {
+    fn send(&mut self, event: TrayEvent) {
+        if let Err(e) = self.output.try_send(event) {
+            log::warn!("Dropping a tray event: {}", e);
+        }
+    }
+}
+
+impl ksni::Tray for Tray {
+    fn id(&self) -> String {
+        env!("CARGO_PKG_NAME").to_owned()
+    }
+
+    fn title(&self) -> String {
+        env!("CARGO_PKG_NAME").to_owned()
+    }
+
+    fn icon_name(&self) -> String {
+        ICON_NAME.to_owned()
+    }
+
+    fn tool_tip(&self) -> ToolTip {
+        let mounted = self.filesystems.iter().filter(|fs| fs.mounted).count();
+
+        ToolTip {
+            icon_name: ICON_NAME.to_owned(),
+            title: env!("CARGO_PKG_NAME").to_owned(),
+            description: strings::tray_tooltip_description(mounted, self.filesystems.len()),
+            ..ToolTip::default()
+        }
+    }
+
+    fn activate(&mut self, _x: i32, _y: i32) {
+        self.send(TrayEvent::ShowWindow);
+    }
+
+    fn menu(&self) -> Vec<MenuItem<Self>> {
+        let mut items: Vec<MenuItem<Self>> = self
+            .filesystems
+            .iter()
+            .map(|fs| {
+                let definition = fs.definition.clone();
+                let mounted = fs.mounted;
+
+                CheckmarkItem {
+                    label: escape_label(&fs.definition.id),
+                    checked: mounted,
+                    activate: Box::new(move |tray: &mut Self| {
+                        if mounted {
+                            tray.send(TrayEvent::Unmount(definition.clone()));
+                        } else {
+                            tray.send(TrayEvent::Mount(definition.clone()));
+                        }
+                    }),
+                    ..CheckmarkItem::default()
+                }
+                .into()
+            })
+            .collect();
+
+        if items.is_empty() {
+            items.push(
+                StandardItem {
+                    label: strings::tray_no_filesystems_label(),
+                    enabled: false,
+                    ..StandardItem::default()
+                }
+                .into(),
+            );
+        }
+
+        items.push(MenuItem::Separator);
+
+        items.push(
+            StandardItem {
+                label: strings::control_bar_mount_all_label(),
+                enabled: self.filesystems.iter().any(|fs| !fs.mounted),
+                activate: Box::new(|tray: &mut Self| tray.send(TrayEvent::MountAll)),
+                ..StandardItem::default()
+            }
+            .into(),
+        );
+
+        items.push(
+            StandardItem {
+                label: strings::control_bar_unmount_all_label(),
+                enabled: self.filesystems.iter().any(|fs| fs.mounted),
+                activate: Box::new(|tray: &mut Self| tray.send(TrayEvent::UnmountAll)),
+                ..StandardItem::default()
+            }
+            .into(),
+        );
+
+        items.push(MenuItem::Separator);
+
+        items.push(
+            StandardItem {
+                label: strings::tray_show_window_label(),
+                activate: Box::new(|tray: &mut Self| tray.send(TrayEvent::ShowWindow)),
+                ..StandardItem::default()
+            }
+            .into(),
+        );
+
+        items.push(
+            StandardItem {
+                label: strings::tray_quit_label(),
+                icon_name: "application-exit".to_owned(),
+                activate: Box::new(|tray: &mut Self| tray.send(TrayEvent::Quit)),
+                ..StandardItem::default()
+            }
+            .into(),
+        );
+
+        items
+    }
+
+    fn watcher_online(&self) {
+        // This is called via a shared reference, so we can't use `send()`.
+        let _ = self.output.clone().try_send(TrayEvent::Available(true));
+    }
+
+    fn watcher_offline(&self, reason: OfflineReason) -> bool {
+        log::warn!("The system tray went away: {:?}", reason);
+
+        let _ = self.output.clone().try_send(TrayEvent::Available(false));
+
+        // We keep running, because the system tray may come back (e.g. when the desktop shell restarts).
+        true
+    }
+}
+
+// Menu labels treat underscores as access key markers, so literal ones need to be doubled.
+fn escape_label(label: &str) -> String {
+    label.replace('_', "__")
+}
+
+/// TrayHandle is used for updating the tray icon after it's been registered (see `TrayEvent::Ready`).
+#[derive(Clone)]
+pub struct TrayHandle {
+    handle: ksni::Handle<Tray>,
+    manager: Manager,
+}
+
+impl fmt::Debug for TrayHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TrayHandle").finish_non_exhaustive()
+    }
+}
+
+impl TrayHandle {
+    /// Reloads the list of filesystems (and their mount state) shown in the tray menu.
+    pub async fn refresh(self) {
+        let manager = self.manager.clone();
+
+        // Like the Home page, we skip over invalid definitions instead of failing like `Manager::full_state()`.
+        let filesystems = match run_blocking(move || load_state(&manager)).await {
+            Ok(state) => state.filesystems,
+            Err(e) => {
+                log::warn!("Failed to load the filesystems list for the tray: {}", e);
+                return;
+            }
+        };
+
+        self.handle
+            .update(move |tray: &mut Tray| tray.filesystems = filesystems)
+            .await;
+    }
+}
+
+/// Returns a subscription which shows a tray icon (a StatusNotifierItem) and produces events for its menu items.
+///
+/// Only `TrayEvent::Available(false)` is produced if the tray icon can't be registered (e.g. D-Bus is not available).
+pub fn tray_events() -> Subscription<TrayEvent> {
+    Subscription::run(run)
+}
+
+fn run() -> impl Stream<Item = TrayEvent> {
+    stream::channel(16, async |mut output: mpsc::Sender<TrayEvent>| {
+        let manager = match sftpman_manager() {
+            Ok(manager) => manager,
+            Err(e) => {
+                log::warn!("Failed to show a tray icon: {}", e);
+                let _ = output.try_send(TrayEvent::Available(false));
+                return;
+            }
+        };
+
+        let tray = Tray {
+            filesystems: Vec::new(),
+            output: output.clone(),
+        };
+
+        // The tray service runs in the background for as long as we (or the application) hold on to a handle.
+        let _handle = match tray.spawn().await {
+            Ok(handle) => {
+                let _ = output.try_send(TrayEvent::Available(true));
+                let _ = output.try_send(TrayEvent::Ready(TrayHandle {
+                    handle: handle.clone(),
+                    manager,
+                }));
+
+                handle
+            }
+            Err(e) => {
+                log::warn!("Failed to show a tray icon: {}", e);
+                let _ = output.try_send(TrayEvent::Available(false));
+                return;
+            }
+        };
+
+        std::future::pending::<()>().await
+    })
+}
@@ -27,6 +27,20 @@ pub const MOUNT_ERROR_MODAL_WIDTH: f32 = 600.0;
 
 pub const ABOUT_MODAL_WIDTH: f32 = 550.0;
 
+// Exported configuration (systemd units, fstab entries) contains long lines.
+pub const EXPORT_MODAL_WIDTH: f32 = 700.0;
+
+// Activity log entries contain long commands, so we make use of most of the window's width.
+pub const ACTIVITY_LOG_MODAL_WIDTH: f32 = 700.0;
+
+// Deeply nested remote directories need room for their indentation.
+pub const REMOTE_BROWSER_MODAL_WIDTH: f32 = 600.0;
+pub const REMOTE_BROWSER_INDENT: f32 = 20.0;
+
+pub const NOTIFICATION_WIDTH: f32 = 360.0;
+pub const NOTIFICATION_STACK_MAX_VISIBLE: usize = 4;
+pub const NOTIFICATION_MESSAGE_PREVIEW_MAX_LENGTH: usize = 120;
+
 pub const ICON_SIZE: f32 = 16.0;
 
 pub const RECORD_LABEL_WIDTH: f32 = 230.0;
@@ -1,5 +1,19 @@
+use std::env;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::thread;
+
+use iced::futures::channel::oneshot;
+
+use libsftpman::Manager;
 use libsftpman::validator::ValidationErrors;
 
+// CONFIG_DIR holds the base configuration directory given via `--config-dir` (see `use_config_dir()`).
+static CONFIG_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+// MANAGER holds the manager created for `CONFIG_DIR` (see `sftpman_manager()`), or the reason why it couldn't be created.
+static MANAGER: OnceLock<Result<Manager, String>> = OnceLock::new();
+
 pub fn errors_to_string_list(errors: ValidationErrors) -> Vec<String> {
     let mut list: Vec<String> = Vec::new();
 
@@ -11,3 +25,94 @@ pub fn errors_to_string_list(errors: ValidationErrors) -> Vec<String> {
 
     list
 }
+
+/// Runs the given (blocking) closure on a separate thread and resolves with its result.
+///
+/// This lets us run long operations (like `sshfs`) without blocking the UI thread
+/// or tying up one of the async executor's worker threads.
+pub async fn run_blocking<T, F>(f: F) -> T
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    let (sender, receiver) = oneshot::channel();
+
+    thread::spawn(move || {
+        let _ = sender.send(f());
+    });
+
+    receiver
+        .await
+        .expect("The blocking operation's thread terminated without producing a result")
+}
+
+/// Makes sftpman's configuration be looked up under the given base directory, instead of `$XDG_CONFIG_HOME` (see `--config-dir`).
+///
+/// This must be called at startup, while we're still single-threaded.
+/// Our own settings get looked up under it too (see `sftpman_iced_config_dir()`), but child processes are not affected.
+pub fn use_config_dir(dir: &Path) {
+    let dir = std::path::absolute(dir).unwrap_or_else(|_| dir.to_path_buf());
+
+    // libsftpman's `Manager` can only be told where its configuration is via `XDG_CONFIG_HOME`,
+    // so we override it just for creating the one manager which we use from then on.
+    let previous = env::var_os("XDG_CONFIG_HOME");
+
+    // SAFETY: we're still single-threaded (see above).
+    unsafe {
+        env::set_var("XDG_CONFIG_HOME", &dir);
+    }
+
+    let manager = Manager::new();
+
+    // SAFETY: we're still single-threaded (see above).
+    unsafe {
+        match previous {
+            Some(previous) => env::set_var("XDG_CONFIG_HOME", previous),
+            None => env::remove_var("XDG_CONFIG_HOME"),
+        }
+    }
+
+    // Failing is remembered (instead of falling back to the default configuration directory),
+    // so that it gets reported (see `sftpman_manager()`) instead of us working on the wrong configuration.
+    let _ = MANAGER.set(manager.map_err(|e| format!("{}: {}", dir.display(), e)));
+
+    let _ = CONFIG_DIR.set(dir);
+}
+
+/// Returns a libsftpman manager for the sftpman configuration directory in use (see `use_config_dir()`).
+pub fn sftpman_manager() -> Result<Manager, String> {
+    match MANAGER.get() {
+        Some(result) => result.clone(),
+        None => Manager::new().map_err(|e| e.to_string()),
+    }
+}
+
+/// Returns the path to the sftpman configuration directory (e.g. `~/.config/sftpman`).
+///
+/// libsftpman doesn't expose this path, so we determine it the same way it does.
+pub fn sftpman_config_dir() -> Option<PathBuf> {
+    if let Some(dir) = CONFIG_DIR.get() {
+        return Some(dir.join("sftpman"));
+    }
+
+    directories::ProjectDirs::from("sftpman", "Devture Ltd", "sftpman")
+        .map(|dirs| dirs.config_dir().to_path_buf())
+}
+
+/// Returns the path to our own configuration directory (e.g. `~/.config/sftpman-iced`), which holds our settings.
+///
+/// It's next to the sftpman configuration directory in use, so that settings (which are keyed by definition id)
+/// for definitions in different configuration directories (see `use_config_dir()`) don't get mixed up.
+pub fn sftpman_iced_config_dir() -> Option<PathBuf> {
+    if let Some(dir) = CONFIG_DIR.get() {
+        return Some(dir.join("sftpman-iced"));
+    }
+
+    directories::ProjectDirs::from("sftpman-iced", "Devture Ltd", "sftpman-iced")
+        .map(|dirs| dirs.config_dir().to_path_buf())
+}
+
+/// Returns the path to the directory which contains the filesystem definitions (`{config_dir}/mounts`).
+pub fn sftpman_mounts_config_dir() -> Option<PathBuf> {
+    sftpman_config_dir().map(|dir| dir.join("mounts"))
+}
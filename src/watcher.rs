@@ -0,0 +1,118 @@
+use std::fs::{self, File};
+use std::os::fd::AsFd;
+use std::thread;
+
+use iced::Subscription;
+use iced::futures::Stream;
+use iced::futures::channel::mpsc;
+use iced::stream;
+
+use nix::errno::Errno;
+use nix::poll::{PollFd, PollFlags, PollTimeout, poll};
+use nix::sys::inotify::{AddWatchFlags, InitFlags, Inotify};
+
+use crate::utils::sftpman_mounts_config_dir;
+
+const MOUNTINFO_PATH: &str = "/proc/self/mountinfo";
+
+// If we can't watch for changes at all (e.g. `/proc` is not available),
+// we fall back to reporting a (potential) change every so often.
+const FALLBACK_REFRESH_INTERVAL_MS: u16 = 5000;
+
+/// Returns a subscription which produces a value whenever the mount state may have changed:
+/// - a filesystem got mounted or unmounted (by us, the sftpman CLI or anything else)
+/// - a filesystem definition got added, changed or removed in the sftpman configuration directory
+///
+/// A value is also produced right after subscribing, so that changes which happened before that are not missed.
+pub fn mount_state_changes() -> Subscription<()> {
+    Subscription::run(watch)
+}
+
+fn watch() -> impl Stream<Item = ()> {
+    stream::channel(1, async |mut output: mpsc::Sender<()>| {
+        let _ = output.try_send(());
+
+        // Polling is blocking, so it happens on a separate thread which lives as long as the subscription.
+        thread::spawn(move || watch_blocking(output));
+
+        std::future::pending::<()>().await
+    })
+}
+
+fn watch_blocking(mut output: mpsc::Sender<()>) {
+    // The kernel reports changes to the mount table as POLLPRI (and POLLERR) events on this file.
+    let mountinfo = File::open(MOUNTINFO_PATH)
+        .inspect_err(|e| log::warn!("Failed to open {} for watching: {}", MOUNTINFO_PATH, e))
+        .ok();
+
+    let inotify = watch_mounts_config_dir()
+        .inspect_err(|e| log::warn!("Failed to watch the configuration directory: {}", e))
+        .ok();
+
+    loop {
+        let mut fds = Vec::new();
+
+        if let Some(mountinfo) = &mountinfo {
+            fds.push(PollFd::new(mountinfo.as_fd(), PollFlags::POLLPRI));
+        }
+
+        if let Some(inotify) = &inotify {
+            fds.push(PollFd::new(inotify.as_fd(), PollFlags::POLLIN));
+        }
+
+        let timeout = if fds.is_empty() {
+            PollTimeout::from(FALLBACK_REFRESH_INTERVAL_MS)
+        } else {
+            PollTimeout::NONE
+        };
+
+        match poll(&mut fds, timeout) {
+            Ok(_) => {}
+            Err(Errno::EINTR) => continue,
+            Err(e) => {
+                log::error!("Failed to wait for mount state changes: {}", e);
+                return;
+            }
+        }
+
+        // Draining the inotify queue, so that we don't get woken up again for the same events.
+        // We don't care which files changed, because subscribers reload everything anyway.
+        if let Some(inotify) = &inotify {
+            let _ = inotify.read_events();
+        }
+
+        // If the channel is full, a notification is already pending and the subscriber will
+        // pick up this change as well when handling it, so it's fine to skip sending.
+        if let Err(e) = output.try_send(())
+            && e.is_disconnected()
+        {
+            return;
+        }
+    }
+}
+
+fn watch_mounts_config_dir() -> Result<Inotify, String> {
+    let Some(dir) = sftpman_mounts_config_dir() else {
+        return Err("could not determine the configuration directory".to_owned());
+    };
+
+    // libsftpman creates this directory when persisting the first definition.
+    // We create it earlier, so we'd have something to watch.
+    fs::create_dir_all(&dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+
+    let inotify =
+        Inotify::init(InitFlags::IN_NONBLOCK | InitFlags::IN_CLOEXEC).map_err(|e| e.to_string())?;
+
+    inotify
+        .add_watch(
+            &dir,
+            AddWatchFlags::IN_CREATE
+                | AddWatchFlags::IN_DELETE
+                | AddWatchFlags::IN_CLOSE_WRITE
+                | AddWatchFlags::IN_MOVED_FROM
+                | AddWatchFlags::IN_MOVED_TO,
+        )
+        .map_err(|e| format!("{}: {}", dir.display(), e))?;
+
+    Ok(inotify)
+}
@@ -1,10 +1,10 @@
-use iced::widget::{Row, row, Space};
+use iced::widget::{Row, Space, row};
 use iced::{Center, Length};
 
 #[cfg(feature = "icons")]
 use iced::widget::svg;
 
-use super::{Button, ButtonStyle};
+use super::{Button, ButtonIconPosition, ButtonStyle};
 use crate::messages::Message;
 use crate::pages::HomeMessage;
 use crate::strings;
@@ -14,6 +14,7 @@ pub fn control_bar(
     are_all_mounted: bool,
     are_all_unmounted: bool,
     is_search_enabled: bool,
+    is_menu_expanded: bool,
 ) -> Row<'static, Message> {
     let mut btn_new = Button::new(strings::control_bar_new_label())
         .with_style(ButtonStyle::Primary)
@@ -61,6 +62,61 @@ pub fn control_bar(
         ));
     }
 
+    let btn_menu = create_menu_dropdown_button(is_menu_expanded);
+
+    let home_action_button_width = Length::Fixed(home_action_button_width());
+
+    row![
+        btn_new.build(),
+        Space::new().width(Length::Fill),
+        btn_mount_all.build(),
+        btn_unmount_all.build(),
+        Space::new().width(Length::Fill),
+        btn_search.with_width(home_action_button_width).build(),
+        btn_menu.with_width(home_action_button_width).build(),
+    ]
+    .spacing(WIDGET_HORIZONTAL_SPACING)
+    .align_y(Center)
+}
+
+/// Renders the row of less frequently used actions, shown below the control bar when its menu is expanded.
+pub fn control_bar_menu() -> Row<'static, Message> {
+    let mut btn_activity = Button::new(strings::control_bar_activity_label())
+        .with_style(ButtonStyle::Secondary)
+        .with_tooltip(strings::control_bar_activity_tooltip())
+        .with_on_press(Some(Message::Home(HomeMessage::ShowActivityLog)));
+
+    #[cfg(feature = "icons")]
+    {
+        btn_activity = btn_activity.with_svg_icon_handle(svg::Handle::from_memory(
+            crate::assets::bootstrap_icons::CLIPBOARD_CHECK,
+        ));
+    }
+
+    let mut btn_settings = Button::new(strings::control_bar_settings_label())
+        .with_style(ButtonStyle::Secondary)
+        .with_tooltip(strings::control_bar_settings_tooltip())
+        .with_on_press(Some(Message::Home(HomeMessage::OpenSettings)));
+
+    #[cfg(feature = "icons")]
+    {
+        btn_settings = btn_settings.with_svg_icon_handle(svg::Handle::from_memory(
+            crate::assets::bootstrap_icons::GEAR,
+        ));
+    }
+
+    let mut btn_import = Button::new(strings::control_bar_import_label())
+        .with_style(ButtonStyle::Secondary)
+        .with_tooltip(strings::control_bar_import_tooltip())
+        .with_on_press(Some(Message::Home(HomeMessage::ImportSshConfig)));
+
+    #[cfg(feature = "icons")]
+    {
+        btn_import = btn_import.with_svg_icon_handle(svg::Handle::from_memory(
+            crate::assets::bootstrap_icons::DOWNLOAD,
+        ));
+    }
+
     let mut btn_about = Button::new(strings::control_bar_about_label())
         .with_style(ButtonStyle::Secondary)
         .with_on_press(Some(Message::Home(HomeMessage::About(true))));
@@ -75,14 +131,35 @@ pub fn control_bar(
     let home_action_button_width = Length::Fixed(home_action_button_width());
 
     row![
-        btn_new.build(),
         Space::new().width(Length::Fill),
-        btn_mount_all.build(),
-        btn_unmount_all.build(),
-        Space::new().width(Length::Fill),
-        btn_search.with_width(home_action_button_width).build(),
+        btn_activity.with_width(home_action_button_width).build(),
+        btn_settings.with_width(home_action_button_width).build(),
+        btn_import.with_width(home_action_button_width).build(),
         btn_about.with_width(home_action_button_width).build(),
     ]
     .spacing(WIDGET_HORIZONTAL_SPACING)
-    .align_y(Center)
+}
+
+fn create_menu_dropdown_button(is_expanded: bool) -> Button {
+    // Like the Options button in the filesystems list, this is a custom dropdown,
+    // which expands a row of buttons below the control bar.
+    let mut btn = Button::new(strings::control_bar_menu_label())
+        .with_style(if is_expanded {
+            ButtonStyle::Primary
+        } else {
+            ButtonStyle::Secondary
+        })
+        .with_icon_position(ButtonIconPosition::Right)
+        .with_on_press(Some(Message::Home(HomeMessage::ToggleMenu)));
+
+    #[cfg(feature = "icons")]
+    {
+        btn = btn.with_svg_icon_handle(svg::Handle::from_memory(if is_expanded {
+            crate::assets::bootstrap_icons::CHEVRON_UP
+        } else {
+            crate::assets::bootstrap_icons::CHEVRON_DOWN
+        }));
+    }
+
+    btn
 }
@@ -0,0 +1,87 @@
+use iced::Alignment::Center;
+use iced::Length;
+use iced::widget::{Column, Container, Space, column, container, row, text};
+
+#[cfg(feature = "icons")]
+use iced::widget::svg;
+
+use super::{Button, ButtonStyle};
+use crate::application::ApplicationMessage;
+use crate::messages::Message;
+use crate::state::InvalidDefinition;
+use crate::strings;
+use crate::ui_config::{
+    FS_LIST_SPACING_BETWEEN_ROW_AND_OPTIONS, FS_LIST_SPACING_BETWEEN_ROWS, ICON_TO_TEXT_SPACING,
+    WIDGET_HORIZONTAL_SPACING, home_action_button_width,
+};
+
+#[cfg(feature = "icons")]
+use crate::ui_config::ICON_SIZE;
+#[cfg(feature = "icons")]
+use crate::widgets::{IconColor, icon};
+
+pub fn fs_invalid_list(
+    invalid_definitions: Vec<InvalidDefinition>,
+    theme: &iced::theme::Theme,
+) -> Column<'static, Message> {
+    invalid_definitions
+        .into_iter()
+        .fold(Column::new(), |col, invalid_definition| {
+            col.push(fs_invalid_list_row(invalid_definition, theme))
+        })
+        .spacing(FS_LIST_SPACING_BETWEEN_ROWS)
+}
+
+fn fs_invalid_list_row(
+    invalid_definition: InvalidDefinition,
+    theme: &iced::theme::Theme,
+) -> Container<'static, Message> {
+    let mut label_row = row![].spacing(ICON_TO_TEXT_SPACING).align_y(Center);
+
+    #[cfg(feature = "icons")]
+    {
+        label_row = label_row.push(icon(
+            &svg::Handle::from_memory(crate::assets::bootstrap_icons::EXCLAMATION_TRIANGLE),
+            ICON_SIZE,
+            IconColor::Danger,
+        ));
+    }
+
+    label_row = label_row.push(text(strings::fs_list_invalid_definition_label(
+        &invalid_definition.id,
+    )));
+
+    // Broken files can't be edited via the Record page, so we let the user open them in their editor.
+    let mut btn_open_file = Button::new(strings::fs_list_open_file_label())
+        .with_style(ButtonStyle::Secondary)
+        .with_width(Length::Fixed(home_action_button_width()))
+        .with_tooltip(invalid_definition.path.display().to_string())
+        .with_on_press(Some(Message::Application(ApplicationMessage::OpenLink(
+            invalid_definition.path.display().to_string(),
+        ))));
+
+    #[cfg(feature = "icons")]
+    {
+        btn_open_file = btn_open_file.with_svg_icon_handle(svg::Handle::from_memory(
+            crate::assets::bootstrap_icons::PENCIL,
+        ));
+    }
+
+    let header_row = row![
+        label_row,
+        Space::new().width(Length::Fill),
+        btn_open_file.build(),
+    ]
+    .spacing(WIDGET_HORIZONTAL_SPACING)
+    .align_y(Center);
+
+    container(
+        column![
+            header_row,
+            text(invalid_definition.error)
+                .size(14)
+                .color(theme.palette().danger),
+        ]
+        .spacing(FS_LIST_SPACING_BETWEEN_ROW_AND_OPTIONS),
+    )
+}
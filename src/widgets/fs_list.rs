@@ -1,5 +1,7 @@
+use std::collections::{HashMap, HashSet};
+
 use iced::Length;
-use iced::widget::{Column, Container, Row, row, toggler, Space};
+use iced::widget::{Column, Container, Space, column, row, toggler};
 
 #[cfg(feature = "icons")]
 use iced::widget::svg;
@@ -7,8 +9,11 @@ use iced::widget::svg;
 use libsftpman::{FilesystemMountDefinition, MountState};
 
 use super::{Button, ButtonIconPosition, ButtonStyle};
+use crate::export::ExportFormat;
 use crate::messages::Message;
+use crate::mount::CancellationToken;
 use crate::pages::HomeMessage;
+use crate::reconnect::Reconnect;
 use crate::strings;
 use crate::ui_config::{
     FS_LIST_SPACING_BETWEEN_ROW_AND_OPTIONS, FS_LIST_SPACING_BETWEEN_ROWS,
@@ -18,25 +23,35 @@ use crate::ui_config::{
 pub fn fs_list(
     state: Vec<MountState>,
     expand_options_for_fs_id: Option<String>,
-    mounting_in_progress_for_fs_id: Option<String>,
-    unmounting_in_progress_for_fs_id: Option<String>,
+    mounting_in_progress_for_fs_ids: &HashMap<String, CancellationToken>,
+    unmounting_in_progress_for_fs_ids: &HashSet<String>,
+    broken_fs_ids: &HashMap<String, String>,
+    reconnecting: &HashMap<String, Reconnect>,
+    reconnect_max_failures: u32,
 ) -> Column<'static, Message> {
     let home_action_button_width = Length::Fixed(home_action_button_width());
 
     state
         .into_iter()
         .fold(Column::new(), |col, entity| {
-            let is_expanded = expand_options_for_fs_id
-                .as_ref()
-                .map_or(false, |id| id == &entity.definition.id);
+            let is_expanded = expand_options_for_fs_id.as_ref() == Some(&entity.definition.id);
+
+            let is_mounting = mounting_in_progress_for_fs_ids.contains_key(&entity.definition.id);
 
-            let is_mounting = mounting_in_progress_for_fs_id
-                .as_ref()
-                .map_or(false, |id| id == &entity.definition.id);
+            let is_unmounting = unmounting_in_progress_for_fs_ids.contains(&entity.definition.id);
 
-            let is_unmounting = unmounting_in_progress_for_fs_id
-                .as_ref()
-                .map_or(false, |id| id == &entity.definition.id);
+            let broken_reason = broken_fs_ids
+                .get(&entity.definition.id)
+                .filter(|_| entity.mounted)
+                .cloned();
+
+            let reconnecting_label = reconnecting.get(&entity.definition.id).map(|reconnect| {
+                strings::filesystem_definition_name_reconnecting_label(
+                    &entity.definition.id,
+                    reconnect.attempt(),
+                    reconnect_max_failures,
+                )
+            });
 
             col.push(fs_list_row(
                 entity,
@@ -44,6 +59,8 @@ pub fn fs_list(
                 is_expanded,
                 is_mounting,
                 is_unmounting,
+                broken_reason,
+                reconnecting_label,
             ))
         })
         .spacing(FS_LIST_SPACING_BETWEEN_ROWS)
@@ -55,48 +72,51 @@ fn fs_list_row(
     options_expanded: bool,
     is_mounting: bool,
     is_unmounting: bool,
+    broken_reason: Option<String>,
+    reconnecting_label: Option<String>,
 ) -> Container<'static, Message> {
     let definition = entity.definition.clone();
 
     let toggler_checked = (entity.mounted || is_mounting) && !is_unmounting;
 
-    let label = if is_mounting {
+    // Reconnecting involves mounting (and possibly unmounting first), but the attempt is more telling.
+    let label = if let Some(reconnecting_label) = reconnecting_label {
+        reconnecting_label
+    } else if is_mounting {
         strings::filesystem_definition_name_mounting_label(&entity.definition.id)
     } else if is_unmounting {
         strings::filesystem_definition_name_unmounting_label(&entity.definition.id)
+    } else if broken_reason.is_some() {
+        strings::filesystem_definition_name_broken_label(&entity.definition.id)
     } else {
         entity.definition.id.clone()
     };
 
+    // While an operation is in progress for this filesystem, toggling is disabled.
+    // Other filesystems in the list remain usable.
+    let on_toggle = (!is_mounting && !is_unmounting).then_some(move |new_toggle_state| {
+        if new_toggle_state {
+            Message::Home(HomeMessage::Mount(definition.clone()))
+        } else {
+            Message::Home(HomeMessage::Unmount(definition.clone()))
+        }
+    });
+
     let widget_toggler = toggler(toggler_checked)
         .label(label)
         // Make it larger (default is 16), as this is an important action that is frequently used
         .size(24)
-        .on_toggle(move |new_toggle_state| {
-            if new_toggle_state {
-                Message::Home(HomeMessage::Mount(definition.clone()))
-            } else {
-                Message::Home(HomeMessage::Unmount(definition.clone()))
-            }
-        });
-
-    let mut widget_btn_open = Button::new(strings::fs_list_open_label())
-        .with_style(if entity.mounted && !is_mounting {
-            ButtonStyle::Primary
-        } else {
-            ButtonStyle::Secondary
-        })
-        .with_width(*home_action_button_width)
-        .with_on_press(
-            (entity.mounted).then_some(Message::Home(HomeMessage::Open(entity.definition.clone()))),
-        );
-
-    #[cfg(feature = "icons")]
-    {
-        widget_btn_open = widget_btn_open.with_svg_icon_handle(svg::Handle::from_memory(
-            crate::assets::bootstrap_icons::FOLDER2_OPEN,
-        ));
-    }
+        .on_toggle_maybe(on_toggle);
+
+    // While mounting, the Open button (which is unusable anyway) is replaced by a Cancel button.
+    // Likewise, for broken (dead) mounts, it's replaced by a Repair button.
+    let widget_btn_open = if is_mounting {
+        create_cancel_mount_button(&entity.definition).with_width(*home_action_button_width)
+    } else if let Some(reason) = broken_reason.filter(|_| !is_unmounting) {
+        create_repair_button(&entity.definition, &reason).with_width(*home_action_button_width)
+    } else {
+        create_open_button(&entity, home_action_button_width)
+    };
 
     let widget_btn_options = create_dropdown_button(&entity.definition, options_expanded)
         .with_width(*home_action_button_width);
@@ -114,7 +134,8 @@ fn fs_list_row(
 
     if options_expanded {
         col = col.push(build_options_row(
-            &entity.definition,
+            &entity,
+            is_mounting || is_unmounting,
             home_action_button_width,
         ));
     }
@@ -122,11 +143,70 @@ fn fs_list_row(
     Container::new(col)
 }
 
+fn create_open_button(entity: &MountState, home_action_button_width: &Length) -> Button {
+    let mut btn = Button::new(strings::fs_list_open_label())
+        .with_style(if entity.mounted {
+            ButtonStyle::Primary
+        } else {
+            ButtonStyle::Secondary
+        })
+        .with_width(*home_action_button_width)
+        .with_on_press(
+            (entity.mounted).then_some(Message::Home(HomeMessage::Open(entity.definition.clone()))),
+        );
+
+    #[cfg(feature = "icons")]
+    {
+        btn = btn.with_svg_icon_handle(svg::Handle::from_memory(
+            crate::assets::bootstrap_icons::FOLDER2_OPEN,
+        ));
+    }
+
+    btn
+}
+
+fn create_cancel_mount_button(definition: &FilesystemMountDefinition) -> Button {
+    let mut btn = Button::new(strings::fs_list_cancel_mount_label())
+        .with_style(ButtonStyle::Danger)
+        .with_tooltip(strings::fs_list_cancel_mount_tooltip())
+        .with_on_press(Some(Message::Home(HomeMessage::CancelMount(
+            definition.clone(),
+        ))));
+
+    #[cfg(feature = "icons")]
+    {
+        btn = btn.with_svg_icon_handle(svg::Handle::from_memory(
+            crate::assets::bootstrap_icons::X_CIRCLE,
+        ));
+    }
+
+    btn
+}
+
+fn create_repair_button(definition: &FilesystemMountDefinition, reason: &str) -> Button {
+    let mut btn = Button::new(strings::fs_list_repair_label())
+        .with_style(ButtonStyle::Danger)
+        .with_tooltip(strings::fs_list_repair_tooltip(reason))
+        .with_on_press(Some(Message::Home(HomeMessage::Repair(definition.clone()))));
+
+    #[cfg(feature = "icons")]
+    {
+        btn = btn.with_svg_icon_handle(svg::Handle::from_memory(
+            crate::assets::bootstrap_icons::ARROW_CLOCKWISE,
+        ));
+    }
+
+    btn
+}
+
 fn build_options_row(
-    definition: &FilesystemMountDefinition,
+    entity: &MountState,
+    is_busy: bool,
     home_action_button_width: &Length,
-) -> Row<'static, Message> {
-    row![
+) -> Column<'static, Message> {
+    let definition = &entity.definition;
+
+    let row_actions = row![
         Space::new().width(Length::Fill),
         create_edit_option_button(definition)
             .with_width(*home_action_button_width)
@@ -138,7 +218,35 @@ fn build_options_row(
             .with_width(*home_action_button_width)
             .build(),
     ]
-    .spacing(WIDGET_HORIZONTAL_SPACING)
+    .spacing(WIDGET_HORIZONTAL_SPACING);
+
+    // Like exporting (below), these have labels too long for fixed-width buttons.
+    let row_terminal = row![
+        Space::new().width(Length::Fill),
+        create_open_terminal_option_button(definition, entity.mounted && !is_busy).build(),
+        create_ssh_option_button(definition).build(),
+    ]
+    .spacing(WIDGET_HORIZONTAL_SPACING);
+
+    // Exporting is done rarely, so it goes on its own row (with labels too long for fixed-width buttons).
+    let row_export = row![
+        Space::new().width(Length::Fill),
+        create_export_option_button(
+            definition,
+            ExportFormat::Systemd,
+            strings::fs_list_export_systemd_label()
+        )
+        .build(),
+        create_export_option_button(
+            definition,
+            ExportFormat::Fstab,
+            strings::fs_list_export_fstab_label()
+        )
+        .build(),
+    ]
+    .spacing(WIDGET_HORIZONTAL_SPACING);
+
+    column![row_actions, row_terminal, row_export].spacing(FS_LIST_SPACING_BETWEEN_ROW_AND_OPTIONS)
 }
 
 fn create_dropdown_button(definition: &FilesystemMountDefinition, is_expanded: bool) -> Button {
@@ -213,6 +321,67 @@ fn create_clone_option_button(definition: &FilesystemMountDefinition) -> Button
     btn
 }
 
+fn create_open_terminal_option_button(
+    definition: &FilesystemMountDefinition,
+    enabled: bool,
+) -> Button {
+    let mut btn = Button::new(strings::fs_list_open_terminal_label())
+        .with_style(ButtonStyle::SecondaryOutlined)
+        .with_tooltip(strings::fs_list_open_terminal_tooltip())
+        .with_on_press(
+            enabled.then_some(Message::Home(HomeMessage::OpenTerminal(definition.clone()))),
+        );
+
+    #[cfg(feature = "icons")]
+    {
+        btn = btn.with_svg_icon_handle(svg::Handle::from_memory(
+            crate::assets::bootstrap_icons::TERMINAL,
+        ));
+    }
+
+    btn
+}
+
+fn create_ssh_option_button(definition: &FilesystemMountDefinition) -> Button {
+    let mut btn = Button::new(strings::fs_list_ssh_label())
+        .with_style(ButtonStyle::SecondaryOutlined)
+        .with_tooltip(strings::fs_list_ssh_tooltip(&definition.remote_path))
+        .with_on_press(Some(Message::Home(HomeMessage::OpenSsh(
+            definition.clone(),
+        ))));
+
+    #[cfg(feature = "icons")]
+    {
+        btn = btn.with_svg_icon_handle(svg::Handle::from_memory(
+            crate::assets::bootstrap_icons::TERMINAL,
+        ));
+    }
+
+    btn
+}
+
+fn create_export_option_button(
+    definition: &FilesystemMountDefinition,
+    format: ExportFormat,
+    label: String,
+) -> Button {
+    let mut btn = Button::new(label)
+        .with_style(ButtonStyle::SecondaryOutlined)
+        .with_on_press(Some(Message::Home(HomeMessage::Export(
+            definition.clone(),
+            format,
+        ))));
+
+    #[cfg(feature = "icons")]
+    {
+        btn = btn.with_svg_icon_handle(svg::Handle::from_memory(
+            crate::assets::bootstrap_icons::DOWNLOAD,
+        ));
+    }
+
+    btn
+}
+
 fn create_remove_option_button(definition: &FilesystemMountDefinition) -> Button {
     let mut btn = Button::new(strings::remove_button_label())
         .with_style(ButtonStyle::Danger)
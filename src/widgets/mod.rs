@@ -1,22 +1,26 @@
 mod button;
 mod control_bar;
 mod fs_empty_list;
+mod fs_invalid_list;
 mod fs_list;
 #[cfg(feature = "icons")]
 mod icon;
 mod modal;
+mod notification_stack;
 mod preflight_check_errors_bar;
 mod record;
 mod search_bar;
 mod text_link;
 
 pub use button::{Button, ButtonIconPosition, ButtonStyle};
-pub use control_bar::control_bar;
+pub use control_bar::{control_bar, control_bar_menu};
 pub use fs_empty_list::fs_empty_list;
+pub use fs_invalid_list::fs_invalid_list;
 pub use fs_list::fs_list;
 #[cfg(feature = "icons")]
 pub use icon::{IconColor, icon};
 pub use modal::modal;
+pub use notification_stack::notification_stack;
 pub use preflight_check_errors_bar::preflight_check_errors_bar;
 pub use record::field_control_label;
 pub use search_bar::{SEARCH_BAR_INPUT_FIELD_ID, search_bar};
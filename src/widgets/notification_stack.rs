@@ -0,0 +1,160 @@
+use iced::Alignment::Center;
+use iced::widget::{Column, Container, Space, column, container, row, text};
+use iced::{Border, Length, Padding};
+
+#[cfg(feature = "icons")]
+use iced::widget::svg;
+
+use super::{Button, ButtonStyle};
+use crate::application::ApplicationMessage;
+use crate::messages::Message;
+use crate::notifications::{Notification, NotificationSeverity};
+use crate::strings;
+use crate::ui_config::{
+    ICON_TO_TEXT_SPACING, NOTIFICATION_MESSAGE_PREVIEW_MAX_LENGTH, NOTIFICATION_STACK_MAX_VISIBLE,
+    NOTIFICATION_WIDTH, WIDGET_HORIZONTAL_SPACING, WIDGET_VERTICAL_SPACING,
+};
+
+#[cfg(feature = "icons")]
+use crate::ui_config::ICON_SIZE;
+#[cfg(feature = "icons")]
+use crate::widgets::{IconColor, icon};
+
+pub fn notification_stack(notifications: &[Notification]) -> Container<'static, Message> {
+    let mut col = Column::new().spacing(WIDGET_VERTICAL_SPACING);
+
+    // The most recent notifications are the most relevant ones, so we show them (at the bottom).
+    let hidden_count = notifications
+        .len()
+        .saturating_sub(NOTIFICATION_STACK_MAX_VISIBLE);
+
+    if hidden_count > 0 {
+        col = col.push(
+            container(
+                row![
+                    text(strings::notification_stack_more_label(hidden_count)),
+                    Space::new().width(Length::Fill),
+                    Button::new(strings::notification_dismiss_all_button_label())
+                        .with_style(ButtonStyle::SecondaryOutlined)
+                        .with_on_press(Some(Message::Application(
+                            ApplicationMessage::DismissAllNotifications
+                        )))
+                        .build(),
+                ]
+                .spacing(WIDGET_HORIZONTAL_SPACING)
+                .align_y(Center),
+            )
+            .padding(WIDGET_VERTICAL_SPACING)
+            .style(container::rounded_box),
+        );
+    }
+
+    for notification in notifications.iter().skip(hidden_count) {
+        col = col.push(notification_card(notification));
+    }
+
+    container(col.width(NOTIFICATION_WIDTH))
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .align_right(Length::Fill)
+        .align_bottom(Length::Fill)
+        .padding(Padding::new(WIDGET_VERTICAL_SPACING))
+}
+
+fn notification_card(notification: &Notification) -> Container<'static, Message> {
+    let severity = notification.severity;
+
+    let mut heading_row = row![].spacing(ICON_TO_TEXT_SPACING).align_y(Center);
+
+    #[cfg(feature = "icons")]
+    {
+        if let Some(svg_icon_handle) = &notification.alert.svg_icon_handle {
+            let icon_color = match severity {
+                NotificationSeverity::Error => IconColor::Danger,
+                NotificationSeverity::Warning => IconColor::Danger,
+                NotificationSeverity::Info | NotificationSeverity::Success => IconColor::Primary,
+            };
+
+            heading_row = heading_row.push(icon(svg_icon_handle, ICON_SIZE, icon_color));
+        }
+    }
+
+    heading_row = heading_row.push(text(notification.alert.title.clone()));
+
+    let (preview, is_truncated) = message_preview(&notification.alert.message);
+
+    let mut buttons_row = row![Space::new().width(Length::Fill)].spacing(WIDGET_HORIZONTAL_SPACING);
+
+    // Notifications with long messages or additional actions (copying the error, etc.) can be opened in full.
+    if is_truncated || !notification.alert.additional_buttons.is_empty() {
+        let mut btn_details = Button::new(strings::notification_details_button_label())
+            .with_style(ButtonStyle::Primary)
+            .with_on_press(Some(Message::Application(
+                ApplicationMessage::ShowNotification(Some(notification.id)),
+            )));
+
+        #[cfg(feature = "icons")]
+        {
+            btn_details = btn_details.with_svg_icon_handle(svg::Handle::from_memory(
+                crate::assets::bootstrap_icons::INFO_CIRCLE,
+            ));
+        }
+
+        buttons_row = buttons_row.push(btn_details.build());
+    }
+
+    buttons_row = buttons_row.push(
+        Button::new(strings::alert_close_button_label())
+            .with_style(ButtonStyle::SecondaryOutlined)
+            .with_on_press(Some(Message::Application(
+                ApplicationMessage::DismissNotification(notification.id),
+            )))
+            .build(),
+    );
+
+    let mut col = column![heading_row].spacing(WIDGET_VERTICAL_SPACING);
+    if !preview.is_empty() {
+        col = col.push(text(preview).size(14));
+    }
+    col = col.push(buttons_row);
+
+    container(col)
+        .width(Length::Fill)
+        .padding(WIDGET_VERTICAL_SPACING)
+        .style(move |theme: &iced::theme::Theme| {
+            let palette = theme.palette();
+
+            let border_color = match severity {
+                NotificationSeverity::Info => palette.primary,
+                NotificationSeverity::Success => palette.success,
+                NotificationSeverity::Warning => palette.warning,
+                NotificationSeverity::Error => palette.danger,
+            };
+
+            container::rounded_box(theme)
+                .background(palette.background)
+                .border(Border::default().rounded(4).width(2).color(border_color))
+        })
+}
+
+// Returns the first line of the message (shortened if necessary) and whether anything was left out.
+fn message_preview(message: &str) -> (String, bool) {
+    let first_line = message.lines().next().unwrap_or_default();
+
+    let mut is_truncated = message.trim_end() != first_line;
+
+    let preview = if first_line.chars().count() > NOTIFICATION_MESSAGE_PREVIEW_MAX_LENGTH {
+        is_truncated = true;
+
+        let shortened: String = first_line
+            .chars()
+            .take(NOTIFICATION_MESSAGE_PREVIEW_MAX_LENGTH)
+            .collect();
+
+        format!("{}…", shortened.trim_end())
+    } else {
+        first_line.to_owned()
+    };
+
+    (preview, is_truncated)
+}